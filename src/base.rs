@@ -21,6 +21,17 @@ pub struct Coord3d {
 }
 
 /// Structure for defining 3d points.
+///
+/// Points and vectors support the usual affine arithmetic, on owned values or references:
+///
+/// ```
+/// use mersh::base::*;
+///
+/// let p = Pnt3d::new([0., 0., 0.]);
+/// let q = Pnt3d::new([2., 4., 0.]);
+/// let m = &p + (&q - &p) * 0.5;
+/// assert!(m.coords.equals(&Coord3d::new([1., 2., 0.]), GEOMETRICAL_TOLERANCE));
+/// ```
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct Pnt3d {
     /// Coordinates associated to the point.
@@ -41,6 +52,34 @@ pub struct Dir3d {
     pub coords: Coord3d,
 }
 
+/// Structure for defining 3d axis-aligned bounding boxes.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct BoundingBox3d {
+    /// Coordinates of the lower corner of the box.
+    pub min: Coord3d,
+    /// Coordinates of the upper corner of the box.
+    pub max: Coord3d,
+}
+
+/// Former name of 3d axis-aligned bounding boxes, kept for compatibility.
+pub type Aabb3d = BoundingBox3d;
+
+/// Structure for defining 3x3 matrices, stored row by row.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Mat3 {
+    /// Rows of the matrix.
+    pub rows: [[f64; 3]; 3],
+}
+
+/// Structure for defining 3d affine transformations, mapping a point p to `linear * p + translation`.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Transform3d {
+    /// Linear part of the transformation, e.g. rotation and scaling.
+    pub linear: Mat3,
+    /// Translation part of the transformation.
+    pub translation: Vec3d,
+}
+
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 // 3D implementations.
@@ -48,12 +87,44 @@ pub struct Dir3d {
 //////////////////////////////////////////////////////////////
 
 impl std::fmt::Display for Coord3d {
-    /// Implementing display for 3d coordinates. By default the number of decimal is set to 6.
+    /// Implementing display for 3d coordinates. The number of decimal is taken from the
+    /// formatter precision (e.g. `{:.3}`) and is set to 6 by default.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let precision = formatter.precision().unwrap_or(6);
+        write!(formatter, "({:.*}, {:.*}, {:.*})", precision, self.x, precision, self.y, precision, self.z)
+    }
+}
+
+impl std::fmt::Display for Pnt3d {
+    /// Implementing display for 3d points using the display of their coordinates.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.coords.fmt(formatter)
+    }
+}
+
+impl std::fmt::Display for Vec3d {
+    /// Implementing display for 3d vectors using the display of their coordinates.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.coords.fmt(formatter)
+    }
+}
+
+impl std::fmt::Display for Dir3d {
+    /// Implementing display for 3d directions using the display of their coordinates.
     ///
     /// * `formatter` - input reference to formatter.
     ///
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "({:.6}, {:.6}, {:.6})", self.x, self.y, self.z)
+        self.coords.fmt(formatter)
     }
 }
 
@@ -139,11 +210,20 @@ impl Coord3d {
         self.add_out(-1.0, c).sq_norm() < eps
     }
 
+    /// Computing dot product with another 3d coordinate.
+    ///
+    /// * `c` - Second coordinate used for dot product.
+    ///
+    pub fn dot(&self, c: &Coord3d) -> f64
+    {
+        self.x * c.x + self.y * c.y + self.z * c.z
+    }
+
     /// Computing square norm of a 3d coordinate.
     ///
     pub fn sq_norm(&self) -> f64
     {
-        self.x * self.x + self.y * self.y + self.z * self.z
+        self.dot(self)
     }
 
     /// Computing norm of a 3d coordinate.
@@ -152,6 +232,20 @@ impl Coord3d {
     {
          self.sq_norm().sqrt()
     }
+
+    /// Clamping each component of a coordinate to a range. Out-of-place function.
+    ///
+    /// * `min` - Lower bounds of the range.
+    /// * `max` - Upper bounds of the range.
+    ///
+    pub fn clamp(&self, min: &Coord3d, max: &Coord3d) -> Self
+    {
+        Coord3d {
+            x: self.x.max(min.x).min(max.x),
+            y: self.y.max(min.y).min(max.y),
+            z: self.z.max(min.z).min(max.z)
+        }
+    }
 }
 
 impl Pnt3d {
@@ -179,7 +273,7 @@ impl Pnt3d {
     ///
     pub fn translate_by(&self, v: &Vec3d) -> Self
     {
-        Pnt3d { coords: self.coords.add_out(1.0, &v.coords) }
+        self + v
     }
 
     /// Creating a vector pointing to an input point.
@@ -188,7 +282,54 @@ impl Pnt3d {
     ///
     pub fn to(&self, p: &Pnt3d) -> Vec3d
     {
-        Vec3d { coords: p.coords.add_out(-1.0, &self.coords) }
+        p - self
+    }
+
+    /// Creating new point by linear interpolation with another point. The interpolation is
+    /// exact at both ends and extrapolates for a parameter outside of [0, 1].
+    ///
+    /// * `other` - Point reached for a parameter equal to one.
+    /// * `t` - Interpolation parameter.
+    ///
+    pub fn lerp(&self, other: &Pnt3d, t: f64) -> Self
+    {
+        Pnt3d { coords: self.coords.mlt_add_out(1.0 - t, t, &other.coords) }
+    }
+
+    /// Creating new point at the middle of the segment joining another point.
+    ///
+    /// * `other` - Second end of the segment.
+    ///
+    pub fn midpoint_with(&self, other: &Pnt3d) -> Self
+    {
+        self.lerp(other, 0.5)
+    }
+
+    /// Creating new point by clamping the point inside an axis-aligned bounding box.
+    ///
+    /// * `bbox` - Input bounding box the point is clamped to.
+    ///
+    pub fn clamp_to_box(&self, bbox: &BoundingBox3d) -> Self
+    {
+        Pnt3d { coords: self.coords.clamp(&bbox.min, &bbox.max) }
+    }
+
+    /// Creating new point by rotating the point around an axis, using Rodrigues' rotation formula.
+    /// Rotation follows the right-hand rule around the axis direction.
+    ///
+    /// * `origin` - Point the rotation axis passes through.
+    /// * `axis` - Direction of the rotation axis.
+    /// * `angle` - Rotation angle in radians.
+    ///
+    pub fn rotate_around(&self, origin: &Pnt3d, axis: &Dir3d, angle: f64) -> Self
+    {
+        let v = origin.to(self);
+        let k = Vec3d { coords: axis.coords.clone() };
+        let (cos, sin) = (angle.cos(), angle.sin());
+        let rotated = v.coords.amplify_out(cos)
+            .add_out(sin, &k.cross_out(&v).coords)
+            .add_out(k.dot(&v) * (1.0 - cos), &k.coords);
+        Pnt3d { coords: origin.coords.add_out(1.0, &rotated) }
     }
 }
 
@@ -202,6 +343,15 @@ impl Vec3d {
         Vec3d{ coords: Coord3d::new(coords) }
     }
 
+    /// Computing dot product with another vector.
+    ///
+    /// * `v` - Second vector used for dot product.
+    ///
+    pub fn dot(&self, v: &Vec3d) -> f64
+    {
+        self.coords.dot(&v.coords)
+    }
+
     /// Creating a vector by applying cross product. Out-of-place function.
     ///
     /// * `v` - Second vector used for cross product.
@@ -215,6 +365,27 @@ impl Vec3d {
         ])
     }
 
+    /// Computing angle in radians with another vector, in [0, pi]. The angle is computed from
+    /// both the cross and dot products, which remains accurate for nearly parallel vectors.
+    /// If one of the vectors has zero length, the returned angle is 0.
+    ///
+    /// * `v` - Second vector used for angle computation.
+    ///
+    pub fn angle_to(&self, v: &Vec3d) -> f64
+    {
+        self.cross_out(v).coords.norm().atan2(self.dot(v))
+    }
+
+    /// Creating new vector by linear interpolation with another vector, componentwise.
+    ///
+    /// * `v` - Vector reached for a parameter equal to one.
+    /// * `t` - Interpolation parameter.
+    ///
+    pub fn lerp(&self, v: &Vec3d, t: f64) -> Vec3d
+    {
+        Vec3d { coords: self.coords.mlt_add_out(1.0 - t, t, &v.coords) }
+    }
+
     /// Creating new direction by normalizing the vector. Out-of-place function.
     ///
     pub fn normalize_out(&self) -> Dir3d
@@ -223,3 +394,895 @@ impl Vec3d {
         Dir3d{ coords: self.coords.amplify_out(1.0 / norm) }
     }
 }
+
+impl Dir3d {
+    /// Computing dot product with another direction.
+    ///
+    /// * `d` - Second direction used for dot product.
+    ///
+    pub fn dot(&self, d: &Dir3d) -> f64
+    {
+        self.coords.dot(&d.coords)
+    }
+
+    /// Computing angle in radians with another direction, in [0, pi]. The dot product is clamped
+    /// to [-1, 1] before computing its arc cosine.
+    ///
+    /// * `d` - Second direction used for angle computation.
+    ///
+    pub fn angle_to(&self, d: &Dir3d) -> f64
+    {
+        self.dot(d).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Creating new direction by normalizing the linear interpolation with another direction.
+    /// The result is undefined when the interpolated vector vanishes, i.e. for opposite
+    /// directions at their middle.
+    ///
+    /// * `d` - Direction reached for a parameter equal to one.
+    /// * `t` - Interpolation parameter.
+    ///
+    pub fn nlerp(&self, d: &Dir3d, t: f64) -> Dir3d
+    {
+        Vec3d { coords: self.coords.mlt_add_out(1.0 - t, t, &d.coords) }.normalize_out()
+    }
+
+    /// Creating new direction by spherical interpolation with another direction, i.e. rotating
+    /// at constant angular speed along the great circle joining them. Nearly parallel directions
+    /// fall back to `nlerp`, and nearly opposite directions are joined through the first
+    /// direction of `orthonormal_basis`.
+    ///
+    /// * `d` - Direction reached for a parameter equal to one.
+    /// * `t` - Interpolation parameter.
+    ///
+    pub fn slerp(&self, d: &Dir3d, t: f64) -> Dir3d
+    {
+        let angle = self.angle_to(d);
+        let sin = angle.sin();
+        if sin > 1e-6 {
+            let (a, b) = (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin);
+            return Dir3d { coords: self.coords.mlt_add_out(a, b, &d.coords) };
+        }
+        if self.dot(d) > 0.0 { return self.nlerp(d, t); }
+        let (u, _) = self.orthonormal_basis();
+        let phi = t * std::f64::consts::PI;
+        Dir3d { coords: self.coords.mlt_add_out(phi.cos(), phi.sin(), &u.coords) }
+    }
+
+    /// Computing two directions forming a right-handed orthonormal frame with the direction,
+    /// i.e. such that the cross product of the first by the second one is the direction. The
+    /// branchless construction of Duff et al. remains stable for directions aligned with an axis.
+    ///
+    pub fn orthonormal_basis(&self) -> (Dir3d, Dir3d)
+    {
+        let n = &self.coords;
+        let sign = 1f64.copysign(n.z);
+        let a = -1.0 / (sign + n.z);
+        let b = n.x * n.y * a;
+        (
+            Dir3d { coords: Coord3d::new([1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x]) },
+            Dir3d { coords: Coord3d::new([b, sign + n.y * n.y * a, -n.y]) }
+        )
+    }
+}
+
+impl BoundingBox3d {
+    /// Creating new bounding box from its lower and upper corners.
+    ///
+    /// * `min` - Coordinates of the lower corner.
+    /// * `max` - Coordinates of the upper corner.
+    ///
+    pub fn new(min: [f64; 3], max: [f64; 3]) -> Self
+    {
+        BoundingBox3d { min: Coord3d::new(min), max: Coord3d::new(max) }
+    }
+
+    /// Creating the smallest bounding box containing a set of points. Returns `None` if no point
+    /// is given.
+    ///
+    /// * `points` - Input points, e.g. a slice of points or an iterator over point references.
+    ///
+    pub fn from_points<'a, I>(points: I) -> Option<Self> where I: IntoIterator<Item = &'a Pnt3d>
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bbox = BoundingBox3d { min: first.coords.clone(), max: first.coords.clone() };
+        for p in points { bbox.extend(p); }
+        Some(bbox)
+    }
+
+    /// Extending the bounding box so that it contains a point. In-place function.
+    ///
+    /// * `p` - Point to include in the box.
+    ///
+    pub fn extend(&mut self, p: &Pnt3d) -> &mut Self
+    {
+        self.min.x = self.min.x.min(p.coords.x); self.max.x = self.max.x.max(p.coords.x);
+        self.min.y = self.min.y.min(p.coords.y); self.max.y = self.max.y.max(p.coords.y);
+        self.min.z = self.min.z.min(p.coords.z); self.max.z = self.max.z.max(p.coords.z);
+        self
+    }
+
+    /// Checking whether a point lies inside the bounding box enlarged by a tolerance.
+    ///
+    /// * `p` - Point to locate.
+    /// * `tol` - Tolerance on the coordinates.
+    ///
+    pub fn contains(&self, p: &Pnt3d, tol: f64) -> bool
+    {
+        p.coords.x >= self.min.x - tol && p.coords.x <= self.max.x + tol &&
+        p.coords.y >= self.min.y - tol && p.coords.y <= self.max.y + tol &&
+        p.coords.z >= self.min.z - tol && p.coords.z <= self.max.z + tol
+    }
+
+    /// Checking whether the bounding box intersects another one, boxes touching on their
+    /// boundaries being considered as intersecting.
+    ///
+    /// * `other` - Second bounding box.
+    ///
+    pub fn intersects(&self, other: &BoundingBox3d) -> bool
+    {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y &&
+        self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// Computing center of the bounding box.
+    ///
+    pub fn center(&self) -> Pnt3d
+    {
+        Pnt3d { coords: self.min.mlt_add_out(0.5, 0.5, &self.max) }
+    }
+
+    /// Computing length of the diagonal of the bounding box.
+    ///
+    pub fn diagonal_length(&self) -> f64
+    {
+        self.max.add_out(-1.0, &self.min).norm()
+    }
+}
+
+impl Mat3 {
+    /// Creating new matrix from its rows.
+    ///
+    /// * `rows` - Rows of the matrix.
+    ///
+    pub fn new(rows: [[f64; 3]; 3]) -> Self
+    {
+        Mat3 { rows }
+    }
+
+    /// Creating the identity matrix.
+    ///
+    pub fn identity() -> Self
+    {
+        Mat3 { rows: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] }
+    }
+
+    /// Creating the matrix of a rotation around an axis, following the right-hand rule.
+    ///
+    /// * `axis` - Direction of the rotation axis.
+    /// * `angle` - Rotation angle in radians.
+    ///
+    pub fn from_axis_angle(axis: &Dir3d, angle: f64) -> Self
+    {
+        let (x, y, z) = (axis.coords.x, axis.coords.y, axis.coords.z);
+        let (cos, sin) = (angle.cos(), angle.sin());
+        let c = 1.0 - cos;
+        Mat3 { rows: [
+            [cos + x * x * c, x * y * c - z * sin, x * z * c + y * sin],
+            [y * x * c + z * sin, cos + y * y * c, y * z * c - x * sin],
+            [z * x * c - y * sin, z * y * c + x * sin, cos + z * z * c]
+        ]}
+    }
+
+    /// Computing determinant of the matrix.
+    ///
+    pub fn determinant(&self) -> f64
+    {
+        let m = &self.rows;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Computing product with another matrix, the other matrix being on the right.
+    ///
+    /// * `other` - Right operand of the product.
+    ///
+    pub fn mlt(&self, other: &Mat3) -> Self
+    {
+        let mut rows = [[0.0; 3]; 3];
+        for (row, lhs) in rows.iter_mut().zip(&self.rows) {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = lhs[0] * other.rows[0][j] + lhs[1] * other.rows[1][j] + lhs[2] * other.rows[2][j];
+            }
+        }
+        Mat3 { rows }
+    }
+
+    /// Computing product of the matrix with coordinates.
+    ///
+    /// * `c` - Coordinates to multiply.
+    ///
+    pub fn apply_to(&self, c: &Coord3d) -> Coord3d
+    {
+        let m = &self.rows;
+        Coord3d {
+            x: m[0][0] * c.x + m[0][1] * c.y + m[0][2] * c.z,
+            y: m[1][0] * c.x + m[1][1] * c.y + m[1][2] * c.z,
+            z: m[2][0] * c.x + m[2][1] * c.y + m[2][2] * c.z
+        }
+    }
+
+    /// Computing inverse of the matrix. Returns `None` if the matrix is singular, i.e. when its
+    /// determinant is negligible with respect to the product of the norms of its rows.
+    ///
+    pub fn inverse(&self) -> Option<Self>
+    {
+        let m = &self.rows;
+        let det = self.determinant();
+        let scale: f64 = m.iter().map(|r| (r[0] * r[0] + r[1] * r[1] + r[2] * r[2]).sqrt()).product();
+        if det.abs() <= GEOMETRICAL_TOLERANCE * scale { return None; }
+        let cofactor = |i: usize, j: usize| {
+            let (i0, i1, j0, j1) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+            m[i0][j0] * m[i1][j1] - m[i0][j1] * m[i1][j0]
+        };
+        let mut rows = [[0.0; 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() { *x = cofactor(j, i) / det; }
+        }
+        Some(Mat3 { rows })
+    }
+}
+
+impl Transform3d {
+    /// Creating new transformation from its linear and translation parts.
+    ///
+    /// * `linear` - Linear part of the transformation.
+    /// * `translation` - Translation part of the transformation.
+    ///
+    pub fn new(linear: Mat3, translation: Vec3d) -> Self
+    {
+        Transform3d { linear, translation }
+    }
+
+    /// Creating the identity transformation.
+    ///
+    pub fn identity() -> Self
+    {
+        Transform3d { linear: Mat3::identity(), translation: Vec3d::default() }
+    }
+
+    /// Composing the transformation with another one. The resulting transformation applies
+    /// `other` first, then the calling transformation.
+    ///
+    /// * `other` - Transformation applied first.
+    ///
+    pub fn compose(&self, other: &Transform3d) -> Self
+    {
+        Transform3d {
+            linear: self.linear.mlt(&other.linear),
+            translation: self.apply_to_vector(&other.translation) + &self.translation
+        }
+    }
+
+    /// Applying the transformation to a point.
+    ///
+    /// * `p` - Point to transform.
+    ///
+    pub fn apply_to_point(&self, p: &Pnt3d) -> Pnt3d
+    {
+        Pnt3d { coords: self.linear.apply_to(&p.coords).add_out(1.0, &self.translation.coords) }
+    }
+
+    /// Applying the transformation to a vector, which is only affected by the linear part.
+    ///
+    /// * `v` - Vector to transform.
+    ///
+    pub fn apply_to_vector(&self, v: &Vec3d) -> Vec3d
+    {
+        Vec3d { coords: self.linear.apply_to(&v.coords) }
+    }
+
+    /// Computing inverse of the transformation. Returns `None` if its linear part is singular.
+    ///
+    pub fn inverse(&self) -> Option<Self>
+    {
+        let linear = self.linear.inverse()?;
+        let translation = Vec3d { coords: linear.apply_to(&self.translation.coords).amplify_out(-1.0) };
+        Some(Transform3d { linear, translation })
+    }
+}
+
+/// Computing barycenter of 3d points, i.e. the arithmetic mean of their coordinates.
+/// Panics if no point is given.
+///
+/// * `points` - Input references to points.
+///
+pub fn barycenter_3d(points: &[&Pnt3d]) -> Pnt3d
+{
+    assert!(!points.is_empty(), "cannot compute the barycenter of an empty set of points");
+    let mut coords = Coord3d::default();
+    for p in points { coords.add_in(1.0, &p.coords); }
+    Pnt3d { coords: coords.amplify_out(1.0 / (points.len() as f64)) }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D data structure.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Structure for defining 2d coordinates.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Coord2d {
+    /// First coordinate.
+    pub x: f64,
+    /// Second coordinate.
+    pub y: f64,
+}
+
+/// Structure for defining 2d points.
+///
+/// Points and vectors support the usual affine arithmetic, on owned values or references:
+///
+/// ```
+/// use mersh::base::*;
+///
+/// let p = Pnt2d::new([0., 0.]);
+/// let q = Pnt2d::new([2., 4.]);
+/// let m = &p + (&q - &p) * 0.5;
+/// assert!(m.coords.equals(&Coord2d::new([1., 2.]), GEOMETRICAL_TOLERANCE));
+/// ```
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Pnt2d {
+    /// Coordinates associated to the point.
+    pub coords: Coord2d,
+}
+
+/// Structure for defining 2d vectors.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Vec2d {
+    /// Coordinates associated to the vector.
+    pub coords: Coord2d,
+}
+
+/// Structure for defining 2d directions (i.e. unit vectors).
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Dir2d {
+    /// Coordinates associated to the direction.
+    pub coords: Coord2d,
+}
+
+/// Structure for defining 2d axis-aligned bounding boxes.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct BoundingBox2d {
+    /// Coordinates of the lower corner of the box.
+    pub min: Coord2d,
+    /// Coordinates of the upper corner of the box.
+    pub max: Coord2d,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl std::fmt::Display for Coord2d {
+    /// Implementing display for 2d coordinates. The number of decimal is taken from the
+    /// formatter precision (e.g. `{:.3}`) and is set to 6 by default.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let precision = formatter.precision().unwrap_or(6);
+        write!(formatter, "({:.*}, {:.*})", precision, self.x, precision, self.y)
+    }
+}
+
+impl std::fmt::Display for Pnt2d {
+    /// Implementing display for 2d points using the display of their coordinates.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.coords.fmt(formatter)
+    }
+}
+
+impl std::fmt::Display for Vec2d {
+    /// Implementing display for 2d vectors using the display of their coordinates.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.coords.fmt(formatter)
+    }
+}
+
+impl std::fmt::Display for Dir2d {
+    /// Implementing display for 2d directions using the display of their coordinates.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.coords.fmt(formatter)
+    }
+}
+
+impl Coord2d {
+    /// Creating new coordinates.
+    ///
+    /// * `xy` - associated coordinates values.
+    ///
+    pub fn new(xy: [f64; 2]) -> Self
+    {
+        Coord2d { x: xy[0], y: xy[1] }
+    }
+
+    /// Amplifying coordinates by a scalar coefficient. In-place function.
+    ///
+    /// * `a` - Scalar coefficient used for amplification.
+    ///
+    pub fn amplify_in(&mut self, a: f64) -> &mut Self
+    {
+        self.x *= a; self.y *= a; self
+    }
+
+    /// Amplifying coordinates by a scalar coefficient. Out-of-place function.
+    ///
+    /// * `a` - Scalar coefficient used for amplification.
+    ///
+    pub fn amplify_out(&self, a: f64) -> Self
+    {
+        Coord2d { x: a * self.x, y: a * self.y }
+    }
+
+    /// Adding potentially amplified coordinate to coordinate. In-place function.
+    ///
+    /// * `a` - Coefficient applied on input coordinate.
+    /// * `c` - Coordinate to add.
+    ///
+    pub fn add_in(&mut self, a: f64, c: &Coord2d) -> &mut Self
+    {
+        self.x += a * c.x; self.y += a * c.y; self
+    }
+
+    /// Adding potentially amplified coordinate to coordinate. Out-of-place function.
+    ///
+    /// * `a` - Coefficient applied on input coordinate.
+    /// * `c` - Coordinate to add.
+    ///
+    pub fn add_out(&self, a: f64, c: &Coord2d) -> Self
+    {
+        Coord2d { x: self.x + a * c.x, y: self.y + a * c.y }
+    }
+
+    /// Computing 2d coordinate using linear combination of two coordinates. In-place function.
+    ///
+    /// * `a` - First scalar coefficient in combination applied on calling instance.
+    /// * `b` - Second scalar coefficient in combination.
+    /// * `c` - Second coordinate in combination.
+    ///
+    pub fn mlt_add_in(&mut self, a: f64, b: f64, c: &Coord2d) -> &mut Self
+    {
+        self.x = a * self.x + b * c.x; self.y = a * self.y + b * c.y;
+        self
+    }
+
+    /// Creating 2d coordinate using linear combination of two coordinates. Out-of-place function.
+    ///
+    /// * `a` - First scalar coefficient in combination.
+    /// * `b` - Second scalar coefficient in combination.
+    /// * `c` - Second coordinate in combination.
+    ///
+    pub fn mlt_add_out(&self, a: f64, b: f64, c: &Coord2d) -> Self
+    {
+        Coord2d { x: a * self.x + b * c.x, y: a * self.y + b * c.y }
+    }
+
+    /// Comparing a coordinate with another one using a fixed epsilon. The comparison is done by
+    /// computing the square norm of the difference between the two coordinate.
+    ///
+    /// * `c` - Coordinate to compare with.
+    /// * `eps` - Threshold used for fixed-epsilon floating point comparison.
+    ///
+    pub fn equals(&self, c: &Coord2d, eps: f64) -> bool
+    {
+        self.add_out(-1.0, c).sq_norm() < eps
+    }
+
+    /// Computing dot product with another 2d coordinate.
+    ///
+    /// * `c` - Second coordinate used for dot product.
+    ///
+    pub fn dot(&self, c: &Coord2d) -> f64
+    {
+        self.x * c.x + self.y * c.y
+    }
+
+    /// Computing square norm of a 2d coordinate.
+    ///
+    pub fn sq_norm(&self) -> f64
+    {
+        self.dot(self)
+    }
+
+    /// Computing norm of a 2d coordinate.
+    ///
+    pub fn norm(&self) -> f64
+    {
+         self.sq_norm().sqrt()
+    }
+
+    /// Clamping each component of a coordinate to a range. Out-of-place function.
+    ///
+    /// * `min` - Lower bounds of the range.
+    /// * `max` - Upper bounds of the range.
+    ///
+    pub fn clamp(&self, min: &Coord2d, max: &Coord2d) -> Self
+    {
+        Coord2d {
+            x: self.x.max(min.x).min(max.x),
+            y: self.y.max(min.y).min(max.y)
+        }
+    }
+}
+
+impl Pnt2d {
+    /// Creating new point from coordinates.
+    ///
+    /// * `coords` - associated coordinates.
+    ///
+    pub fn new(coords: [f64; 2]) -> Self
+    {
+        Pnt2d{ coords: Coord2d::new(coords) }
+    }
+
+    /// Computing distance to another 2d points.
+    ///
+    /// * `q` - Input 2d point to compute the distance from.
+    ///
+    pub fn distance_to(&self, q: &Pnt2d) -> f64
+    {
+        self.coords.add_out(-1.0, &q.coords).norm()
+    }
+
+    /// Creating new point by applying translation defined from an input vector.
+    ///
+    /// * `v` - Input vector used to create point.
+    ///
+    pub fn translate_by(&self, v: &Vec2d) -> Self
+    {
+        self + v
+    }
+
+    /// Creating a vector pointing to an input point.
+    ///
+    /// * `p` - The point to point to.
+    ///
+    pub fn to(&self, p: &Pnt2d) -> Vec2d
+    {
+        p - self
+    }
+
+    /// Creating new point by linear interpolation with another point. The interpolation is
+    /// exact at both ends and extrapolates for a parameter outside of [0, 1].
+    ///
+    /// * `other` - Point reached for a parameter equal to one.
+    /// * `t` - Interpolation parameter.
+    ///
+    pub fn lerp(&self, other: &Pnt2d, t: f64) -> Self
+    {
+        Pnt2d { coords: self.coords.mlt_add_out(1.0 - t, t, &other.coords) }
+    }
+
+    /// Creating new point at the middle of the segment joining another point.
+    ///
+    /// * `other` - Second end of the segment.
+    ///
+    pub fn midpoint_with(&self, other: &Pnt2d) -> Self
+    {
+        self.lerp(other, 0.5)
+    }
+
+    /// Creating new point by clamping the point inside an axis-aligned bounding box.
+    ///
+    /// * `bbox` - Input bounding box the point is clamped to.
+    ///
+    pub fn clamp_to_box(&self, bbox: &BoundingBox2d) -> Self
+    {
+        Pnt2d { coords: self.coords.clamp(&bbox.min, &bbox.max) }
+    }
+
+    /// Creating new point by rotating the point around an origin, counterclockwise for a
+    /// positive angle.
+    ///
+    /// * `origin` - Center of the rotation.
+    /// * `angle` - Rotation angle in radians.
+    ///
+    pub fn rotate_around(&self, origin: &Pnt2d, angle: f64) -> Self
+    {
+        let v = origin.to(self);
+        let (cos, sin) = (angle.cos(), angle.sin());
+        Pnt2d { coords: Coord2d {
+            x: origin.coords.x + cos * v.coords.x - sin * v.coords.y,
+            y: origin.coords.y + sin * v.coords.x + cos * v.coords.y
+        }}
+    }
+}
+
+impl Vec2d {
+    /// Creating new vector from coordinates.
+    ///
+    /// * `coords` - Associated coordinate.
+    ///
+    pub fn new(coords: [f64; 2]) -> Self
+    {
+        Vec2d{ coords: Coord2d::new(coords) }
+    }
+
+    /// Computing dot product with another vector.
+    ///
+    /// * `v` - Second vector used for dot product.
+    ///
+    pub fn dot(&self, v: &Vec2d) -> f64
+    {
+        self.coords.dot(&v.coords)
+    }
+
+    /// Computing cross product with another vector. The result is the scalar component of the
+    /// cross product along the axis orthogonal to the plane.
+    ///
+    /// * `v` - Second vector used for cross product.
+    ///
+    pub fn cross_out(&self, v: &Vec2d) -> f64
+    {
+        self.coords.x * v.coords.y - self.coords.y * v.coords.x
+    }
+
+    /// Computing angle in radians with another vector, in [0, pi]. The angle is computed from
+    /// both the cross and dot products, as for 3d vectors. If one of the vectors has zero
+    /// length, the returned angle is 0.
+    ///
+    /// * `v` - Second vector used for angle computation.
+    ///
+    pub fn angle_to(&self, v: &Vec2d) -> f64
+    {
+        self.cross_out(v).abs().atan2(self.dot(v))
+    }
+
+    /// Creating new direction by normalizing the vector. Out-of-place function.
+    ///
+    pub fn normalize_out(&self) -> Dir2d
+    {
+        let norm = self.coords.norm();
+        Dir2d{ coords: self.coords.amplify_out(1.0 / norm) }
+    }
+}
+
+impl BoundingBox2d {
+    /// Creating new bounding box from its lower and upper corners.
+    ///
+    /// * `min` - Coordinates of the lower corner.
+    /// * `max` - Coordinates of the upper corner.
+    ///
+    pub fn new(min: [f64; 2], max: [f64; 2]) -> Self
+    {
+        BoundingBox2d { min: Coord2d::new(min), max: Coord2d::new(max) }
+    }
+
+    /// Creating the smallest bounding box containing a set of points. Returns `None` if no point
+    /// is given.
+    ///
+    /// * `points` - Input points, e.g. a slice of points or an iterator over point references.
+    ///
+    pub fn from_points<'a, I>(points: I) -> Option<Self> where I: IntoIterator<Item = &'a Pnt2d>
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bbox = BoundingBox2d { min: first.coords.clone(), max: first.coords.clone() };
+        for p in points { bbox.extend(p); }
+        Some(bbox)
+    }
+
+    /// Extending the bounding box so that it contains a point. In-place function.
+    ///
+    /// * `p` - Point to include in the box.
+    ///
+    pub fn extend(&mut self, p: &Pnt2d) -> &mut Self
+    {
+        self.min.x = self.min.x.min(p.coords.x); self.max.x = self.max.x.max(p.coords.x);
+        self.min.y = self.min.y.min(p.coords.y); self.max.y = self.max.y.max(p.coords.y);
+        self
+    }
+
+    /// Checking whether a point lies inside the bounding box enlarged by a tolerance.
+    ///
+    /// * `p` - Point to locate.
+    /// * `tol` - Tolerance on the coordinates.
+    ///
+    pub fn contains(&self, p: &Pnt2d, tol: f64) -> bool
+    {
+        p.coords.x >= self.min.x - tol && p.coords.x <= self.max.x + tol &&
+        p.coords.y >= self.min.y - tol && p.coords.y <= self.max.y + tol
+    }
+
+    /// Checking whether the bounding box intersects another one, boxes touching on their
+    /// boundaries being considered as intersecting.
+    ///
+    /// * `other` - Second bounding box.
+    ///
+    pub fn intersects(&self, other: &BoundingBox2d) -> bool
+    {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
+
+    /// Computing center of the bounding box.
+    ///
+    pub fn center(&self) -> Pnt2d
+    {
+        Pnt2d { coords: self.min.mlt_add_out(0.5, 0.5, &self.max) }
+    }
+
+    /// Computing length of the diagonal of the bounding box.
+    ///
+    pub fn diagonal_length(&self) -> f64
+    {
+        self.max.add_out(-1.0, &self.min).norm()
+    }
+}
+
+impl Dir2d {
+    /// Computing dot product with another direction.
+    ///
+    /// * `d` - Second direction used for dot product.
+    ///
+    pub fn dot(&self, d: &Dir2d) -> f64
+    {
+        self.coords.dot(&d.coords)
+    }
+
+    /// Computing angle in radians with another direction, in [0, pi]. The dot product is clamped
+    /// to [-1, 1] before computing its arc cosine.
+    ///
+    /// * `d` - Second direction used for angle computation.
+    ///
+    pub fn angle_to(&self, d: &Dir2d) -> f64
+    {
+        self.dot(d).clamp(-1.0, 1.0).acos()
+    }
+}
+
+/// Computing barycenter of 2d points, i.e. the arithmetic mean of their coordinates.
+/// Panics if no point is given.
+///
+/// * `points` - Input references to points.
+///
+pub fn barycenter_2d(points: &[&Pnt2d]) -> Pnt2d
+{
+    assert!(!points.is_empty(), "cannot compute the barycenter of an empty set of points");
+    let mut coords = Coord2d::default();
+    for p in points { coords.add_in(1.0, &p.coords); }
+    Pnt2d { coords: coords.amplify_out(1.0 / (points.len() as f64)) }
+}
+
+/// Computing orientation of three 2d points, i.e. twice the signed area of the triangle they
+/// define. Positive when counterclockwise, negative when clockwise and zero when collinear.
+///
+/// * `a` - First point.
+/// * `b` - Second point.
+/// * `c` - Third point.
+///
+pub fn orient2d(a: &Pnt2d, b: &Pnt2d, c: &Pnt2d) -> f64
+{
+    (b.coords.x - a.coords.x) * (c.coords.y - a.coords.y) - (b.coords.y - a.coords.y) * (c.coords.x - a.coords.x)
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Operators.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Implementing a binary operator for all combinations of owned and borrowed operands, from a
+// function on borrowed operands.
+macro_rules! impl_binary_operator {
+    ($op:ident, $method:ident, $lhs:ty, $rhs:ty, $output:ty, $f:expr) => {
+        impl<'a, 'b> std::ops::$op<&'b $rhs> for &'a $lhs {
+            type Output = $output;
+            fn $method(self, rhs: &'b $rhs) -> $output { let f: fn(&$lhs, &$rhs) -> $output = $f; f(self, rhs) }
+        }
+        impl<'b> std::ops::$op<&'b $rhs> for $lhs {
+            type Output = $output;
+            fn $method(self, rhs: &'b $rhs) -> $output { std::ops::$op::$method(&self, rhs) }
+        }
+        impl<'a> std::ops::$op<$rhs> for &'a $lhs {
+            type Output = $output;
+            fn $method(self, rhs: $rhs) -> $output { std::ops::$op::$method(self, &rhs) }
+        }
+        impl std::ops::$op<$rhs> for $lhs {
+            type Output = $output;
+            fn $method(self, rhs: $rhs) -> $output { std::ops::$op::$method(&self, &rhs) }
+        }
+    }
+}
+
+// Implementing a binary operator with a scalar right operand, for owned and borrowed left
+// operands, from a function on a borrowed left operand.
+macro_rules! impl_scalar_operator {
+    ($op:ident, $method:ident, $lhs:ty, $f:expr) => {
+        impl<'a> std::ops::$op<f64> for &'a $lhs {
+            type Output = $lhs;
+            fn $method(self, a: f64) -> $lhs { let f: fn(&$lhs, f64) -> $lhs = $f; f(self, a) }
+        }
+        impl std::ops::$op<f64> for $lhs {
+            type Output = $lhs;
+            fn $method(self, a: f64) -> $lhs { std::ops::$op::$method(&self, a) }
+        }
+    }
+}
+
+// Implementing negation for owned and borrowed operands, from a function on a borrowed operand.
+macro_rules! impl_neg_operator {
+    ($t:ty, $f:expr) => {
+        impl<'a> std::ops::Neg for &'a $t {
+            type Output = $t;
+            fn neg(self) -> $t { let f: fn(&$t) -> $t = $f; f(self) }
+        }
+        impl std::ops::Neg for $t {
+            type Output = $t;
+            fn neg(self) -> $t { -&self }
+        }
+    }
+}
+
+// Implementing the multiplication by a scalar on the left, for owned and borrowed right
+// operands, from the multiplication by a scalar on the right.
+macro_rules! impl_left_scalar_mul_operator {
+    ($t:ty) => {
+        impl<'a> std::ops::Mul<&'a $t> for f64 {
+            type Output = $t;
+            fn mul(self, rhs: &'a $t) -> $t { rhs * self }
+        }
+        impl std::ops::Mul<$t> for f64 {
+            type Output = $t;
+            fn mul(self, rhs: $t) -> $t { &rhs * self }
+        }
+    }
+}
+
+impl_binary_operator!(Add, add, Pnt3d, Vec3d, Pnt3d, |p, v| Pnt3d { coords: p.coords.add_out(1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Pnt3d, Vec3d, Pnt3d, |p, v| Pnt3d { coords: p.coords.add_out(-1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Pnt3d, Pnt3d, Vec3d, |p, q| Vec3d { coords: p.coords.add_out(-1.0, &q.coords) });
+impl_binary_operator!(Add, add, Vec3d, Vec3d, Vec3d, |u, v| Vec3d { coords: u.coords.add_out(1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Vec3d, Vec3d, Vec3d, |u, v| Vec3d { coords: u.coords.add_out(-1.0, &v.coords) });
+impl_neg_operator!(Vec3d, |v| Vec3d { coords: v.coords.amplify_out(-1.0) });
+impl_scalar_operator!(Mul, mul, Vec3d, |v, a| Vec3d { coords: v.coords.amplify_out(a) });
+impl_scalar_operator!(Div, div, Vec3d, |v, a| Vec3d { coords: v.coords.amplify_out(1.0 / a) });
+impl_scalar_operator!(Mul, mul, Coord3d, |c, a| c.amplify_out(a));
+impl_scalar_operator!(Div, div, Coord3d, |c, a| c.amplify_out(1.0 / a));
+impl_binary_operator!(Add, add, Coord3d, Coord3d, Coord3d, |c, d| c.add_out(1.0, d));
+impl_binary_operator!(Sub, sub, Coord3d, Coord3d, Coord3d, |c, d| c.add_out(-1.0, d));
+impl_neg_operator!(Coord3d, |c| c.amplify_out(-1.0));
+impl_left_scalar_mul_operator!(Vec3d);
+impl_left_scalar_mul_operator!(Coord3d);
+
+impl_binary_operator!(Add, add, Pnt2d, Vec2d, Pnt2d, |p, v| Pnt2d { coords: p.coords.add_out(1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Pnt2d, Vec2d, Pnt2d, |p, v| Pnt2d { coords: p.coords.add_out(-1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Pnt2d, Pnt2d, Vec2d, |p, q| Vec2d { coords: p.coords.add_out(-1.0, &q.coords) });
+impl_binary_operator!(Add, add, Vec2d, Vec2d, Vec2d, |u, v| Vec2d { coords: u.coords.add_out(1.0, &v.coords) });
+impl_binary_operator!(Sub, sub, Vec2d, Vec2d, Vec2d, |u, v| Vec2d { coords: u.coords.add_out(-1.0, &v.coords) });
+impl_neg_operator!(Vec2d, |v| Vec2d { coords: v.coords.amplify_out(-1.0) });
+impl_scalar_operator!(Mul, mul, Vec2d, |v, a| Vec2d { coords: v.coords.amplify_out(a) });
+impl_scalar_operator!(Div, div, Vec2d, |v, a| Vec2d { coords: v.coords.amplify_out(1.0 / a) });
+impl_scalar_operator!(Mul, mul, Coord2d, |c, a| c.amplify_out(a));
+impl_scalar_operator!(Div, div, Coord2d, |c, a| c.amplify_out(1.0 / a));
+impl_binary_operator!(Add, add, Coord2d, Coord2d, Coord2d, |c, d| c.add_out(1.0, d));
+impl_binary_operator!(Sub, sub, Coord2d, Coord2d, Coord2d, |c, d| c.add_out(-1.0, d));
+impl_neg_operator!(Coord2d, |c| c.amplify_out(-1.0));
+impl_left_scalar_mul_operator!(Vec2d);
+impl_left_scalar_mul_operator!(Coord2d);
@@ -0,0 +1,453 @@
+extern crate std;
+
+use super::base::*;
+use super::elements::*;
+use super::mesh::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::vec::*;
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Data structures.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Options driving quadric error metric decimation.
+#[derive(Clone, Debug)]
+pub struct QemOptions {
+    /// Name of the edge tag whose edges must be preserved. Vertices of these edges are never
+    /// moved nor removed by the decimation.
+    pub feature_tag: Option<String>,
+    /// Maximum quadric error allowed for a collapse. Decimation stops as soon as the cheapest
+    /// remaining collapse exceeds this value.
+    pub max_error: f64,
+    /// Weight of the quadrics constraining boundary edges.
+    pub boundary_weight: f64,
+}
+
+// Symmetric 4x4 quadric stored by its upper triangular part.
+#[derive(Clone, Copy, Default)]
+struct Quadric {
+    q: [f64; 10],
+}
+
+// Candidate edge collapse stored in the priority queue.
+struct Candidate {
+    cost: f64,
+    target: Coord3d,
+    kept: usize,
+    removed: usize,
+    stamps: (usize, usize),
+}
+
+// Working state of a decimation.
+struct Decimation {
+    positions: Vec<Coord3d>,
+    quadrics: Vec<Quadric>,
+    stamps: Vec<usize>,
+    locked: Vec<bool>,
+    boundary: Vec<bool>,
+    collapsed_into: Vec<Option<usize>>,
+    triangles: Vec<[usize; 3]>,
+    alive_triangles: Vec<bool>,
+    n_alive_triangles: usize,
+    vertex_triangles: Vec<Vec<usize>>,
+    queue: BinaryHeap<Candidate>,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Implementation.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Default for QemOptions {
+    /// Creating default options: no feature edges, unbounded error and stiff boundaries.
+    ///
+    fn default() -> Self
+    {
+        QemOptions { feature_tag: None, max_error: f64::INFINITY, boundary_weight: 1e3 }
+    }
+}
+
+impl Mesh3d {
+    /// Decimating a triangle surface using Garland-Heckbert quadric error simplification.
+    ///
+    /// Edges are collapsed by increasing quadric cost, the merged vertex being placed at the
+    /// quadric-optimal point or at the edge midpoint when the quadric is singular. Boundary
+    /// vertices are constrained by additional boundary-plane quadrics and are only merged onto
+    /// boundary vertices. Collapses changing the surface topology or flipping a triangle are
+    /// rejected. Only vertices, edges and triangles are carried to the decimated mesh, other
+    /// element families are dropped.
+    ///
+    /// * `target_triangles` - Number of triangles below which decimation stops.
+    /// * `options` - Options driving the decimation.
+    ///
+    pub fn decimate_qem(&self, target_triangles: usize, options: QemOptions) -> Mesh3d
+    {
+        let mut decimation = Decimation::new(self, &options);
+        decimation.run(target_triangles, options.max_error);
+        decimation.into_mesh(self)
+    }
+}
+
+impl Quadric {
+    // Creating the quadric associated to the plane n.x + d = 0, scaled by a weight.
+    fn from_plane(n: &Coord3d, d: f64, w: f64) -> Self
+    {
+        Quadric { q: [
+            w * n.x * n.x, w * n.x * n.y, w * n.x * n.z, w * n.x * d,
+            w * n.y * n.y, w * n.y * n.z, w * n.y * d,
+            w * n.z * n.z, w * n.z * d,
+            w * d * d
+        ]}
+    }
+
+    // Accumulating a quadric.
+    fn add_in(&mut self, other: &Quadric)
+    {
+        for (a, b) in self.q.iter_mut().zip(other.q.iter()) { *a += *b; }
+    }
+
+    // Summing two quadrics.
+    fn add_out(&self, other: &Quadric) -> Quadric
+    {
+        let mut sum = *self;
+        sum.add_in(other);
+        sum
+    }
+
+    // Evaluating the quadric error at a position.
+    fn eval(&self, p: &Coord3d) -> f64
+    {
+        let q = &self.q;
+        q[0] * p.x * p.x + 2.0 * q[1] * p.x * p.y + 2.0 * q[2] * p.x * p.z + 2.0 * q[3] * p.x
+            + q[4] * p.y * p.y + 2.0 * q[5] * p.y * p.z + 2.0 * q[6] * p.y
+            + q[7] * p.z * p.z + 2.0 * q[8] * p.z
+            + q[9]
+    }
+
+    // Computing the position minimizing the quadric, if the quadric is not singular.
+    fn optimal(&self) -> Option<Coord3d>
+    {
+        let q = &self.q;
+        let (a00, a01, a02, a11, a12, a22) = (q[0], q[1], q[2], q[4], q[5], q[7]);
+        let (b0, b1, b2) = (-q[3], -q[6], -q[8]);
+
+        let c00 = a11 * a22 - a12 * a12;
+        let c01 = a02 * a12 - a01 * a22;
+        let c02 = a01 * a12 - a02 * a11;
+        let det = a00 * c00 + a01 * c01 + a02 * c02;
+
+        let trace = a00 + a11 + a22;
+        if det.abs() <= 1e-6 * trace * trace * trace { return None; }
+
+        let c11 = a00 * a22 - a02 * a02;
+        let c12 = a01 * a02 - a00 * a12;
+        let c22 = a00 * a11 - a01 * a01;
+        Some(Coord3d {
+            x: (c00 * b0 + c01 * b1 + c02 * b2) / det,
+            y: (c01 * b0 + c11 * b1 + c12 * b2) / det,
+            z: (c02 * b0 + c12 * b1 + c22 * b2) / det
+        })
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Candidate {
+    // Reversing the ordering on costs so that the binary heap pops the cheapest collapse first.
+    fn cmp(&self, other: &Candidate) -> Ordering
+    {
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)
+            .then_with(|| other.kept.cmp(&self.kept))
+            .then_with(|| other.removed.cmp(&self.removed))
+    }
+}
+
+impl Decimation {
+    // Initializing quadrics, adjacency and collapse candidates from a mesh.
+    fn new(mesh: &Mesh3d, options: &QemOptions) -> Self
+    {
+        let n_vertices = mesh.vertices.len();
+        let triangles: Vec<[usize; 3]> = mesh.triangles.iter().map(|tri| tri.indexes).collect();
+
+        let mut decimation = Decimation {
+            positions: mesh.vertices.iter().map(|p| p.coords.clone()).collect(),
+            quadrics: vec![Quadric::default(); n_vertices],
+            stamps: vec![0; n_vertices],
+            locked: vec![false; n_vertices],
+            boundary: vec![false; n_vertices],
+            collapsed_into: vec![None; n_vertices],
+            alive_triangles: vec![true; triangles.len()],
+            n_alive_triangles: triangles.len(),
+            vertex_triangles: vec![Vec::new(); n_vertices],
+            triangles,
+            queue: BinaryHeap::new(),
+        };
+
+        // Accumulating area-weighted plane quadrics and counting edge occurrences.
+        let mut edge_count: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+        for (t, tri) in decimation.triangles.iter().enumerate() {
+            let normal = decimation.cross(tri);
+            let area = 0.5 * normal.norm();
+            if area > 0.0 {
+                let n = normal.amplify_out(0.5 / area);
+                let d = -n.dot(&decimation.positions[tri[0]]);
+                let quadric = Quadric::from_plane(&n, d, area);
+                for &v in tri { decimation.quadrics[v].add_in(&quadric); }
+            }
+            for &v in tri { decimation.vertex_triangles[v].push(t); }
+            for k in 0..3 {
+                let entry = edge_count.entry(sorted_pair(tri[k], tri[(k + 1) % 3])).or_insert((0, t));
+                entry.0 += 1;
+            }
+        }
+
+        // Constraining boundary edges with planes orthogonal to their triangle.
+        for (&(a, b), &(count, t)) in &edge_count {
+            if count != 1 { continue; }
+            let tri = decimation.triangles[t];
+            let normal = decimation.cross(&tri);
+            let edge = decimation.positions[b].add_out(-1.0, &decimation.positions[a]);
+            let plane = Vec3d { coords: edge.clone() }.cross_out(&Vec3d { coords: normal });
+            let norm = plane.coords.norm();
+            if norm <= 0.0 { continue; }
+            let n = plane.coords.amplify_out(1.0 / norm);
+            let d = -n.dot(&decimation.positions[a]);
+            let quadric = Quadric::from_plane(&n, d, options.boundary_weight * edge.sq_norm());
+            decimation.quadrics[a].add_in(&quadric);
+            decimation.quadrics[b].add_in(&quadric);
+            decimation.boundary[a] = true;
+            decimation.boundary[b] = true;
+        }
+
+        // Locking vertices of tagged feature edges.
+        if let Some(ref name) = options.feature_tag {
+            if let Some(indexes) = mesh.edges_tags.get_registered_indexes(name) {
+                for &e in indexes {
+                    for &v in &mesh.edges[e].indexes { decimation.locked[v] = true; }
+                }
+            }
+        }
+
+        let mut pairs: Vec<(usize, usize)> = edge_count.keys().cloned().collect();
+        pairs.sort();
+        for (a, b) in pairs { decimation.push_candidate(a, b); }
+        decimation
+    }
+
+    // Computing the unnormalized normal of a triangle.
+    fn cross(&self, tri: &[usize; 3]) -> Coord3d
+    {
+        let p0 = &self.positions[tri[0]];
+        let u = Vec3d { coords: self.positions[tri[1]].add_out(-1.0, p0) };
+        let v = Vec3d { coords: self.positions[tri[2]].add_out(-1.0, p0) };
+        u.cross_out(&v).coords
+    }
+
+    // Pushing a collapse candidate for the edge connecting two vertices. Locked vertices are
+    // never moved, and boundary vertices are only merged onto an endpoint so that the boundary
+    // keeps its original vertices.
+    fn push_candidate(&mut self, a: usize, b: usize)
+    {
+        if self.locked[a] && self.locked[b] { return; }
+        let quadric = self.quadrics[a].add_out(&self.quadrics[b]);
+        let (kept, removed) = match (self.is_pinned(a), self.is_pinned(b)) {
+            (true, false) => (a, b),
+            (false, true) => (b, a),
+            (true, true) if self.locked[a] => (a, b),
+            (true, true) if self.locked[b] => (b, a),
+            _ => if quadric.eval(&self.positions[b]) < quadric.eval(&self.positions[a]) { (b, a) } else { (a, b) }
+        };
+        let target = if self.is_pinned(kept) {
+            self.positions[kept].clone()
+        } else {
+            quadric.optimal().unwrap_or_else(|| self.positions[kept].mlt_add_out(0.5, 0.5, &self.positions[removed]))
+        };
+        self.queue.push(Candidate {
+            cost: quadric.eval(&target).max(0.0),
+            target,
+            kept,
+            removed,
+            stamps: (self.stamps[kept], self.stamps[removed])
+        });
+    }
+
+    // Checking whether a vertex position must be kept by collapses.
+    fn is_pinned(&self, v: usize) -> bool
+    {
+        self.locked[v] || self.boundary[v]
+    }
+
+    // Collecting the alive triangles incident to a vertex.
+    fn incident_triangles(&self, v: usize) -> Vec<usize>
+    {
+        self.vertex_triangles[v].iter().cloned().filter(|&t| self.alive_triangles[t]).collect()
+    }
+
+    // Collecting the neighbours of a vertex through alive triangles.
+    fn neighbours(&self, v: usize) -> HashSet<usize>
+    {
+        self.incident_triangles(v).iter()
+            .flat_map(|&t| self.triangles[t].iter().cloned())
+            .filter(|&w| w != v)
+            .collect()
+    }
+
+    // Checking that collapsing an edge keeps the surface topology and does not flip triangles.
+    fn is_valid_collapse(&self, candidate: &Candidate) -> bool
+    {
+        let (a, b) = (candidate.kept, candidate.removed);
+        let shared: Vec<usize> = self.incident_triangles(a).into_iter()
+            .filter(|&t| self.triangles[t].contains(&b))
+            .collect();
+        if shared.is_empty() { return false; }
+
+        // Link condition: common neighbours must be the opposite vertices of shared triangles.
+        let common = self.neighbours(a).intersection(&self.neighbours(b)).count();
+        if common != shared.len() { return false; }
+
+        // Collapsing an interior edge joining two boundary vertices would pinch the surface.
+        if shared.len() > 1 && self.boundary[a] && self.boundary[b] { return false; }
+
+        // Rejecting collapses flipping or degenerating the remaining triangles.
+        for v in &[a, b] {
+            for t in self.incident_triangles(*v) {
+                if shared.contains(&t) { continue; }
+                let tri = self.triangles[t];
+                let before = self.cross(&tri);
+                let mut moved = self.clone_positions_of(&tri);
+                for (k, &w) in tri.iter().enumerate() {
+                    if w == a || w == b { moved[k] = candidate.target.clone(); }
+                }
+                let u = Vec3d { coords: moved[1].add_out(-1.0, &moved[0]) };
+                let w = Vec3d { coords: moved[2].add_out(-1.0, &moved[0]) };
+                let after = u.cross_out(&w).coords;
+                if before.dot(&after) <= GEOMETRICAL_TOLERANCE * before.sq_norm() { return false; }
+            }
+        }
+        true
+    }
+
+    // Copying the positions of the vertices of a triangle.
+    fn clone_positions_of(&self, tri: &[usize; 3]) -> [Coord3d; 3]
+    {
+        [self.positions[tri[0]].clone(), self.positions[tri[1]].clone(), self.positions[tri[2]].clone()]
+    }
+
+    // Collapsing an edge by merging its removed vertex into its kept vertex.
+    fn collapse(&mut self, candidate: Candidate)
+    {
+        let (a, b) = (candidate.kept, candidate.removed);
+        for t in self.incident_triangles(b) {
+            if self.triangles[t].contains(&a) {
+                self.alive_triangles[t] = false;
+                self.n_alive_triangles -= 1;
+            } else {
+                for w in self.triangles[t].iter_mut() {
+                    if *w == b { *w = a; }
+                }
+                self.vertex_triangles[a].push(t);
+            }
+        }
+        self.vertex_triangles[b].clear();
+        let alive_triangles = &self.alive_triangles;
+        self.vertex_triangles[a].retain(|&t| alive_triangles[t]);
+
+        self.positions[a] = candidate.target;
+        let removed_quadric = self.quadrics[b];
+        self.quadrics[a].add_in(&removed_quadric);
+        self.boundary[a] = self.boundary[a] || self.boundary[b];
+        self.collapsed_into[b] = Some(a);
+        self.stamps[a] += 1;
+        self.stamps[b] += 1;
+
+        let mut neighbours: Vec<usize> = self.neighbours(a).into_iter().collect();
+        neighbours.sort();
+        for n in neighbours { self.push_candidate(a, n); }
+    }
+
+    // Collapsing edges until the number of triangles reaches the target.
+    fn run(&mut self, target_triangles: usize, max_error: f64)
+    {
+        while self.n_alive_triangles > target_triangles {
+            let candidate = match self.queue.pop() { Some(candidate) => candidate, None => break };
+            let (a, b) = (candidate.kept, candidate.removed);
+            if self.collapsed_into[a].is_some() || self.collapsed_into[b].is_some() { continue; }
+            if candidate.stamps != (self.stamps[a], self.stamps[b]) { continue; }
+            if candidate.cost > max_error { break; }
+            if self.is_valid_collapse(&candidate) { self.collapse(candidate); }
+        }
+    }
+
+    // Following collapses up to the surviving vertex.
+    fn representative(&self, mut v: usize) -> usize
+    {
+        while let Some(w) = self.collapsed_into[v] { v = w; }
+        v
+    }
+
+    // Building the decimated mesh, carrying vertices, edges, triangles and their tags.
+    fn into_mesh(self, mesh: &Mesh3d) -> Mesh3d
+    {
+        let mut decimated = Mesh3d::default();
+
+        let mut vertex_map = vec![None; self.positions.len()];
+        for (v, position) in self.positions.iter().enumerate() {
+            if self.collapsed_into[v].is_none() {
+                vertex_map[v] = Some(decimated.vertices.len());
+                decimated.vertices.push(Pnt3d { coords: position.clone() });
+            }
+        }
+        let merged_vertex_map: Vec<Option<usize>> = (0..self.positions.len())
+            .map(|v| vertex_map[self.representative(v)])
+            .collect();
+        decimated.vertices_tags = mesh.vertices_tags.remap(&merged_vertex_map);
+
+        let mut edge_map = vec![None; mesh.edges.len()];
+        for (e, edge) in mesh.edges.iter().enumerate() {
+            let a = vertex_map[self.representative(edge.indexes[0])];
+            let b = vertex_map[self.representative(edge.indexes[1])];
+            if let (Some(a), Some(b)) = (a, b) {
+                if a != b {
+                    edge_map[e] = Some(decimated.edges.len());
+                    decimated.edges.push(Edge::new([a, b]));
+                }
+            }
+        }
+        decimated.edges_tags = mesh.edges_tags.remap(&edge_map);
+
+        let mut triangle_map = vec![None; self.triangles.len()];
+        for (t, tri) in self.triangles.iter().enumerate() {
+            if !self.alive_triangles[t] { continue; }
+            if let (Some(a), Some(b), Some(c)) = (vertex_map[tri[0]], vertex_map[tri[1]], vertex_map[tri[2]]) {
+                triangle_map[t] = Some(decimated.triangles.len());
+                decimated.triangles.push(Tri::new([a, b, c]));
+            }
+        }
+        decimated.triangles_tags = mesh.triangles_tags.remap(&triangle_map);
+        decimated
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Ordering the vertex indexes of an edge.
+fn sorted_pair(a: usize, b: usize) -> (usize, usize)
+{
+    if a < b { (a, b) } else { (b, a) }
+}
@@ -53,6 +53,22 @@ pub struct Quad {
 }
 
 /// Tetrahedron as a mesh element.
+///
+/// Local numbering order of tetrahedron is such that P3 lies on the side pointed to by the
+/// normal of the triangle (P0, P1, P2), giving a positive signed volume :
+///
+/// ```text
+///          P3
+///           *
+///          /|`\
+///         / |  `\
+///        /  |    `\
+///       /   * P2   `\
+///      /  ,/ `\      `\
+///     / ,/     `\      `\
+///    *,/---------`\------*
+/// P0                       P1
+/// ```
 #[derive(Serialize, Deserialize)]
 pub struct Tet {
     /// Associated index of vertices in mesh.
@@ -60,6 +76,22 @@ pub struct Tet {
 }
 
 /// Hexahedron as a mesh element.
+///
+/// Local numbering order of hexahedron is made of the bottom quadrangle (P0, P1, P2, P3),
+/// followed by the top quadrangle (P4, P5, P6, P7), P4 to P7 lying respectively above P0 to P3.
+/// The top face lies on the side pointed to by the normal of the bottom face :
+///
+/// ```text
+///       P7 *----------* P6
+///         /|         /|
+///        / |        / |
+///    P4 *----------* P5
+///       |  |       |  |
+///       |  * P3 ---|--* P2
+///       | /        | /
+///       |/         |/
+///    P0 *----------* P1
+/// ```
 #[derive(Serialize, Deserialize)]
 pub struct Hexa {
     /// Associated index of vertices in mesh.
@@ -72,6 +104,21 @@ pub struct Hexa {
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 
+/// Definition of the kinds of mesh elements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElementKind {
+    /// Edges.
+    Edge,
+    /// Triangles.
+    Tri,
+    /// Quadrangles.
+    Quad,
+    /// Tetrahedra.
+    Tet,
+    /// Hexahedra.
+    Hexa
+}
+
 /// Definition of local ordering and naming of edges in a triangle.
 #[derive(Clone, Copy)]
 pub enum EdgeInTri {
@@ -105,6 +152,26 @@ pub enum TriInQuad {
     Tri123
 }
 
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Element trait.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Definition of the features shared by all mesh elements, allowing algorithms generic over
+/// element kinds.
+pub trait Element {
+    /// Number of vertices of the element.
+    const NVERTICES: usize;
+
+    /// Kind of the element.
+    const KIND: ElementKind;
+
+    /// Accessing the indexes of the vertices of the element in the mesh.
+    ///
+    fn vertex_indices(&self) -> &[usize];
+}
+
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 // Implementations.
@@ -112,31 +179,85 @@ pub enum TriInQuad {
 //////////////////////////////////////////////////////////////
 
 impl Edge {
+    /// Local vertices of the edge.
+    pub const EDGES: [[usize; 2]; 1] = [[0, 1]];
+
     /// Creating a new edge.
     ///
     pub fn new(indexes: [usize; 2]) -> Edge { Edge { indexes } }
 }
 
 impl Tri {
+    /// Local vertices of the edges of the triangle, ordered as in `EdgeInTri`.
+    pub const EDGES: [[usize; 2]; 3] = [[0, 1], [1, 2], [2, 0]];
+
     /// Creating a new tri.
     ///
     pub fn new(indexes: [usize; 3]) -> Tri { Tri { indexes } }
 }
 
 impl Quad {
+    /// Local vertices of the edges of the quadrangle, ordered as in `EdgeInQuad`.
+    pub const EDGES: [[usize; 2]; 4] = [[0, 1], [1, 2], [2, 3], [3, 0]];
+
     /// Creating a new quad.
     ///
     pub fn new(indexes: [usize; 4]) -> Quad { Quad { indexes } }
 }
 
 impl Tet {
+    /// Local vertices of the edges of the tetrahedron.
+    pub const EDGES: [[usize; 2]; 6] = [[0, 1], [1, 2], [2, 0], [0, 3], [1, 3], [2, 3]];
+
+    /// Local vertices of the faces of the tetrahedron, ordered so that their normal points
+    /// outward for a positively oriented tetrahedron.
+    pub const FACES: [[usize; 3]; 4] = [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]];
+
     /// Creating a new tetrahedra.
     ///
     pub fn new(indexes: [usize; 4]) -> Tet { Tet { indexes } }
 }
 
 impl Hexa {
+    /// Local vertices of the edges of the hexahedron.
+    pub const EDGES: [[usize; 2]; 12] = [
+        [0, 1], [1, 2], [2, 3], [3, 0],
+        [4, 5], [5, 6], [6, 7], [7, 4],
+        [0, 4], [1, 5], [2, 6], [3, 7]
+    ];
+
+    /// Local vertices of the faces of the hexahedron, ordered so that their normal points
+    /// outward.
+    pub const FACES: [[usize; 4]; 6] = [
+        [0, 3, 2, 1], [4, 5, 6, 7],
+        [0, 1, 5, 4], [1, 2, 6, 5], [2, 3, 7, 6], [3, 0, 4, 7]
+    ];
+
+    /// Local vertices of six positively oriented tetrahedra filling the hexahedron, all sharing
+    /// the diagonal (P0, P6). Each face is split along its diagonal through P0 or P6.
+    pub const TETRAHEDRA: [[usize; 4]; 6] = [
+        [0, 1, 2, 6], [0, 5, 1, 6], [0, 2, 3, 6],
+        [0, 3, 7, 6], [0, 4, 5, 6], [0, 7, 4, 6]
+    ];
+
     /// Creating a new hexa.
     ///
     pub fn new(indexes: [usize; 8]) -> Hexa { Hexa { indexes } }
-}
\ No newline at end of file
+}
+
+// Implementing the element trait for an element type with a given number of vertices.
+macro_rules! impl_element {
+    ($t:ident, $n:expr) => {
+        impl Element for $t {
+            const NVERTICES: usize = $n;
+            const KIND: ElementKind = ElementKind::$t;
+            fn vertex_indices(&self) -> &[usize] { &self.indexes }
+        }
+    }
+}
+
+impl_element!(Edge, 2);
+impl_element!(Tri, 3);
+impl_element!(Quad, 4);
+impl_element!(Tet, 4);
+impl_element!(Hexa, 8);
@@ -0,0 +1,339 @@
+extern crate std;
+
+use super::base::*;
+use super::mesh::*;
+use std::collections::HashMap;
+use std::vec::*;
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Data structures.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Errors raised when fitting an analytic shape.
+#[derive(Debug, PartialEq)]
+pub enum FitError {
+    /// Not enough points to determine the shape.
+    NotEnoughPoints {
+        /// Minimal number of points required by the shape.
+        required: usize,
+        /// Number of points provided.
+        found: usize,
+    },
+    /// Points do not determine a unique shape, e.g. collinear points for a plane.
+    Degenerate,
+}
+
+/// Sphere fitted to a set of points, with residual statistics.
+#[derive(Clone, Debug)]
+pub struct SphereFit {
+    /// Center of the sphere.
+    pub center: Pnt3d,
+    /// Radius of the sphere.
+    pub radius: f64,
+    /// Maximum distance from a point to the sphere.
+    pub max_deviation: f64,
+    /// Root mean square distance from the points to the sphere.
+    pub rms_deviation: f64,
+}
+
+/// Plane fitted to a set of points, with residual statistics.
+#[derive(Clone, Debug)]
+pub struct PlaneFit {
+    /// Point of the plane, the centroid of the fitted points.
+    pub origin: Pnt3d,
+    /// Unit normal of the plane.
+    pub normal: Dir3d,
+    /// Maximum distance from a point to the plane.
+    pub max_deviation: f64,
+    /// Root mean square distance from the points to the plane.
+    pub rms_deviation: f64,
+}
+
+/// Cylinder fitted to a set of points, with residual statistics.
+#[derive(Clone, Debug)]
+pub struct CylinderFit {
+    /// Point of the cylinder axis.
+    pub origin: Pnt3d,
+    /// Unit direction of the cylinder axis.
+    pub axis: Dir3d,
+    /// Radius of the cylinder.
+    pub radius: f64,
+    /// Maximum distance from a point to the cylinder.
+    pub max_deviation: f64,
+    /// Root mean square distance from the points to the cylinder.
+    pub rms_deviation: f64,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl std::fmt::Display for FitError {
+    /// Implementing display for fit errors.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            FitError::NotEnoughPoints { required, found } =>
+                write!(formatter, "at least {} points are required, {} found", required, found),
+            FitError::Degenerate => write!(formatter, "points do not determine a unique shape"),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+impl Mesh3d {
+    /// Fitting a sphere to the mesh vertices in the least-squares sense. The algebraic fit is
+    /// exact when all vertices lie on a sphere.
+    ///
+    pub fn fit_to_sphere(&self) -> Result<SphereFit, FitError>
+    {
+        check_point_count(self.vertices.len(), 4)?;
+        let centroid = get_centroid(&self.vertices);
+
+        // Solving |p|^2 = 2 c.p + d in the least-squares sense, p being relative to the centroid.
+        let mut a = vec![vec![0.0; 4]; 4];
+        let mut b = vec![0.0; 4];
+        for p in &self.vertices {
+            let c = p.coords.add_out(-1.0, &centroid);
+            let row = [2.0 * c.x, 2.0 * c.y, 2.0 * c.z, 1.0];
+            for i in 0..4 {
+                for j in 0..4 { a[i][j] += row[i] * row[j]; }
+                b[i] += row[i] * c.sq_norm();
+            }
+        }
+        let x = solve_linear_system(a, b).ok_or(FitError::Degenerate)?;
+
+        let offset = Coord3d::new([x[0], x[1], x[2]]);
+        let center = Pnt3d { coords: centroid.add_out(1.0, &offset) };
+        let radius = (x[3] + offset.sq_norm()).sqrt();
+        let (max_deviation, rms_deviation) = get_deviations(self.vertices.iter().map(|p| p.distance_to(&center) - radius));
+        Ok(SphereFit { center, radius, max_deviation, rms_deviation })
+    }
+
+    /// Fitting a plane to the mesh vertices in the least-squares sense. The normal is the
+    /// direction of least variance of the vertices, which must not be collinear.
+    ///
+    pub fn fit_to_plane(&self) -> Result<PlaneFit, FitError>
+    {
+        check_point_count(self.vertices.len(), 3)?;
+        let centroid = get_centroid(&self.vertices);
+        let covariance = get_covariance(self.vertices.iter().map(|p| p.coords.add_out(-1.0, &centroid)));
+        let (values, vectors) = get_symmetric_eigen(covariance);
+        if is_negligible(values[1], values[2]) { return Err(FitError::Degenerate); }
+
+        let normal = Dir3d { coords: vectors[0].clone() };
+        let (max_deviation, rms_deviation) = get_deviations(
+            self.vertices.iter().map(|p| p.coords.add_out(-1.0, &centroid).dot(&normal.coords)));
+        Ok(PlaneFit { origin: Pnt3d { coords: centroid }, normal, max_deviation, rms_deviation })
+    }
+
+    /// Fitting a cylinder to the mesh vertices. The axis is the direction of least variance of
+    /// the triangle normals, which are orthogonal to the axis on a cylinder. The radius and the
+    /// axis position are then fitted to the vertices projected on a plane orthogonal to the axis.
+    /// Triangles must be provided and must not all be parallel.
+    ///
+    pub fn fit_to_cylinder(&self) -> Result<CylinderFit, FitError>
+    {
+        check_point_count(self.vertices.len(), 3)?;
+        let normals = self.triangles.iter()
+            .map(|tri| self.get_tri_view(tri).get_normal().coords)
+            .filter(|n| n.norm().is_finite());
+        let (values, vectors) = get_symmetric_eigen(get_covariance(normals));
+        if is_negligible(values[1], values[2]) { return Err(FitError::Degenerate); }
+        let axis = Dir3d { coords: vectors[0].clone() };
+        let (u, v) = (&vectors[2], &vectors[1]);
+
+        // Solving |q|^2 = 2 c.q + d in the least-squares sense, q being the projected vertices.
+        let centroid = get_centroid(&self.vertices);
+        let mut a = vec![vec![0.0; 3]; 3];
+        let mut b = vec![0.0; 3];
+        for p in &self.vertices {
+            let c = p.coords.add_out(-1.0, &centroid);
+            let (x, y) = (c.dot(u), c.dot(v));
+            let row = [2.0 * x, 2.0 * y, 1.0];
+            for i in 0..3 {
+                for j in 0..3 { a[i][j] += row[i] * row[j]; }
+                b[i] += row[i] * (x * x + y * y);
+            }
+        }
+        let x = solve_linear_system(a, b).ok_or(FitError::Degenerate)?;
+
+        let origin = Pnt3d { coords: centroid.add_out(x[0], u).add_out(x[1], v) };
+        let radius = (x[2] + x[0] * x[0] + x[1] * x[1]).sqrt();
+        let (max_deviation, rms_deviation) = get_deviations(self.vertices.iter().map(|p| {
+            let w = p.coords.add_out(-1.0, &origin.coords);
+            w.add_out(-w.dot(&axis.coords), &axis.coords).norm() - radius
+        }));
+        Ok(CylinderFit { origin, axis, radius, max_deviation, rms_deviation })
+    }
+
+    /// Detecting a plane of mirror symmetry of the mesh vertices among the planes through their
+    /// centroid orthogonal to their principal axes, tried by decreasing variance. A plane is
+    /// accepted if every vertex has a mirror image among the vertices within a tolerance. Returns
+    /// a point and the unit normal of the first accepted plane, if any. As principal axes are not
+    /// unique for isotropic vertex sets, symmetry planes of such sets may be missed.
+    ///
+    /// * `tol` - Maximum distance between the mirror image of a vertex and another vertex.
+    ///
+    pub fn detect_symmetry_plane(&self, tol: f64) -> Option<(Pnt3d, Dir3d)>
+    {
+        if self.vertices.is_empty() { return None; }
+        let centroid = get_centroid(&self.vertices);
+        let (_, vectors) = get_symmetric_eigen(get_covariance(self.vertices.iter().map(|p| p.coords.add_out(-1.0, &centroid))));
+
+        let size = tol.max(GEOMETRICAL_TOLERANCE);
+        let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+        for (v, p) in self.vertices.iter().enumerate() { grid.entry(get_cell(&p.coords, size)).or_default().push(v); }
+
+        for normal in vectors.iter().rev() {
+            let symmetric = self.vertices.iter().all(|p| {
+                let mirror = p.coords.add_out(-2.0 * p.coords.add_out(-1.0, &centroid).dot(normal), normal);
+                let c = get_cell(&mirror, size);
+                (0..27).any(|n| {
+                    let neighbour = [c[0].saturating_add(n % 3 - 1), c[1].saturating_add((n / 3) % 3 - 1), c[2].saturating_add(n / 9 - 1)];
+                    grid.get(&neighbour).is_some_and(|vertices| {
+                        vertices.iter().any(|&w| self.vertices[w].coords.add_out(-1.0, &mirror).norm() <= tol)
+                    })
+                })
+            });
+            if symmetric { return Some((Pnt3d { coords: centroid }, Dir3d { coords: normal.clone() })); }
+        }
+        None
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Checking that enough points are provided to fit a shape.
+fn check_point_count(found: usize, required: usize) -> Result<(), FitError>
+{
+    if found < required { Err(FitError::NotEnoughPoints { required, found }) } else { Ok(()) }
+}
+
+// Checking whether an eigenvalue is negligible with respect to the largest one.
+fn is_negligible(value: f64, largest: f64) -> bool
+{
+    value <= GEOMETRICAL_TOLERANCE * largest
+}
+
+// Computing the centroid of points.
+fn get_centroid(points: &[Pnt3d]) -> Coord3d
+{
+    let mut centroid = Coord3d::default();
+    for p in points { centroid.add_in(1.0, &p.coords); }
+    centroid.amplify_out(1.0 / (points.len() as f64))
+}
+
+// Computing the second moment matrix of coordinates.
+fn get_covariance<I>(coords: I) -> [[f64; 3]; 3] where I: Iterator<Item = Coord3d>
+{
+    let mut m = [[0.0; 3]; 3];
+    for c in coords {
+        let v = [c.x, c.y, c.z];
+        for i in 0..3 {
+            for j in 0..3 { m[i][j] += v[i] * v[j]; }
+        }
+    }
+    m
+}
+
+// Computing the cell of a regular grid containing coordinates, cell coordinates saturating at
+// the bounds of the integer range.
+fn get_cell(coords: &Coord3d, size: f64) -> [i64; 3]
+{
+    [(coords.x / size).floor() as i64, (coords.y / size).floor() as i64, (coords.z / size).floor() as i64]
+}
+
+// Computing the maximum absolute value and root mean square of residuals.
+fn get_deviations<I>(residuals: I) -> (f64, f64) where I: Iterator<Item = f64>
+{
+    let (mut max, mut sum, mut n) = (0.0f64, 0.0, 0);
+    for r in residuals {
+        max = max.max(r.abs());
+        sum += r * r;
+        n += 1;
+    }
+    (max, if n > 0 { (sum / (n as f64)).sqrt() } else { 0.0 })
+}
+
+// Computing eigenvalues and unit eigenvectors of a symmetric 3x3 matrix with cyclic Jacobi
+// rotations. Eigenvalues are sorted by increasing value.
+fn get_symmetric_eigen(m: [[f64; 3]; 3]) -> ([f64; 3], [Coord3d; 3])
+{
+    let mut a = m;
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off == 0.0 { break; }
+        for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 { continue; }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in &mut a {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            for k in 0..3 {
+                a[p][k] = c * row_p[k] - s * row_q[k];
+                a[q][k] = s * row_p[k] + c * row_q[k];
+            }
+            for row in &mut v {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(std::cmp::Ordering::Equal));
+    let values = [a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]]];
+    let vectors = [
+        Coord3d::new([v[0][order[0]], v[1][order[0]], v[2][order[0]]]),
+        Coord3d::new([v[0][order[1]], v[1][order[1]], v[2][order[1]]]),
+        Coord3d::new([v[0][order[2]], v[1][order[2]], v[2][order[2]]])
+    ];
+    (values, vectors)
+}
+
+// Solving a square linear system with Gaussian elimination and partial pivoting. Returns None
+// when the system is singular.
+fn solve_linear_system(a: Vec<Vec<f64>>, b: Vec<f64>) -> Option<Vec<f64>>
+{
+    let (mut a, mut b) = (a, b);
+    let n = b.len();
+    let scale = a.iter().flat_map(|row| row.iter()).fold(0.0f64, |m, &x| m.max(x.abs()));
+    for k in 0..n {
+        let pivot = (k..n).fold(k, |p, i| if a[i][k].abs() > a[p][k].abs() { i } else { p });
+        if a[pivot][k].abs() <= GEOMETRICAL_TOLERANCE * scale { return None; }
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        let pivot_row = a[k].clone();
+        for i in k + 1..n {
+            let f = a[i][k] / pivot_row[k];
+            for (aij, akj) in a[i].iter_mut().zip(&pivot_row).skip(k) { *aij -= f * akj; }
+            b[i] -= f * b[k];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for k in (0..n).rev() {
+        let s = (k + 1..n).fold(b[k], |s, j| s - a[k][j] * x[j]);
+        x[k] = s / a[k][k];
+    }
+    Some(x)
+}
@@ -0,0 +1,272 @@
+extern crate std;
+
+use super::base::*;
+use super::elements::*;
+use super::mesh::*;
+use std::collections::HashMap;
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Mesh2d {
+    /// Creating a triangulated grid of the rectangle [0, width] x [0, height], made of nx by ny
+    /// cells each split into two counterclockwise triangles along the diagonal from its lower left
+    /// corner. Vertex (i, j) has index i + j * (nx + 1). The boundary edges are oriented outward
+    /// and tagged "bottom", "right", "top" and "left". Panics if nx or ny is zero.
+    ///
+    /// * `nx` - Number of cells along the width.
+    /// * `ny` - Number of cells along the height.
+    /// * `width` - Width of the rectangle.
+    /// * `height` - Height of the rectangle.
+    ///
+    pub fn new_rectangle(nx: usize, ny: usize, width: f64, height: f64) -> Mesh2d
+    {
+        assert!(nx > 0 && ny > 0, "a rectangle needs at least one cell in each direction");
+        let mut mesh = Mesh2d::default();
+        let v = |i: usize, j: usize| i + j * (nx + 1);
+        for j in 0..ny + 1 {
+            for i in 0..nx + 1 {
+                mesh.vertices.push(Pnt2d::new([width * i as f64 / nx as f64, height * j as f64 / ny as f64]));
+            }
+        }
+        for j in 0..ny {
+            for i in 0..nx {
+                mesh.triangles.push(Tri::new([v(i, j), v(i + 1, j), v(i + 1, j + 1)]));
+                mesh.triangles.push(Tri::new([v(i, j), v(i + 1, j + 1), v(i, j + 1)]));
+            }
+        }
+        for i in 0..nx { mesh.push_tagged_edge(Edge::new([v(i, 0), v(i + 1, 0)]), "bottom"); }
+        for j in 0..ny { mesh.push_tagged_edge(Edge::new([v(nx, j), v(nx, j + 1)]), "right"); }
+        for i in (0..nx).rev() { mesh.push_tagged_edge(Edge::new([v(i + 1, ny), v(i, ny)]), "top"); }
+        for j in (0..ny).rev() { mesh.push_tagged_edge(Edge::new([v(0, j + 1), v(0, j)]), "left"); }
+        mesh
+    }
+
+    /// Creating a triangulated disk centered at the origin, made of a center vertex and of
+    /// n_radial rings of n_angular vertices at evenly spaced radii. The center is joined to the
+    /// first ring by a fan of triangles, and consecutive rings by pairs of triangles, all
+    /// counterclockwise. The boundary edges along the outer ring are oriented outward and tagged
+    /// "boundary". Panics if n_radial is zero or n_angular is lower than three.
+    ///
+    /// * `n_radial` - Number of rings.
+    /// * `n_angular` - Number of vertices per ring.
+    /// * `radius` - Radius of the outer ring.
+    ///
+    pub fn new_disk(n_radial: usize, n_angular: usize, radius: f64) -> Mesh2d
+    {
+        assert!(n_radial > 0 && n_angular > 2, "a disk needs at least one ring of three vertices");
+        let mut mesh = Mesh2d::default();
+        let v = |k: usize, a: usize| 1 + (k - 1) * n_angular + a % n_angular;
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        for k in 1..n_radial + 1 {
+            let r = radius * k as f64 / n_radial as f64;
+            for a in 0..n_angular {
+                let theta = 2.0 * std::f64::consts::PI * a as f64 / n_angular as f64;
+                mesh.vertices.push(Pnt2d::new([r * theta.cos(), r * theta.sin()]));
+            }
+        }
+        for a in 0..n_angular { mesh.triangles.push(Tri::new([0, v(1, a), v(1, a + 1)])); }
+        for k in 1..n_radial {
+            for a in 0..n_angular {
+                mesh.triangles.push(Tri::new([v(k, a), v(k + 1, a), v(k + 1, a + 1)]));
+                mesh.triangles.push(Tri::new([v(k, a), v(k + 1, a + 1), v(k, a + 1)]));
+            }
+        }
+        for a in 0..n_angular { mesh.push_tagged_edge(Edge::new([v(n_radial, a), v(n_radial, a + 1)]), "boundary"); }
+        mesh
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 3D implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Mesh3d {
+    /// Creating a structured grid of hexahedra filling the box [0, width] x [0, height] x
+    /// [0, depth], made of nx by ny by nz cells. Vertex (i, j, k) has index
+    /// i + (nx + 1) * (j + (ny + 1) * k). The boundary faces are added as quadrangles oriented
+    /// outward, following `Hexa::FACES`, and tagged "left" and "right" along x, "front" and
+    /// "back" along y, "bottom" and "top" along z. Panics if nx, ny or nz is zero.
+    ///
+    /// * `nx` - Number of cells along the width.
+    /// * `ny` - Number of cells along the height.
+    /// * `nz` - Number of cells along the depth.
+    /// * `width` - Size of the box along x.
+    /// * `height` - Size of the box along y.
+    /// * `depth` - Size of the box along z.
+    ///
+    pub fn new_box(nx: usize, ny: usize, nz: usize, width: f64, height: f64, depth: f64) -> Mesh3d
+    {
+        assert!(nx > 0 && ny > 0 && nz > 0, "a box needs at least one cell in each direction");
+        let mut mesh = Mesh3d::default();
+        let v = |i: usize, j: usize, k: usize| i + (nx + 1) * (j + (ny + 1) * k);
+        for k in 0..nz + 1 {
+            for j in 0..ny + 1 {
+                for i in 0..nx + 1 {
+                    let xyz = [width * i as f64 / nx as f64, height * j as f64 / ny as f64, depth * k as f64 / nz as f64];
+                    mesh.vertices.push(Pnt3d::new(xyz));
+                }
+            }
+        }
+
+        for k in 0..nz {
+            for j in 0..ny {
+                for i in 0..nx {
+                    let hexa = [
+                        v(i, j, k), v(i + 1, j, k), v(i + 1, j + 1, k), v(i, j + 1, k),
+                        v(i, j, k + 1), v(i + 1, j, k + 1), v(i + 1, j + 1, k + 1), v(i, j + 1, k + 1)
+                    ];
+                    // Tags of the faces listed in Hexa::FACES, with whether they lie on the boundary.
+                    let faces = [
+                        ("bottom", k == 0), ("top", k + 1 == nz),
+                        ("front", j == 0), ("right", i + 1 == nx), ("back", j + 1 == ny), ("left", i == 0)
+                    ];
+                    for (f, &(name, on_boundary)) in Hexa::FACES.iter().zip(&faces) {
+                        if on_boundary {
+                            mesh.push_tagged_quadrangle(Quad::new([hexa[f[0]], hexa[f[1]], hexa[f[2]], hexa[f[3]]]), name);
+                        }
+                    }
+                    mesh.hexahedra.push(Hexa::new(hexa));
+                }
+            }
+        }
+        mesh
+    }
+
+    /// Creating a triangulated sphere made of two poles and of n_lat - 1 rings of n_lon vertices
+    /// at evenly spaced latitudes, the north pole lying along +z from the center. The poles are
+    /// joined to their ring by fans of triangles, and consecutive rings by pairs of triangles, all
+    /// oriented outward. Panics if n_lat is lower than two or n_lon lower than three.
+    ///
+    /// * `n_lat` - Number of bands between the poles.
+    /// * `n_lon` - Number of vertices per ring.
+    /// * `radius` - Radius of the sphere.
+    /// * `center` - Center of the sphere.
+    ///
+    pub fn new_uv_sphere(n_lat: usize, n_lon: usize, radius: f64, center: Pnt3d) -> Mesh3d
+    {
+        assert!(n_lat > 1 && n_lon > 2, "a sphere needs at least one ring of three vertices");
+        let mut mesh = Mesh3d::default();
+        let v = |k: usize, a: usize| 1 + (k - 1) * n_lon + a % n_lon;
+        let south = 1 + (n_lat - 1) * n_lon;
+        let point = |xyz: [f64; 3]| Pnt3d { coords: Coord3d::new(xyz).amplify_out(radius).add_out(1.0, &center.coords) };
+
+        mesh.vertices.push(point([0., 0., 1.]));
+        for k in 1..n_lat {
+            let theta = std::f64::consts::PI * k as f64 / n_lat as f64;
+            for a in 0..n_lon {
+                let phi = 2.0 * std::f64::consts::PI * a as f64 / n_lon as f64;
+                mesh.vertices.push(point([theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()]));
+            }
+        }
+        mesh.vertices.push(point([0., 0., -1.]));
+
+        for a in 0..n_lon { mesh.triangles.push(Tri::new([0, v(1, a), v(1, a + 1)])); }
+        for k in 1..n_lat - 1 {
+            for a in 0..n_lon {
+                mesh.triangles.push(Tri::new([v(k, a), v(k + 1, a), v(k + 1, a + 1)]));
+                mesh.triangles.push(Tri::new([v(k, a), v(k + 1, a + 1), v(k, a + 1)]));
+            }
+        }
+        for a in 0..n_lon { mesh.triangles.push(Tri::new([south, v(n_lat - 1, a + 1), v(n_lat - 1, a)])); }
+        mesh
+    }
+
+    /// Creating a triangulated sphere centered at the origin from an icosahedron, each
+    /// subdivision splitting every triangle into four by its edge midpoints projected on the
+    /// sphere. Triangles are oriented outward.
+    ///
+    /// * `subdivisions` - Number of subdivisions of the icosahedron.
+    /// * `radius` - Radius of the sphere.
+    ///
+    pub fn new_icosphere(subdivisions: usize, radius: f64) -> Mesh3d
+    {
+        let t = (1.0 + 5f64.sqrt()) / 2.0;
+        let corners = [
+            [-1., t, 0.], [1., t, 0.], [-1., -t, 0.], [1., -t, 0.],
+            [0., -1., t], [0., 1., t], [0., -1., -t], [0., 1., -t],
+            [t, 0., -1.], [t, 0., 1.], [-t, 0., -1.], [-t, 0., 1.]
+        ];
+        let mut faces = vec![
+            [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+            [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+            [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+            [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1]
+        ];
+
+        let mut mesh = Mesh3d::default();
+        let project = |c: Coord3d| Pnt3d { coords: c.amplify_out(radius / c.norm()) };
+        for xyz in &corners { mesh.vertices.push(project(Coord3d::new(*xyz))); }
+
+        for _ in 0..subdivisions {
+            let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+            let mut subdivided = Vec::with_capacity(4 * faces.len());
+            for f in &faces {
+                let mut m = [0; 3];
+                for k in 0..3 {
+                    let (a, b) = (f[k], f[(k + 1) % 3]);
+                    let n = mesh.vertices.len();
+                    m[k] = *midpoints.entry((a.min(b), a.max(b))).or_insert(n);
+                    if m[k] == n {
+                        let midpoint = mesh.vertices[a].coords.add_out(1.0, &mesh.vertices[b].coords);
+                        mesh.vertices.push(project(midpoint));
+                    }
+                }
+                subdivided.push([f[0], m[0], m[2]]);
+                subdivided.push([f[1], m[1], m[0]]);
+                subdivided.push([f[2], m[2], m[1]]);
+                subdivided.push(m);
+            }
+            faces = subdivided;
+        }
+
+        for f in faces { mesh.triangles.push(Tri::new(f)); }
+        mesh
+    }
+
+    /// Creating a closed triangulated cylinder of axis z, from z = 0 to z = height, made of
+    /// n_height + 1 rings of n_angular vertices joined by pairs of triangles tagged "side", and
+    /// of two cap centers joined to the end rings by fans of triangles tagged "bottom" and
+    /// "top". Vertex a of ring j has index a + j * n_angular, followed by the bottom and top
+    /// centers. Triangles are oriented outward. Panics if n_angular is lower than three or
+    /// n_height is zero.
+    ///
+    /// * `n_angular` - Number of vertices per ring.
+    /// * `n_height` - Number of bands along the axis.
+    /// * `radius` - Radius of the cylinder.
+    /// * `height` - Height of the cylinder.
+    ///
+    pub fn new_cylinder(n_angular: usize, n_height: usize, radius: f64, height: f64) -> Mesh3d
+    {
+        assert!(n_angular > 2 && n_height > 0, "a cylinder needs at least one band of three vertices per ring");
+        let mut mesh = Mesh3d::default();
+        let v = |j: usize, a: usize| j * n_angular + a % n_angular;
+        for j in 0..n_height + 1 {
+            let z = height * j as f64 / n_height as f64;
+            for a in 0..n_angular {
+                let phi = 2.0 * std::f64::consts::PI * a as f64 / n_angular as f64;
+                mesh.vertices.push(Pnt3d::new([radius * phi.cos(), radius * phi.sin(), z]));
+            }
+        }
+        let (bottom, top) = (mesh.vertices.len(), mesh.vertices.len() + 1);
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., height]));
+
+        for j in 0..n_height {
+            for a in 0..n_angular {
+                mesh.push_tagged_triangle(Tri::new([v(j, a), v(j, a + 1), v(j + 1, a + 1)]), "side");
+                mesh.push_tagged_triangle(Tri::new([v(j, a), v(j + 1, a + 1), v(j + 1, a)]), "side");
+            }
+        }
+        for a in 0..n_angular {
+            mesh.push_tagged_triangle(Tri::new([bottom, v(0, a + 1), v(0, a)]), "bottom");
+            mesh.push_tagged_triangle(Tri::new([top, v(n_height, a), v(n_height, a + 1)]), "top");
+        }
+        mesh
+    }
+}
@@ -1,8 +1,12 @@
 #![cfg_attr(feature="clippy", feature(plugin))]
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 
 /// Definition of basic geometrical utilities.
 pub mod base;
@@ -18,3 +22,18 @@ pub mod mesh;
 
 /// Definition of view on mesh elements.
 pub mod views;
+
+/// Simplification of triangle surfaces.
+pub mod decimation;
+
+/// Mesh quality and size measures.
+pub mod quality;
+
+/// Fitting of analytic shapes to meshes.
+pub mod fitting;
+
+/// Generation of structured meshes.
+pub mod generators;
+
+/// Spatial search over mesh vertices.
+pub mod search;
@@ -4,14 +4,98 @@ use super::base::*;
 use super::elements::*;
 use super::tag::*;
 use super::views::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{BufRead, Write};
 use std::vec::*;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Import and export of meshes in external file formats.
+pub mod io;
+
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 // 3D data structure.
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 
+/// Validation applied when inserting elements in a mesh.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationMode {
+    /// Element vertex indexes are checked against the number of vertices of the mesh.
+    Strict,
+    /// No check is performed, elements may reference vertices inserted afterwards.
+    Off,
+}
+
+/// Errors raised by mesh operations.
+#[derive(Debug, PartialEq)]
+pub enum MeshError {
+    /// An element references a vertex index out of the range of the mesh vertices.
+    VertexIndexOutOfRange {
+        /// Faulty vertex index.
+        index: usize,
+        /// Number of vertices in the mesh.
+        n_vertices: usize,
+    },
+    /// A vertex to remove is referenced by an element.
+    VertexInUse {
+        /// Faulty vertex index.
+        index: usize,
+    },
+    /// A triangle to refine is a face of a volume element.
+    VolumeElementFace {
+        /// Faulty triangle index.
+        index: usize,
+    },
+}
+
+/// Indexes of the vertices and elements of a mesh sharing a tag name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+    pub vertices: Vec<usize>,
+    pub edges: Vec<usize>,
+    pub triangles: Vec<usize>,
+    pub quadrangles: Vec<usize>,
+    pub tetrahedra: Vec<usize>,
+    pub hexahedra: Vec<usize>,
+}
+
+/// Indexes of degenerate triangles of a mesh, sorted by the kind of fix they need.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DegenerateReport {
+    /// Triangles of nearly zero area, to be removed.
+    pub zero_area: Vec<usize>,
+    /// Triangles of non-negligible area with a high aspect ratio, to be flipped or collapsed.
+    pub high_aspect_ratio: Vec<usize>,
+}
+
+/// Summary statistics of a mesh.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshStats {
+    pub n_vertices: usize,
+    pub n_edges: usize,
+    pub n_triangles: usize,
+    pub n_quadrangles: usize,
+    pub n_tetrahedra: usize,
+    pub n_hexahedra: usize,
+    /// Total area of the triangles and quadrangles.
+    pub surface_area: f64,
+    /// Total absolute volume of the tetrahedra and hexahedra.
+    pub volume: f64,
+}
+
+/// Definition of the elements stored in a 3d mesh, giving access to their storage so that
+/// elements can be pushed generically with `Mesh3d::push_tagged`.
+pub trait Mesh3dElement: Element + Sized {
+    /// Accessing the elements of the same kind in a mesh, along with their tags.
+    ///
+    /// * `mesh` - Mesh storing the elements.
+    ///
+    fn get_storage(mesh: &mut Mesh3d) -> (&mut Vec<Self>, &mut TagSet);
+}
+
 /// Structure defining a 3d tagged mesh.
 #[derive(Default, Serialize, Deserialize)]
 pub struct Mesh3d {
@@ -27,8 +111,42 @@ pub struct Mesh3d {
     pub quadrangles_tags: TagSet,
     pub tetrahedra_tags: TagSet,
     pub hexahedra_tags: TagSet,
+    /// Validation applied by the `insert_*` methods. Not serialized.
+    #[serde(skip)]
+    pub validation: ValidationMode,
 }
 
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Default for ValidationMode {
+    /// Creating default validation mode, i.e. strict validation.
+    ///
+    fn default() -> Self { ValidationMode::Strict }
+}
+
+impl std::fmt::Display for MeshError {
+    /// Implementing display for mesh errors.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            MeshError::VertexIndexOutOfRange { index, n_vertices } =>
+                write!(formatter, "vertex index {} is out of range for a mesh of {} vertices", index, n_vertices),
+            MeshError::VertexInUse { index } =>
+                write!(formatter, "vertex {} is referenced by an element", index),
+            MeshError::VolumeElementFace { index } =>
+                write!(formatter, "triangle {} is a face of a volume element", index),
+        }
+    }
+}
+
+impl std::error::Error for MeshError {}
+
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 // 3D implementations.
@@ -46,6 +164,17 @@ impl Mesh3d {
         push_tagged_element(&mut self.vertices, &mut self.vertices_tags, point, name);
     }
 
+    /// Creating a tagged element in the mesh, stored with the elements of the same kind.
+    ///
+    /// * `element` - Element to add in the mesh.
+    /// * `name` - Tag name.
+    ///
+    pub fn push_tagged<E: Mesh3dElement>(&mut self, element: E, name: &str)
+    {
+        let (elements, tags) = E::get_storage(self);
+        push_tagged_element(elements, tags, element, name);
+    }
+
     /// Creating an tagged edge in the mesh.
     ///
     /// * `edge` - Edge to add in the mesh.
@@ -53,7 +182,7 @@ impl Mesh3d {
     ///
     pub fn push_tagged_edge(&mut self, edge: Edge, name: &str)
     {
-       push_tagged_element(&mut self.edges, &mut self.edges_tags, edge, name);
+        self.push_tagged(edge, name);
     }
 
     /// Creating a view to an edge in a mesh from the input edge itself.
@@ -72,7 +201,7 @@ impl Mesh3d {
     ///
     pub fn push_tagged_triangle(&mut self, tri: Tri, name: &str)
     {
-       push_tagged_element(&mut self.triangles, &mut self.triangles_tags, tri, name);
+        self.push_tagged(tri, name);
     }
 
     /// Making a view to a triangle in a mesh from the element itself.
@@ -91,7 +220,7 @@ impl Mesh3d {
     ///
     pub fn push_tagged_quadrangle(&mut self, quad: Quad, name: &str)
     {
-       push_tagged_element(&mut self.quadrangles, &mut self.quadrangles_tags, quad, name);
+        self.push_tagged(quad, name);
     }
 
     /// Making a view to a quadrangle in a mesh from the element itself.
@@ -110,7 +239,7 @@ impl Mesh3d {
     ///
     pub fn push_tagged_tetrahedron(&mut self, tet: Tet, name: &str)
     {
-       push_tagged_element(&mut self.tetrahedra, &mut self.tetrahedra_tags, tet, name);
+        self.push_tagged(tet, name);
     }
 
     /// Making a view to a tetrahedron in a mesh the element itself.
@@ -129,7 +258,7 @@ impl Mesh3d {
     ///
     pub fn push_tagged_hexahedron(&mut self, hexa: Hexa, name: &str)
     {
-       push_tagged_element(&mut self.hexahedra, &mut self.hexahedra_tags, hexa, name);
+        self.push_tagged(hexa, name);
     }
 
     /// Making a view to a hexahedron in a mesh from the element itself.
@@ -140,20 +269,2217 @@ impl Mesh3d {
     {
        HexaView3d { points: get_eight_vertices_view(&self.vertices, &hexa.indexes) }
     }
-}
 
-//////////////////////////////////////////////////////////////
-//////////////////////////////////////////////////////////////
-// Private implementation methods.
-//////////////////////////////////////////////////////////////
-//////////////////////////////////////////////////////////////
+    /// Serializing the mesh to JSON, either pretty-printed or compact.
+    ///
+    /// * `pretty` - Whether the output is pretty-printed.
+    ///
+    pub fn to_json(&self, pretty: bool) -> serde_json::Result<String>
+    {
+        if pretty { serde_json::to_string_pretty(self) } else { serde_json::to_string(self) }
+    }
 
-// Pushing an element into a vector of elements and registering its associated tag.
-fn push_tagged_element<T>(elements: &mut Vec<T>, tags: &mut TagSet, element: T, name: &str)
-{
-    let idx = elements.len();
-    elements.push(element);
-    tags.register(name, idx);
+    /// Deserializing a mesh from JSON.
+    ///
+    /// * `s` - JSON string describing the mesh.
+    ///
+    pub fn from_json(s: &str) -> serde_json::Result<Mesh3d>
+    {
+        serde_json::from_str(s)
+    }
+
+    /// Reading a mesh in the ASCII Object File Format, as in `io::read_off`.
+    ///
+    /// * `r` - Input reader.
+    ///
+    pub fn read_off<R: BufRead>(mut r: R) -> Result<Mesh3d, io::OffError>
+    {
+        io::read_off(&mut r)
+    }
+
+    /// Writing the surface of the mesh in the ASCII Object File Format, as in `io::write_off`.
+    ///
+    /// * `w` - Output writer.
+    ///
+    pub fn write_off<W: Write>(&self, w: &mut W) -> std::io::Result<()>
+    {
+        io::write_off(self, w)
+    }
+
+    /// Inserting a vertex in the mesh, returning its index.
+    ///
+    /// * `point` - Point to add in the mesh.
+    ///
+    pub fn insert_vertex(&mut self, point: Pnt3d) -> usize
+    {
+        self.vertices.push(point);
+        self.vertices.len() - 1
+    }
+
+    /// Inserting an edge in the mesh, returning its index. In strict validation mode, an error
+    /// is returned if the edge references a vertex which is not in the mesh.
+    ///
+    /// * `edge` - Edge to add in the mesh.
+    ///
+    pub fn insert_edge(&mut self, edge: Edge) -> Result<usize, MeshError>
+    {
+        self.validate_indexes(&edge.indexes)?;
+        self.edges.push(edge);
+        Ok(self.edges.len() - 1)
+    }
+
+    /// Inserting a triangle in the mesh, returning its index. In strict validation mode, an
+    /// error is returned if the triangle references a vertex which is not in the mesh.
+    ///
+    /// * `tri` - Triangle to add in the mesh.
+    ///
+    pub fn insert_triangle(&mut self, tri: Tri) -> Result<usize, MeshError>
+    {
+        self.validate_indexes(&tri.indexes)?;
+        self.triangles.push(tri);
+        Ok(self.triangles.len() - 1)
+    }
+
+    /// Inserting a quadrangle in the mesh, returning its index. In strict validation mode, an
+    /// error is returned if the quadrangle references a vertex which is not in the mesh.
+    ///
+    /// * `quad` - Quadrangle to add in the mesh.
+    ///
+    pub fn insert_quadrangle(&mut self, quad: Quad) -> Result<usize, MeshError>
+    {
+        self.validate_indexes(&quad.indexes)?;
+        self.quadrangles.push(quad);
+        Ok(self.quadrangles.len() - 1)
+    }
+
+    /// Inserting a tetrahedron in the mesh, returning its index. In strict validation mode, an
+    /// error is returned if the tetrahedron references a vertex which is not in the mesh.
+    ///
+    /// * `tet` - Tetrahedron to add in the mesh.
+    ///
+    pub fn insert_tetrahedron(&mut self, tet: Tet) -> Result<usize, MeshError>
+    {
+        self.validate_indexes(&tet.indexes)?;
+        self.tetrahedra.push(tet);
+        Ok(self.tetrahedra.len() - 1)
+    }
+
+    /// Inserting a hexahedron in the mesh, returning its index. In strict validation mode, an
+    /// error is returned if the hexahedron references a vertex which is not in the mesh.
+    ///
+    /// * `hexa` - Hexahedron to add in the mesh.
+    ///
+    pub fn insert_hexahedron(&mut self, hexa: Hexa) -> Result<usize, MeshError>
+    {
+        self.validate_indexes(&hexa.indexes)?;
+        self.hexahedra.push(hexa);
+        Ok(self.hexahedra.len() - 1)
+    }
+
+    /// Reserving capacity for at least `additional` more vertices.
+    ///
+    pub fn reserve_vertices(&mut self, additional: usize) { self.vertices.reserve(additional); }
+
+    /// Reserving capacity for at least `additional` more edges.
+    ///
+    pub fn reserve_edges(&mut self, additional: usize) { self.edges.reserve(additional); }
+
+    /// Reserving capacity for at least `additional` more triangles.
+    ///
+    pub fn reserve_triangles(&mut self, additional: usize) { self.triangles.reserve(additional); }
+
+    /// Reserving capacity for at least `additional` more quadrangles.
+    ///
+    pub fn reserve_quadrangles(&mut self, additional: usize) { self.quadrangles.reserve(additional); }
+
+    /// Reserving capacity for at least `additional` more tetrahedra.
+    ///
+    pub fn reserve_tetrahedra(&mut self, additional: usize) { self.tetrahedra.reserve(additional); }
+
+    /// Reserving capacity for at least `additional` more hexahedra.
+    ///
+    pub fn reserve_hexahedra(&mut self, additional: usize) { self.hexahedra.reserve(additional); }
+
+    // Checking element vertex indexes against the number of vertices in strict validation mode.
+    fn validate_indexes(&self, indexes: &[usize]) -> Result<(), MeshError>
+    {
+        if self.validation == ValidationMode::Off { return Ok(()); }
+        let n_vertices = self.vertices.len();
+        match indexes.iter().find(|&&index| index >= n_vertices) {
+            Some(&index) => Err(MeshError::VertexIndexOutOfRange { index, n_vertices }),
+            None => Ok(())
+        }
+    }
+
+    /// Removing all triangles registered under a tag, the remaining triangles being renumbered
+    /// in the triangle tags. Returns the number of removed triangles, zero if the tag does not
+    /// exist.
+    ///
+    /// * `name` - Tag name of the triangles to remove.
+    ///
+    pub fn remove_triangles_by_tag(&mut self, name: &str) -> usize
+    {
+        let indexes = match self.triangles_tags.get_registered_indexes(name) {
+            Some(indexes) => indexes.clone(),
+            None => { return 0; }
+        };
+        self.remove_triangles(&indexes).iter().filter(|i| i.is_none()).count()
+    }
+
+    /// Removing edges, the remaining edges being compacted and renumbered in the edge tags.
+    /// Indexes out of range are ignored. Returns the map from former to new edge indexes,
+    /// removed edges being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the edges to remove.
+    ///
+    pub fn remove_edges(&mut self, indexes: &[usize]) -> Vec<Option<usize>>
+    {
+        remove_elements(&mut self.edges, &mut self.edges_tags, indexes)
+    }
+
+    /// Removing triangles, the remaining triangles being compacted and renumbered in the triangle
+    /// tags. Indexes out of range are ignored. Returns the map from former to new triangle
+    /// indexes, removed triangles being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the triangles to remove.
+    ///
+    pub fn remove_triangles(&mut self, indexes: &[usize]) -> Vec<Option<usize>>
+    {
+        remove_elements(&mut self.triangles, &mut self.triangles_tags, indexes)
+    }
+
+    /// Removing quadrangles, the remaining quadrangles being compacted and renumbered in the
+    /// quadrangle tags. Indexes out of range are ignored. Returns the map from former to new
+    /// quadrangle indexes, removed quadrangles being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the quadrangles to remove.
+    ///
+    pub fn remove_quadrangles(&mut self, indexes: &[usize]) -> Vec<Option<usize>>
+    {
+        remove_elements(&mut self.quadrangles, &mut self.quadrangles_tags, indexes)
+    }
+
+    /// Removing tetrahedra, the remaining tetrahedra being compacted and renumbered in the
+    /// tetrahedron tags. Indexes out of range are ignored. Returns the map from former to new
+    /// tetrahedron indexes, removed tetrahedra being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the tetrahedra to remove.
+    ///
+    pub fn remove_tetrahedra(&mut self, indexes: &[usize]) -> Vec<Option<usize>>
+    {
+        remove_elements(&mut self.tetrahedra, &mut self.tetrahedra_tags, indexes)
+    }
+
+    /// Removing hexahedra, the remaining hexahedra being compacted and renumbered in the
+    /// hexahedron tags. Indexes out of range are ignored. Returns the map from former to new
+    /// hexahedron indexes, removed hexahedra being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the hexahedra to remove.
+    ///
+    pub fn remove_hexahedra(&mut self, indexes: &[usize]) -> Vec<Option<usize>>
+    {
+        remove_elements(&mut self.hexahedra, &mut self.hexahedra_tags, indexes)
+    }
+
+    /// Removing vertices, the remaining vertices being compacted and renumbered in the elements
+    /// and in the vertex tags. Indexes out of range are ignored. Vertices referenced by elements
+    /// are only removed in cascade mode, along with these elements. Otherwise an error is
+    /// returned and the mesh is left untouched. Returns the map from former to new vertex
+    /// indexes, removed vertices being mapped to `None`.
+    ///
+    /// * `indexes` - Indexes of the vertices to remove.
+    /// * `cascade` - Whether elements referencing removed vertices are removed as well.
+    ///
+    pub fn remove_vertices(&mut self, indexes: &[usize], cascade: bool) -> Result<Vec<Option<usize>>, MeshError>
+    {
+        let mut removed = vec![false; self.vertices.len()];
+        for &v in indexes { if let Some(r) = removed.get_mut(v) { *r = true; } }
+
+        let edges = get_elements_using(self.edges.iter().map(|e| &e.indexes[..]), &removed);
+        let triangles = get_elements_using(self.triangles.iter().map(|t| &t.indexes[..]), &removed);
+        let quadrangles = get_elements_using(self.quadrangles.iter().map(|q| &q.indexes[..]), &removed);
+        let tetrahedra = get_elements_using(self.tetrahedra.iter().map(|t| &t.indexes[..]), &removed);
+        let hexahedra = get_elements_using(self.hexahedra.iter().map(|h| &h.indexes[..]), &removed);
+        if !cascade {
+            let used = self.edges.iter().map(|e| &e.indexes[..])
+                .chain(self.triangles.iter().map(|t| &t.indexes[..]))
+                .chain(self.quadrangles.iter().map(|q| &q.indexes[..]))
+                .chain(self.tetrahedra.iter().map(|t| &t.indexes[..]))
+                .chain(self.hexahedra.iter().map(|h| &h.indexes[..]))
+                .flat_map(|indexes| indexes.iter())
+                .find(|&&v| removed[v]);
+            if let Some(&index) = used { return Err(MeshError::VertexInUse { index }); }
+        }
+        self.remove_edges(&edges);
+        self.remove_triangles(&triangles);
+        self.remove_quadrangles(&quadrangles);
+        self.remove_tetrahedra(&tetrahedra);
+        self.remove_hexahedra(&hexahedra);
+
+        let map = remove_elements(&mut self.vertices, &mut self.vertices_tags, indexes);
+        let element_map: Vec<usize> = map.iter().map(|v| v.unwrap_or(0)).collect();
+        renumber_vertices(self.get_elements_mut(), &element_map);
+        Ok(map)
+    }
+
+    /// Computing the unique undirected edges of the triangles and quadrangles of the mesh.
+    /// Edges are returned in order of first appearance, oriented as first encountered.
+    ///
+    pub fn unique_edges(&self) -> Vec<Edge>
+    {
+        let mut visited = HashSet::new();
+        let mut edges = Vec::new();
+        let tri_edges = self.triangles.iter().flat_map(|t| (0..3).map(move |k| [t.indexes[k], t.indexes[(k + 1) % 3]]));
+        let quad_edges = self.quadrangles.iter().flat_map(|q| (0..4).map(move |k| [q.indexes[k], q.indexes[(k + 1) % 4]]));
+        for indexes in tri_edges.chain(quad_edges) {
+            let key = if indexes[0] < indexes[1] { (indexes[0], indexes[1]) } else { (indexes[1], indexes[0]) };
+            if visited.insert(key) { edges.push(Edge::new(indexes)); }
+        }
+        edges
+    }
+
+    /// Translating all vertices of the mesh. In-place function.
+    ///
+    /// * `v` - Translation vector.
+    ///
+    pub fn translate(&mut self, v: &Vec3d) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.add_in(1.0, &v.coords); }
+        self
+    }
+
+    /// Scaling all vertices of the mesh about the origin. In-place function.
+    ///
+    /// * `factor` - Scaling factor.
+    ///
+    pub fn scale(&mut self, factor: f64) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.amplify_in(factor); }
+        self
+    }
+
+    /// Scaling all vertices of the mesh about a center. In-place function.
+    ///
+    /// * `center` - Point left unchanged by the scaling.
+    /// * `factor` - Scaling factor.
+    ///
+    pub fn scale_about(&mut self, center: &Pnt3d, factor: f64) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.mlt_add_in(factor, 1.0 - factor, &center.coords); }
+        self
+    }
+
+    /// Rotating all vertices of the mesh around an axis passing through a center, using
+    /// Rodrigues' rotation formula. In-place function.
+    ///
+    /// * `axis` - Direction of the rotation axis.
+    /// * `angle` - Rotation angle in radians, following the right-hand rule around the axis.
+    /// * `center` - Point the rotation axis passes through.
+    ///
+    pub fn rotate(&mut self, axis: &Dir3d, angle: f64, center: &Pnt3d) -> &mut Self
+    {
+        for p in &mut self.vertices { *p = p.rotate_around(center, axis, angle); }
+        self
+    }
+
+    /// Applying an affine transformation to all vertices of the mesh. In-place function.
+    ///
+    /// * `t` - Transformation to apply.
+    ///
+    pub fn apply_transform(&mut self, t: &Transform3d) -> &mut Self
+    {
+        for p in &mut self.vertices { *p = t.apply_to_point(p); }
+        self
+    }
+
+    /// Computing the axis-aligned bounding box of the vertices. Returns `None` if the mesh has no
+    /// vertex.
+    ///
+    pub fn get_bounding_box(&self) -> Option<BoundingBox3d>
+    {
+        BoundingBox3d::from_points(&self.vertices)
+    }
+
+    /// Computing the lower and upper corners of the axis-aligned bounding box of the vertices.
+    /// Returns `None` if the mesh has no vertex.
+    ///
+    pub fn bounding_box(&self) -> Option<(Pnt3d, Pnt3d)>
+    {
+        self.get_bounding_box().map(|bbox| (Pnt3d { coords: bbox.min }, Pnt3d { coords: bbox.max }))
+    }
+
+    /// Finding a tetrahedron containing a point, as in `TetLocator3d::locate`. The bounding boxes
+    /// of the tetrahedra are indexed at each call, so a locator built by `build_tet_locator`
+    /// should be reused when locating many points.
+    ///
+    /// * `p` - Point to locate.
+    ///
+    pub fn locate_point_in_tets(&self, p: &Pnt3d) -> Option<usize>
+    {
+        self.build_tet_locator().locate(p)
+    }
+
+    /// Projecting points on the surface defined by the triangles of the mesh, returning for each
+    /// point the index of the closest triangle along with the closest point on it. Triangles are
+    /// scanned linearly, the cost being proportional to the number of points times the number
+    /// of triangles. Returns an empty vector if the mesh has no triangles.
+    ///
+    /// * `points` - Points to project.
+    ///
+    pub fn project_points(&self, points: &[Pnt3d]) -> Vec<(usize, Pnt3d)>
+    {
+        if self.triangles.is_empty() { return Vec::new(); }
+        points.iter().map(|p| {
+            let mut closest = (0, self.get_tri_view(&self.triangles[0]).closest_point(p));
+            let mut sq_distance = closest.1.to(p).coords.sq_norm();
+            for (i, t) in self.triangles.iter().enumerate().skip(1) {
+                let q = self.get_tri_view(t).closest_point(p);
+                let d = q.to(p).coords.sq_norm();
+                if d < sq_distance { closest = (i, q); sq_distance = d; }
+            }
+            closest
+        }).collect()
+    }
+
+    /// Computing the total area of the triangles and quadrangles of the mesh.
+    ///
+    pub fn get_total_surface_area(&self) -> f64
+    {
+        self.triangles.iter().map(|t| self.get_tri_view(t).get_area()).sum::<f64>()
+            + self.quadrangles.iter().map(|q| self.get_quad_view(q).get_area()).sum::<f64>()
+    }
+
+    /// Finding the degenerate triangles of the mesh. Triangles with an area below a threshold
+    /// are reported as of zero area, and the other ones with an aspect ratio above a cap, as
+    /// computed by `TriView3d::get_aspect_ratio`, as of high aspect ratio.
+    ///
+    /// * `area_eps` - Area below which triangles are of zero area.
+    /// * `ratio_cap` - Aspect ratio above which triangles are of high aspect ratio.
+    ///
+    pub fn degenerate_elements(&self, area_eps: f64, ratio_cap: f64) -> DegenerateReport
+    {
+        let mut report = DegenerateReport::default();
+        for (i, tri) in self.triangles.iter().enumerate() {
+            let t = self.get_tri_view(tri);
+            if t.get_area() < area_eps { report.zero_area.push(i); }
+            else if t.get_aspect_ratio() > ratio_cap { report.high_aspect_ratio.push(i); }
+        }
+        report
+    }
+
+    /// Computing the total volume of the tetrahedra and hexahedra of the mesh. Signed element
+    /// volumes are summed unless `absolute` is set, in which case inverted elements contribute
+    /// positively.
+    ///
+    /// * `absolute` - Whether absolute element volumes are summed.
+    ///
+    pub fn get_total_volume(&self, absolute: bool) -> f64
+    {
+        let volumes = self.tetrahedra.iter().map(|t| self.get_tet_view(t).get_signed_volume())
+            .chain(self.hexahedra.iter().map(|h| self.get_hexa_view(h).get_signed_volume()));
+        if absolute { volumes.map(f64::abs).sum() } else { volumes.sum() }
+    }
+
+    /// Computing summary statistics of the mesh: the number of vertices and elements of each
+    /// kind, the total area of the triangles and quadrangles, and the total absolute volume of
+    /// the tetrahedra and hexahedra.
+    ///
+    pub fn stats(&self) -> MeshStats
+    {
+        MeshStats {
+            n_vertices: self.vertices.len(),
+            n_edges: self.edges.len(),
+            n_triangles: self.triangles.len(),
+            n_quadrangles: self.quadrangles.len(),
+            n_tetrahedra: self.tetrahedra.len(),
+            n_hexahedra: self.hexahedra.len(),
+            surface_area: self.get_total_surface_area(),
+            volume: self.get_total_volume(true),
+        }
+    }
+
+    /// Computing the area associated to each vertex from the triangles of the mesh. Barycentric
+    /// areas give a third of the area of each incident triangle to a vertex. Mixed areas
+    /// (Meyer et al.) use the Voronoi region of the vertex in non-obtuse triangles, and half or
+    /// a quarter of the area of obtuse triangles depending on whether the obtuse angle is at the
+    /// vertex. In both cases, vertex areas sum to the total triangle area.
+    ///
+    /// * `mixed` - Whether mixed Voronoi areas are computed instead of barycentric ones.
+    ///
+    pub fn vertex_areas(&self, mixed: bool) -> Vec<f64>
+    {
+        let mut areas = vec![0.0; self.vertices.len()];
+        for tri in &self.triangles {
+            let t = self.get_tri_view(tri);
+            let area = t.get_area();
+            if !mixed {
+                for &v in &tri.indexes { areas[v] += area / 3.0; }
+                continue;
+            }
+
+            // Edge vectors leaving each corner towards the next and previous corners.
+            let next: Vec<Vec3d> = (0..3).map(|k| t.points[k].to(t.points[(k + 1) % 3])).collect();
+            let prev: Vec<Vec3d> = (0..3).map(|k| t.points[k].to(t.points[(k + 2) % 3])).collect();
+            let dots: Vec<f64> = (0..3).map(|k| next[k].dot(&prev[k])).collect();
+            match dots.iter().position(|&d| d < 0.0) {
+                Some(obtuse) => {
+                    for (k, &v) in tri.indexes.iter().enumerate() {
+                        areas[v] += if k == obtuse { area / 2.0 } else { area / 4.0 };
+                    }
+                },
+                None => {
+                    if area <= 0.0 { continue; }
+                    // Cotangent of the angle at each corner.
+                    let cot: Vec<f64> = dots.iter().map(|d| d / (2.0 * area)).collect();
+                    for (k, &v) in tri.indexes.iter().enumerate() {
+                        let (k1, k2) = ((k + 1) % 3, (k + 2) % 3);
+                        areas[v] += (next[k].dot(&next[k]) * cot[k2] + prev[k].dot(&prev[k]) * cot[k1]) / 8.0;
+                    }
+                }
+            }
+        }
+        areas
+    }
+
+    /// Computing the entries (row, column, value) of the cotangent Laplacian matrix of the
+    /// surface made of the triangles of the mesh. The off-diagonal entry of an edge (i, j) is
+    /// the sum of half the cotangents of the angles opposite to it, and diagonal entries are the
+    /// negative sums of the off-diagonal entries of their row. Entries are sorted by row then
+    /// column, and triangles of zero area are skipped.
+    ///
+    pub fn cotangent_laplacian_triplets(&self) -> Vec<(usize, usize, f64)>
+    {
+        let mut entries = BTreeMap::new();
+        for tri in &self.triangles {
+            let t = self.get_tri_view(tri);
+            let double_area = 2.0 * t.get_area();
+            if double_area <= 0.0 { continue; }
+            for k in 0..3 {
+                let (k1, k2) = ((k + 1) % 3, (k + 2) % 3);
+                let cot = t.points[k].to(t.points[k1]).dot(&t.points[k].to(t.points[k2])) / double_area;
+                let (i, j) = (tri.indexes[k1], tri.indexes[k2]);
+                *entries.entry((i, j)).or_insert(0.0) += 0.5 * cot;
+                *entries.entry((j, i)).or_insert(0.0) += 0.5 * cot;
+                *entries.entry((i, i)).or_insert(0.0) -= 0.5 * cot;
+                *entries.entry((j, j)).or_insert(0.0) -= 0.5 * cot;
+            }
+        }
+        entries.into_iter().map(|((i, j), w)| (i, j, w)).collect()
+    }
+
+    /// Applying the cotangent Laplacian matrix, as computed by `cotangent_laplacian_triplets`, to
+    /// a scalar field defined on the vertices, without assembling the matrix. Panics if the field
+    /// does not hold a value per vertex.
+    ///
+    /// * `field` - Values of the field at the vertices.
+    ///
+    pub fn laplacian(&self, field: &[f64]) -> Vec<f64>
+    {
+        assert_eq!(field.len(), self.vertices.len(), "the field must hold a value per vertex");
+        let mut result = vec![0.0; field.len()];
+        for tri in &self.triangles {
+            let t = self.get_tri_view(tri);
+            let double_area = 2.0 * t.get_area();
+            if double_area <= 0.0 { continue; }
+            for k in 0..3 {
+                let (k1, k2) = ((k + 1) % 3, (k + 2) % 3);
+                let cot = t.points[k].to(t.points[k1]).dot(&t.points[k].to(t.points[k2])) / double_area;
+                let (i, j) = (tri.indexes[k1], tri.indexes[k2]);
+                result[i] += 0.5 * cot * (field[j] - field[i]);
+                result[j] += 0.5 * cot * (field[i] - field[j]);
+            }
+        }
+        result
+    }
+
+    /// Computing the Euler characteristic V - E + F of the surface made of the triangles and
+    /// quadrangles of the mesh, counting only the vertices used by these faces.
+    ///
+    pub fn euler_characteristic(&self) -> i64
+    {
+        let faces = get_surface_faces(self);
+        let vertices: HashSet<usize> = faces.iter().flat_map(|f| f.iter().cloned()).collect();
+        let n_edges = get_edge_faces(&faces).len();
+        vertices.len() as i64 - n_edges as i64 + faces.len() as i64
+    }
+
+    /// Checking whether the surface made of the triangles and quadrangles of the mesh is
+    /// closed, i.e. non-empty with every edge shared by exactly two faces.
+    ///
+    pub fn is_watertight(&self) -> bool
+    {
+        let faces = get_surface_faces(self);
+        !faces.is_empty() && get_edge_faces(&faces).values().all(|f| f.len() == 2)
+    }
+
+    /// Computing the total length of the boundary edges of the surface made of the triangles and
+    /// quadrangles of the mesh, i.e. the edges used by a single face. A zero length indicates a
+    /// closed surface.
+    ///
+    pub fn boundary_length(&self) -> f64
+    {
+        get_edge_faces(&get_surface_faces(self)).iter()
+            .filter(|&(_, f)| f.len() == 1)
+            .map(|(&(a, b), _)| EdgeView3d { points: [&self.vertices[a], &self.vertices[b]] }.get_length())
+            .sum()
+    }
+
+    /// Counting the boundary loops of the surface made of the triangles and quadrangles of the
+    /// mesh, i.e. the connected sets of boundary edges. A disk has one loop, an annulus two and
+    /// a closed surface none. Loops sharing a vertex are counted once.
+    ///
+    pub fn num_boundary_loops(&self) -> usize
+    {
+        let mut parents: Vec<usize> = (0..self.vertices.len()).collect();
+        let mut boundary_vertices = Vec::new();
+        for (&(a, b), f) in &get_edge_faces(&get_surface_faces(self)) {
+            if f.len() != 1 { continue; }
+            join_roots(&mut parents, a, b);
+            boundary_vertices.push(a);
+        }
+        let roots: HashSet<usize> = boundary_vertices.iter().map(|&v| find_root(&mut parents, v)).collect();
+        roots.len()
+    }
+
+    /// Extracting the boundary faces of the tetrahedra and hexahedra of the mesh, i.e. the faces
+    /// used by a single volume element, oriented outward.
+    ///
+    pub fn extract_boundary_faces(&self) -> (Vec<Tri>, Vec<Quad>)
+    {
+        let tet_faces = self.tetrahedra.iter()
+            .flat_map(|t| Tet::FACES.iter().map(move |f| [t.indexes[f[0]], t.indexes[f[1]], t.indexes[f[2]]]));
+        let hexa_faces = self.hexahedra.iter()
+            .flat_map(|h| Hexa::FACES.iter().map(move |f| [h.indexes[f[0]], h.indexes[f[1]], h.indexes[f[2]], h.indexes[f[3]]]));
+
+        // Counting the volume elements sharing each face, faces being keyed by sorted indexes.
+        let mut counts: HashMap<Vec<usize>, usize> = HashMap::new();
+        for f in tet_faces.clone() { *counts.entry(get_sorted_key(&f)).or_insert(0) += 1; }
+        for f in hexa_faces.clone() { *counts.entry(get_sorted_key(&f)).or_insert(0) += 1; }
+
+        let tris = tet_faces.filter(|f| counts[&get_sorted_key(f)] == 1).map(Tri::new).collect();
+        let quads = hexa_faces.filter(|f| counts[&get_sorted_key(f)] == 1).map(Quad::new).collect();
+        (tris, quads)
+    }
+
+    /// Pushing the boundary faces of the tetrahedra and hexahedra of the mesh as triangles and
+    /// quadrangles tagged with a name.
+    ///
+    /// * `name` - Tag name.
+    ///
+    pub fn tag_boundary(&mut self, name: &str)
+    {
+        let (tris, quads) = self.extract_boundary_faces();
+        for tri in tris { self.push_tagged_triangle(tri, name); }
+        for quad in quads { self.push_tagged_quadrangle(quad, name); }
+    }
+
+    /// Checking whether the surface made of the triangles and quadrangles of the mesh is a
+    /// manifold, i.e. every edge is shared by at most two faces and the faces around every
+    /// vertex form a single fan.
+    ///
+    pub fn is_manifold(&self) -> bool
+    {
+        let faces = get_surface_faces(self);
+        let edge_faces = get_edge_faces(&faces);
+        if edge_faces.values().any(|f| f.len() > 2) { return false; }
+
+        // Joining the face corners around each vertex across shared edges.
+        let mut offsets = Vec::with_capacity(faces.len());
+        let mut n_corners = 0;
+        for f in &faces {
+            offsets.push(n_corners);
+            n_corners += f.len();
+        }
+        let corner = |f: usize, v: usize| offsets[f] + faces[f].iter().position(|&w| w == v).unwrap_or(0);
+        let mut parents: Vec<usize> = (0..n_corners).collect();
+        for (&(a, b), f) in &edge_faces {
+            if f.len() < 2 { continue; }
+            join_roots(&mut parents, corner(f[0], a), corner(f[1], a));
+            join_roots(&mut parents, corner(f[0], b), corner(f[1], b));
+        }
+
+        let mut fans = HashMap::new();
+        for (i, f) in faces.iter().enumerate() {
+            for (k, &v) in f.iter().enumerate() {
+                let root = find_root(&mut parents, offsets[i] + k);
+                if *fans.entry(v).or_insert(root) != root { return false; }
+            }
+        }
+        true
+    }
+
+    /// Finding the non-manifold edges of the surface made of the triangles and quadrangles of
+    /// the mesh, i.e. the edges shared by more than two faces. Returns their indexes in the edges
+    /// computed by `unique_edges`.
+    ///
+    pub fn non_manifold_edges(&self) -> Vec<usize>
+    {
+        let edge_faces = get_edge_faces(&get_surface_faces(self));
+        self.unique_edges().iter().enumerate()
+            .filter(|&(_, e)| {
+                let (a, b) = (e.indexes[0], e.indexes[1]);
+                edge_faces[&if a < b { (a, b) } else { (b, a) }].len() > 2
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Computing the genus of the closed orientable surface made of the triangles and
+    /// quadrangles of the mesh, as (2 - X) / 2 with X the Euler characteristic. For a surface
+    /// made of several connected components, the sum of their genera is returned. Returns
+    /// `None` if the surface is not closed or not manifold.
+    ///
+    pub fn genus(&self) -> Option<usize>
+    {
+        if !self.is_watertight() || !self.is_manifold() { return None; }
+
+        let faces = get_surface_faces(self);
+        let mut parents: Vec<usize> = (0..self.vertices.len()).collect();
+        for f in &faces {
+            for &v in f.iter() { join_roots(&mut parents, f[0], v); }
+        }
+        let roots: HashSet<usize> = faces.iter().map(|f| find_root(&mut parents, f[0])).collect();
+
+        let doubled_genus = 2 * roots.len() as i64 - self.euler_characteristic();
+        if doubled_genus < 0 || doubled_genus % 2 != 0 { None } else { Some((doubled_genus / 2) as usize) }
+    }
+
+    /// Creating a wireframe mesh made of the vertices and unique edges of the mesh faces.
+    ///
+    pub fn wireframe(&self) -> Mesh3d
+    {
+        Mesh3d { vertices: self.vertices.clone(), edges: self.unique_edges(), ..Mesh3d::default() }
+    }
+
+    /// Computing the one-ring neighbours of every vertex, i.e. the vertices sharing an edge of
+    /// any element with it. Neighbours are sorted by increasing index.
+    ///
+    pub fn vertex_neighbours(&self) -> Vec<Vec<usize>>
+    {
+        let mut neighbours = vec![Vec::new(); self.vertices.len()];
+        {
+            let mut add_edges = |indexes: &[usize], local_edges: &[[usize; 2]]| {
+                for e in local_edges {
+                    let (a, b) = (indexes[e[0]], indexes[e[1]]);
+                    neighbours[a].push(b);
+                    neighbours[b].push(a);
+                }
+            };
+            for e in &self.edges { add_edges(&e.indexes, &Edge::EDGES); }
+            for t in &self.triangles { add_edges(&t.indexes, &Tri::EDGES); }
+            for q in &self.quadrangles { add_edges(&q.indexes, &Quad::EDGES); }
+            for t in &self.tetrahedra { add_edges(&t.indexes, &Tet::EDGES); }
+            for h in &self.hexahedra { add_edges(&h.indexes, &Hexa::EDGES); }
+        }
+        for n in &mut neighbours {
+            n.sort();
+            n.dedup();
+        }
+        neighbours
+    }
+
+    /// Computing the dual graph of the triangles of the mesh in compressed sparse row format,
+    /// as expected by graph partitioners such as METIS or Scotch. Triangles are the nodes of the
+    /// graph, linked when they share an edge. The neighbours of triangle `i` are
+    /// `adjncy[xadj[i]..xadj[i + 1]]`, sorted by increasing index.
+    ///
+    pub fn dual_graph(&self) -> (Vec<usize>, Vec<usize>)
+    {
+        let faces: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..]).collect();
+        let mut neighbours = vec![Vec::new(); faces.len()];
+        for f in get_edge_faces(&faces).values() {
+            for &i in f {
+                neighbours[i].extend(f.iter().filter(|&&j| j != i));
+            }
+        }
+
+        let mut xadj = Vec::with_capacity(faces.len() + 1);
+        let mut adjncy = Vec::new();
+        xadj.push(0);
+        for n in &mut neighbours {
+            n.sort();
+            n.dedup();
+            adjncy.extend_from_slice(n);
+            xadj.push(adjncy.len());
+        }
+        (xadj, adjncy)
+    }
+
+    /// Replacing every quadrangle by its triangles (P0, P1, P3) and (P1, P2, P3), as named in
+    /// `TriInQuad`. Triangles are appended in the order of their quadrangles and inherit their
+    /// tags. The quadrangles and their tags are cleared.
+    ///
+    pub fn triangulate_quads(&mut self)
+    {
+        let (offset, n_quads) = (self.triangles.len(), self.quadrangles.len());
+        for q in self.quadrangles.drain(..) {
+            self.triangles.push(Tri::new([q.indexes[0], q.indexes[1], q.indexes[3]]));
+            self.triangles.push(Tri::new([q.indexes[1], q.indexes[2], q.indexes[3]]));
+        }
+        self.triangles_tags.merge(&get_split_tags(&self.quadrangles_tags, n_quads, 2), offset, "");
+        self.quadrangles_tags = TagSet::default();
+    }
+
+    /// Replacing every quadrangle by two triangles split along the diagonal giving the better
+    /// triangles, i.e. the lower maximum aspect ratio as computed by `TriView3d::get_aspect_ratio`.
+    /// Triangles are (P0, P1, P3) and (P1, P2, P3) for the diagonal (P1, P3), as in
+    /// `triangulate_quads` which is followed on ties, and (P0, P1, P2) and (P0, P2, P3) for the
+    /// diagonal (P0, P2). Triangles are appended in the order of their quadrangles and inherit
+    /// their tags. The quadrangles and their tags are cleared.
+    ///
+    pub fn triangulate_quads_best_diagonal(&mut self)
+    {
+        let (offset, n_quads) = (self.triangles.len(), self.quadrangles.len());
+        let vertices = &self.vertices;
+        let get_aspect_ratio = |t: [usize; 3]| TriView3d { points: get_three_vertices_view(vertices, &t) }.get_aspect_ratio();
+        for q in self.quadrangles.drain(..) {
+            let [a, b, c, d] = q.indexes;
+            let split_13 = [[a, b, d], [b, c, d]];
+            let split_02 = [[a, b, c], [a, c, d]];
+            let worst_13 = get_aspect_ratio(split_13[0]).max(get_aspect_ratio(split_13[1]));
+            let worst_02 = get_aspect_ratio(split_02[0]).max(get_aspect_ratio(split_02[1]));
+            for &t in if worst_02 < worst_13 { &split_02 } else { &split_13 } {
+                self.triangles.push(Tri::new(t));
+            }
+        }
+        self.triangles_tags.merge(&get_split_tags(&self.quadrangles_tags, n_quads, 2), offset, "");
+        self.quadrangles_tags = TagSet::default();
+    }
+
+    /// Refining the triangles uniformly, each triangle being split into four by inserting the
+    /// midpoints of its edges. Midpoints are shared between neighbouring triangles, so that the
+    /// refined triangles remain conforming, and every edge is split into two halves. Children
+    /// inherit the tags of their parent, the children of element i being numbered from 4 * i for
+    /// triangles and 2 * i for edges. Quadrangles are left untouched. An error is returned, and
+    /// the mesh is left untouched, if a triangle is a face of a tetrahedron.
+    ///
+    pub fn refine_triangles_uniform(&mut self) -> Result<(), MeshError>
+    {
+        let tet_faces: HashSet<Vec<usize>> = self.tetrahedra.iter()
+            .flat_map(|t| Tet::FACES.iter().map(move |f| get_sorted_key(&[t.indexes[f[0]], t.indexes[f[1]], t.indexes[f[2]]])))
+            .collect();
+        if let Some(index) = self.triangles.iter().position(|t| tet_faces.contains(&get_sorted_key(&t.indexes))) {
+            return Err(MeshError::VolumeElementFace { index });
+        }
+        refine_triangles(&mut self.vertices, &mut self.edges, &mut self.edges_tags, &mut self.triangles,
+                         &mut self.triangles_tags, |p, q| p.midpoint_with(q));
+        Ok(())
+    }
+
+    /// Replacing every hexahedron by the six tetrahedra defined in `Hexa::TETRAHEDRA`, sharing its
+    /// diagonal (P0, P6). The splitting is conforming between hexahedra sharing faces with the
+    /// same local orientation, e.g. in structured grids. Tetrahedra are appended in the order of
+    /// their hexahedra and inherit their tags. The hexahedra and their tags are cleared.
+    ///
+    pub fn tetrahedralize_hexas(&mut self)
+    {
+        let (offset, n_hexas) = (self.tetrahedra.len(), self.hexahedra.len());
+        for h in self.hexahedra.drain(..) {
+            for t in &Hexa::TETRAHEDRA {
+                self.tetrahedra.push(Tet::new([h.indexes[t[0]], h.indexes[t[1]], h.indexes[t[2]], h.indexes[t[3]]]));
+            }
+        }
+        self.tetrahedra_tags.merge(&get_split_tags(&self.hexahedra_tags, n_hexas, Hexa::TETRAHEDRA.len()), offset, "");
+        self.hexahedra_tags = TagSet::default();
+    }
+
+    /// Labelling the connected components of the triangles of the mesh, triangles being connected
+    /// when they share an edge as in the dual graph. Returns the component of each triangle,
+    /// components being numbered by their lowest triangle index.
+    ///
+    pub fn triangle_components(&self) -> Vec<usize>
+    {
+        let (xadj, adjncy) = self.dual_graph();
+        let mut labels = vec![None; self.triangles.len()];
+        let mut n_labels = 0;
+        for seed in 0..self.triangles.len() {
+            if labels[seed].is_some() { continue; }
+            labels[seed] = Some(n_labels);
+            let mut stack = vec![seed];
+            while let Some(i) = stack.pop() {
+                for &j in &adjncy[xadj[i]..xadj[i + 1]] {
+                    if labels[j].is_none() {
+                        labels[j] = Some(n_labels);
+                        stack.push(j);
+                    }
+                }
+            }
+            n_labels += 1;
+        }
+        labels.into_iter().map(|l| l.unwrap_or(0)).collect()
+    }
+
+    /// Registering the triangles of each connected component, as given by
+    /// `triangle_components`, under a tag named after a prefix and the component number, as in
+    /// "{prefix}_0". Returns the number of components.
+    ///
+    /// * `prefix` - Prefix of the tag names.
+    ///
+    pub fn tag_connected_components(&mut self, prefix: &str) -> usize
+    {
+        let labels = self.triangle_components();
+        for (t, &label) in labels.iter().enumerate() {
+            self.triangles_tags.register(&format!("{}_{}", prefix, label), t);
+        }
+        labels.iter().max().map(|&label| label + 1).unwrap_or(0)
+    }
+
+    /// Computing the mean length of the unique edges of the elements incident to a vertex.
+    /// Returns `None` if no edge is incident to the vertex.
+    ///
+    /// * `vertex` - Index of the vertex.
+    ///
+    pub fn local_edge_length(&self, vertex: usize) -> Option<f64>
+    {
+        let mut neighbours = HashSet::new();
+        {
+            let mut add_edges = |indexes: &[usize], local_edges: &[[usize; 2]]| {
+                for e in local_edges {
+                    let (a, b) = (indexes[e[0]], indexes[e[1]]);
+                    if a == vertex && b != vertex { neighbours.insert(b); }
+                    if b == vertex && a != vertex { neighbours.insert(a); }
+                }
+            };
+            for e in &self.edges { add_edges(&e.indexes, &Edge::EDGES); }
+            for t in &self.triangles { add_edges(&t.indexes, &Tri::EDGES); }
+            for q in &self.quadrangles { add_edges(&q.indexes, &Quad::EDGES); }
+            for t in &self.tetrahedra { add_edges(&t.indexes, &Tet::EDGES); }
+            for h in &self.hexahedra { add_edges(&h.indexes, &Hexa::EDGES); }
+        }
+        if neighbours.is_empty() { return None; }
+        let sum: f64 = neighbours.iter().map(|&n| self.vertices[vertex].distance_to(&self.vertices[n])).sum();
+        Some(sum / (neighbours.len() as f64))
+    }
+
+    /// Updating all vertices from a function computing each new position from the current
+    /// ones. New positions are all computed from a snapshot of the vertices before being
+    /// written back, so the result does not depend on the evaluation order. Vertices are
+    /// processed in parallel when the `rayon` feature is enabled.
+    ///
+    /// * `f` - Function computing the new position of a vertex from its index and position.
+    ///
+    pub fn par_update_vertices<F>(&mut self, f: F) where F: Fn(usize, &Pnt3d) -> Pnt3d + Sync
+    {
+        self.vertices = map_vertices(&self.vertices, f);
+    }
+
+    /// Updating all vertices from a function computing each new position from the current
+    /// position of the vertex and of its one-ring neighbours. Updates are computed from a
+    /// snapshot of the vertices, as in `par_update_vertices`.
+    ///
+    /// * `neighbours` - One-ring neighbours of each vertex, as built by `vertex_neighbours`.
+    /// * `f` - Function computing the new position of a vertex from its index, its position and
+    ///   the positions of its neighbours.
+    ///
+    pub fn par_update_vertices_with_neighbours<F>(&mut self, neighbours: &[Vec<usize>], f: F)
+        where F: Fn(usize, &Pnt3d, &[&Pnt3d]) -> Pnt3d + Sync
+    {
+        let vertices = &self.vertices;
+        let updated = map_vertices(vertices, |i, p| {
+            let ring: Vec<&Pnt3d> = neighbours[i].iter().map(|&n| &vertices[n]).collect();
+            f(i, p, &ring)
+        });
+        self.vertices = updated;
+    }
+
+    /// Selecting the vertices and elements of every kind registered under a tag name. An unknown
+    /// tag name gives an empty selection.
+    ///
+    /// * `name` - Tag name of the vertices and elements to select.
+    ///
+    pub fn selection_from_tag(&self, name: &str) -> Selection
+    {
+        let select = |tags: &TagSet| tags.get_registered_indexes(name).cloned().unwrap_or_default();
+        Selection {
+            vertices: select(&self.vertices_tags),
+            edges: select(&self.edges_tags),
+            triangles: select(&self.triangles_tags),
+            quadrangles: select(&self.quadrangles_tags),
+            tetrahedra: select(&self.tetrahedra_tags),
+            hexahedra: select(&self.hexahedra_tags),
+        }
+    }
+
+    /// Extracting the elements of a given kind registered under a tag name as a new mesh. Only
+    /// the vertices referenced by these elements are copied, numbered by order of appearance.
+    /// The extracted elements are registered under the tag name in the new mesh, and vertex tags
+    /// are carried over. An unknown tag name gives an empty mesh.
+    ///
+    /// * `kind` - Kind of the elements to extract.
+    /// * `name` - Tag name of the elements to extract.
+    ///
+    pub fn extract_by_tag(&self, kind: ElementKind, name: &str) -> Mesh3d
+    {
+        let tags = match kind {
+            ElementKind::Edge => &self.edges_tags,
+            ElementKind::Tri => &self.triangles_tags,
+            ElementKind::Quad => &self.quadrangles_tags,
+            ElementKind::Tet => &self.tetrahedra_tags,
+            ElementKind::Hexa => &self.hexahedra_tags,
+        };
+        let mut mesh = Mesh3d::default();
+        let elements = match tags.get_registered_indexes(name) {
+            Some(indexes) => indexes,
+            None => return mesh,
+        };
+
+        let mut map = vec![None; self.vertices.len()];
+        let mut vertices = Vec::new();
+        {
+            let mut localize = |indexes: &mut [usize]| for idx in indexes.iter_mut() {
+                if map[*idx].is_none() {
+                    map[*idx] = Some(vertices.len());
+                    vertices.push(self.vertices[*idx].clone());
+                }
+                *idx = map[*idx].unwrap_or(0);
+            };
+            for &i in elements {
+                match kind {
+                    ElementKind::Edge => {
+                        let mut indexes = self.edges[i].indexes;
+                        localize(&mut indexes);
+                        mesh.push_tagged_edge(Edge::new(indexes), name);
+                    },
+                    ElementKind::Tri => {
+                        let mut indexes = self.triangles[i].indexes;
+                        localize(&mut indexes);
+                        mesh.push_tagged_triangle(Tri::new(indexes), name);
+                    },
+                    ElementKind::Quad => {
+                        let mut indexes = self.quadrangles[i].indexes;
+                        localize(&mut indexes);
+                        mesh.push_tagged_quadrangle(Quad::new(indexes), name);
+                    },
+                    ElementKind::Tet => {
+                        let mut indexes = self.tetrahedra[i].indexes;
+                        localize(&mut indexes);
+                        mesh.push_tagged_tetrahedron(Tet::new(indexes), name);
+                    },
+                    ElementKind::Hexa => {
+                        let mut indexes = self.hexahedra[i].indexes;
+                        localize(&mut indexes);
+                        mesh.push_tagged_hexahedron(Hexa::new(indexes), name);
+                    },
+                }
+            }
+        }
+        mesh.vertices = vertices;
+        mesh.vertices_tags = self.vertices_tags.remap(&map);
+        mesh
+    }
+
+    /// Appending another mesh, whose vertices are added after the vertices of the mesh and whose
+    /// elements are renumbered accordingly. Tags are merged, indexes of tags sharing the same
+    /// name being accumulated.
+    ///
+    /// * `other` - Mesh to append.
+    ///
+    pub fn append(&mut self, other: &Mesh3d)
+    {
+        self.append_with_prefix(other, "");
+    }
+
+    /// Appending another mesh as in `append`, prefixing the names of its tags so that they do
+    /// not collide with the tags of the mesh.
+    ///
+    /// * `other` - Mesh to append.
+    /// * `prefix` - Prefix added to the tag names of the appended mesh.
+    ///
+    pub fn append_with_prefix(&mut self, other: &Mesh3d, prefix: &str)
+    {
+        let offset = self.vertices.len();
+        let shift = |indexes: &mut [usize]| for idx in indexes.iter_mut() { *idx += offset; };
+
+        self.vertices_tags.merge(&other.vertices_tags, offset, prefix);
+        self.edges_tags.merge(&other.edges_tags, self.edges.len(), prefix);
+        self.triangles_tags.merge(&other.triangles_tags, self.triangles.len(), prefix);
+        self.quadrangles_tags.merge(&other.quadrangles_tags, self.quadrangles.len(), prefix);
+        self.tetrahedra_tags.merge(&other.tetrahedra_tags, self.tetrahedra.len(), prefix);
+        self.hexahedra_tags.merge(&other.hexahedra_tags, self.hexahedra.len(), prefix);
+
+        self.vertices.extend(other.vertices.iter().cloned());
+        for e in &other.edges {
+            let mut indexes = e.indexes;
+            shift(&mut indexes);
+            self.edges.push(Edge::new(indexes));
+        }
+        for t in &other.triangles {
+            let mut indexes = t.indexes;
+            shift(&mut indexes);
+            self.triangles.push(Tri::new(indexes));
+        }
+        for q in &other.quadrangles {
+            let mut indexes = q.indexes;
+            shift(&mut indexes);
+            self.quadrangles.push(Quad::new(indexes));
+        }
+        for t in &other.tetrahedra {
+            let mut indexes = t.indexes;
+            shift(&mut indexes);
+            self.tetrahedra.push(Tet::new(indexes));
+        }
+        for h in &other.hexahedra {
+            let mut indexes = h.indexes;
+            shift(&mut indexes);
+            self.hexahedra.push(Hexa::new(indexes));
+        }
+    }
+
+    /// Removing the vertices which are not referenced by any element. Elements are renumbered and
+    /// vertex tags are remapped, tags left without any vertex being dropped. A mesh without
+    /// elements ends up without vertices. Returns the former indexes of the removed vertices, in
+    /// increasing order.
+    ///
+    pub fn remove_unused_vertices(&mut self) -> Vec<usize>
+    {
+        let removed = get_unused_vertices(self.vertices.len(), &self.get_elements());
+        let map = remove_elements(&mut self.vertices, &mut self.vertices_tags, &removed);
+        let map: Vec<usize> = map.iter().map(|v| v.unwrap_or(0)).collect();
+        renumber_vertices(self.get_elements_mut(), &map);
+        removed
+    }
+
+    /// Merging vertices closer than a distance threshold, each vertex being merged onto the
+    /// remaining vertex of lowest index within the threshold. Candidates are found through a
+    /// spatial hash of cells whose size is the threshold, so that the cost is linear in the number
+    /// of vertices for well-spread vertices. Merged vertices are removed from the mesh, elements
+    /// are renumbered and vertex tags point to the remaining vertices. Elements which become
+    /// degenerate are kept. Returns the number of removed vertices.
+    ///
+    /// * `tol` - Distance below which vertices are merged. Nothing is merged if not positive.
+    ///
+    pub fn merge_duplicate_vertices(&mut self, tol: f64) -> usize
+    {
+        if tol <= 0.0 { return 0; }
+        let (representatives, n_merged) = get_duplicate_representatives(&self.vertices, tol, |p, q| p.distance_to(q) < tol);
+        if n_merged > 0 {
+            let map = merge_vertices(&mut self.vertices, &mut self.vertices_tags, &representatives);
+            renumber_vertices(self.get_elements_mut(), &map);
+        }
+        n_merged
+    }
+
+    /// Welding vertices whose coordinates are equal up to a threshold in the sense of
+    /// `Coord3d::equals`, i.e. whose square distance is below the threshold, so that welding
+    /// vertices closer than a distance d takes a threshold of d * d. Candidates are found through
+    /// the spatial hash of `merge_duplicate_vertices`, with cells sized after the square root of
+    /// the threshold, and the first occurrence of welded vertices is kept. Returns the number of
+    /// removed vertices.
+    ///
+    /// * `eps` - Threshold on the square distance between welded vertices. Nothing is welded if
+    ///   not positive.
+    ///
+    pub fn weld_vertices(&mut self, eps: f64) -> usize
+    {
+        if eps <= 0.0 { return 0; }
+        let (representatives, n_welded) = get_duplicate_representatives(&self.vertices, eps.sqrt(), |p, q| p.coords.equals(&q.coords, eps));
+        if n_welded > 0 {
+            let map = merge_vertices(&mut self.vertices, &mut self.vertices_tags, &representatives);
+            renumber_vertices(self.get_elements_mut(), &map);
+        }
+        n_welded
+    }
+
+    /// Stitching a seam by welding each vertex tagged `tag_a` onto the nearest vertex tagged
+    /// `tag_b` within a distance threshold. Matching is one-to-one, closest pairs being welded
+    /// first. Welded vertices are removed from the mesh and elements are renumbered.
+    /// Returns the number of welded vertices.
+    ///
+    /// * `tag_a` - Tag name of the vertices to weld.
+    /// * `tag_b` - Tag name of the vertices to weld onto.
+    /// * `eps` - Distance below which two vertices can be welded.
+    ///
+    pub fn stitch_seam(&mut self, tag_a: &str, tag_b: &str, eps: f64) -> usize
+    {
+        let empty = Vec::new();
+        let seam_a = self.vertices_tags.get_registered_indexes(tag_a).unwrap_or(&empty);
+        let seam_b = self.vertices_tags.get_registered_indexes(tag_b).unwrap_or(&empty);
+
+        let mut pairs = Vec::new();
+        for &a in seam_a {
+            for &b in seam_b {
+                let d = self.vertices[a].distance_to(&self.vertices[b]);
+                if a != b && d < eps { pairs.push((d, a, b)); }
+            }
+        }
+        pairs.sort_by(|p, q| p.partial_cmp(q).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut representatives: Vec<usize> = (0..self.vertices.len()).collect();
+        let mut welded = vec![false; self.vertices.len()];
+        let mut n_welds = 0;
+        for (_, a, b) in pairs {
+            if welded[a] || welded[b] { continue; }
+            welded[a] = true;
+            welded[b] = true;
+            representatives[a] = b;
+            n_welds += 1;
+        }
+
+        if n_welds > 0 {
+            let map = merge_vertices(&mut self.vertices, &mut self.vertices_tags, &representatives);
+            renumber_vertices(self.get_elements_mut(), &map);
+        }
+        n_welds
+    }
+
+    /// Orienting triangles so that their normals point away from a reference point, which is
+    /// suited to star-shaped surfaces around the reference. Triangles whose normal points
+    /// towards the reference point are flipped.
+    ///
+    /// * `reference` - Point the triangle normals must point away from.
+    ///
+    pub fn orient_outward(&mut self, reference: &Pnt3d)
+    {
+        for i in 0..self.triangles.len() {
+            let inward = {
+                let t = self.get_tri_view(&self.triangles[i]);
+                let u = t.points[0].to(t.points[1]);
+                let v = t.points[0].to(t.points[2]);
+                u.cross_out(&v).dot(&reference.to(&t.get_barycenter())) < 0.0
+            };
+            if inward { self.triangles[i].indexes.swap(1, 2); }
+        }
+    }
+
+    /// Orienting triangles consistently with their neighbours, i.e. so that triangles sharing an
+    /// edge run through it in opposite directions. The lowest indexed triangle of each set of
+    /// triangles connected by edges keeps its orientation, and is propagated to the others.
+    /// Conflicts on non-orientable surfaces are left as found.
+    ///
+    pub fn orient_consistently(&mut self)
+    {
+        let edge_faces = {
+            let faces: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..]).collect();
+            get_edge_faces(&faces)
+        };
+        let mut visited = vec![false; self.triangles.len()];
+        for seed in 0..self.triangles.len() {
+            if visited[seed] { continue; }
+            visited[seed] = true;
+            let mut stack = vec![seed];
+            while let Some(i) = stack.pop() {
+                let indexes = self.triangles[i].indexes;
+                for k in 0..3 {
+                    let (a, b) = (indexes[k], indexes[(k + 1) % 3]);
+                    for &j in &edge_faces[&if a < b { (a, b) } else { (b, a) }] {
+                        if visited[j] { continue; }
+                        visited[j] = true;
+                        let t = &mut self.triangles[j].indexes;
+                        if (0..3).any(|l| t[l] == a && t[(l + 1) % 3] == b) { t.swap(1, 2); }
+                        stack.push(j);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Labelling the connected components of the mesh, two vertices being connected when they
+    /// share an element. Returns the component of each vertex, components being numbered by
+    /// their lowest vertex index. Vertices not used by any element form their own component.
+    ///
+    pub fn connected_components(&self) -> Vec<usize>
+    {
+        get_vertex_components(self.vertices.len(), &self.get_elements())
+    }
+
+    /// Splitting the mesh into its connected components, two elements being connected when they
+    /// share a vertex. Triangles touching at a single vertex thus belong to the same component,
+    /// unlike in `triangle_components` where triangles must share an edge. Each component is
+    /// returned as a compacted mesh carrying its tags, components being ordered by their lowest
+    /// vertex index. Vertices not used by any element are dropped.
+    ///
+    pub fn split_connected_components(&self) -> Vec<Mesh3d>
+    {
+        let elements = self.get_elements();
+        let labels = get_vertex_components(self.vertices.len(), &elements);
+        let mut used = vec![false; self.vertices.len()];
+        for indexes in &elements { for &v in indexes.iter() { used[v] = true; } }
+
+        let mut meshes: Vec<Mesh3d> = Vec::new();
+        let mut components = HashMap::new();
+        let mut component = vec![0; self.vertices.len()];
+        let mut local = vec![None; self.vertices.len()];
+        for v in 0..self.vertices.len() {
+            if !used[v] { continue; }
+            let c = *components.entry(labels[v]).or_insert_with(|| { meshes.push(Mesh3d::default()); meshes.len() - 1 });
+            component[v] = c;
+            local[v] = Some(meshes[c].vertices.len());
+            meshes[c].vertices.push(self.vertices[v].clone());
+        }
+        let localize = |indexes: &mut [usize]| for idx in indexes.iter_mut() { *idx = local[*idx].unwrap_or(0); };
+
+        for e in &self.edges {
+            let mut indexes = e.indexes;
+            localize(&mut indexes);
+            meshes[component[e.indexes[0]]].edges.push(Edge::new(indexes));
+        }
+        for t in &self.triangles {
+            let mut indexes = t.indexes;
+            localize(&mut indexes);
+            meshes[component[t.indexes[0]]].triangles.push(Tri::new(indexes));
+        }
+        for q in &self.quadrangles {
+            let mut indexes = q.indexes;
+            localize(&mut indexes);
+            meshes[component[q.indexes[0]]].quadrangles.push(Quad::new(indexes));
+        }
+        for t in &self.tetrahedra {
+            let mut indexes = t.indexes;
+            localize(&mut indexes);
+            meshes[component[t.indexes[0]]].tetrahedra.push(Tet::new(indexes));
+        }
+        for h in &self.hexahedra {
+            let mut indexes = h.indexes;
+            localize(&mut indexes);
+            meshes[component[h.indexes[0]]].hexahedra.push(Hexa::new(indexes));
+        }
+
+        let n_components = meshes.len();
+        let edges: Vec<&[usize]> = self.edges.iter().map(|e| &e.indexes[..]).collect();
+        let triangles: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..]).collect();
+        let quadrangles: Vec<&[usize]> = self.quadrangles.iter().map(|q| &q.indexes[..]).collect();
+        let tetrahedra: Vec<&[usize]> = self.tetrahedra.iter().map(|t| &t.indexes[..]).collect();
+        let hexahedra: Vec<&[usize]> = self.hexahedra.iter().map(|h| &h.indexes[..]).collect();
+        let edges_maps = get_component_element_maps(&edges, &component, n_components);
+        let triangles_maps = get_component_element_maps(&triangles, &component, n_components);
+        let quadrangles_maps = get_component_element_maps(&quadrangles, &component, n_components);
+        let tetrahedra_maps = get_component_element_maps(&tetrahedra, &component, n_components);
+        let hexahedra_maps = get_component_element_maps(&hexahedra, &component, n_components);
+        for (c, mesh) in meshes.iter_mut().enumerate() {
+            let vertices_map: Vec<Option<usize>> = (0..self.vertices.len())
+                .map(|v| if used[v] && component[v] == c { local[v] } else { None })
+                .collect();
+            mesh.vertices_tags = self.vertices_tags.remap(&vertices_map);
+            mesh.edges_tags = self.edges_tags.remap(&edges_maps[c]);
+            mesh.triangles_tags = self.triangles_tags.remap(&triangles_maps[c]);
+            mesh.quadrangles_tags = self.quadrangles_tags.remap(&quadrangles_maps[c]);
+            mesh.tetrahedra_tags = self.tetrahedra_tags.remap(&tetrahedra_maps[c]);
+            mesh.hexahedra_tags = self.hexahedra_tags.remap(&hexahedra_maps[c]);
+        }
+        meshes
+    }
+
+    /// Reordering vertices along a Morton (Z-order) curve over their coordinates quantized in the
+    /// bounding box of the mesh, so that spatially close vertices get close indexes. Elements and
+    /// vertex tags are renumbered accordingly. Returns the permutation, i.e. the former index of
+    /// each vertex in the new numbering.
+    ///
+    pub fn reorder_vertices_morton(&mut self) -> Vec<usize>
+    {
+        let bbox = match self.get_bounding_box() {
+            Some(bbox) => bbox,
+            None => return Vec::new(),
+        };
+        let codes: Vec<u64> = self.vertices.iter().map(|p| get_morton_code(&p.coords, &bbox)).collect();
+        let mut permutation: Vec<usize> = (0..self.vertices.len()).collect();
+        permutation.sort_by_key(|&v| codes[v]);
+
+        let mut map = vec![0; self.vertices.len()];
+        for (new_idx, &old_idx) in permutation.iter().enumerate() { map[old_idx] = new_idx; }
+        let mut vertices: Vec<Option<Pnt3d>> = self.vertices.drain(..).map(Some).collect();
+        self.vertices = permutation.iter().filter_map(|&v| vertices[v].take()).collect();
+
+        let tag_map: Vec<Option<usize>> = map.iter().map(|&v| Some(v)).collect();
+        self.vertices_tags = self.vertices_tags.remap(&tag_map);
+        renumber_vertices(self.get_elements_mut(), &map);
+        permutation
+    }
+
+    // Collecting the vertex indexes of all the elements of the mesh.
+    fn get_elements(&self) -> Vec<&[usize]>
+    {
+        self.edges.iter().map(|e| &e.indexes[..])
+            .chain(self.triangles.iter().map(|t| &t.indexes[..]))
+            .chain(self.quadrangles.iter().map(|q| &q.indexes[..]))
+            .chain(self.tetrahedra.iter().map(|t| &t.indexes[..]))
+            .chain(self.hexahedra.iter().map(|h| &h.indexes[..]))
+            .collect()
+    }
+
+    // Collecting mutable vertex indexes of all the elements of the mesh.
+    fn get_elements_mut(&mut self) -> Vec<&mut [usize]>
+    {
+        self.edges.iter_mut().map(|e| &mut e.indexes[..])
+            .chain(self.triangles.iter_mut().map(|t| &mut t.indexes[..]))
+            .chain(self.quadrangles.iter_mut().map(|q| &mut q.indexes[..]))
+            .chain(self.tetrahedra.iter_mut().map(|t| &mut t.indexes[..]))
+            .chain(self.hexahedra.iter_mut().map(|h| &mut h.indexes[..]))
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D data structure.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Structure defining a 2d tagged mesh.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Mesh2d {
+    pub vertices: Vec<Pnt2d>,
+    pub edges: Vec<Edge>,
+    pub triangles: Vec<Tri>,
+    pub quadrangles: Vec<Quad>,
+    pub vertices_tags: TagSet,
+    pub edges_tags: TagSet,
+    pub triangles_tags: TagSet,
+    pub quadrangles_tags: TagSet,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Mesh2d {
+    /// Creating a tagged vertex from coordinates & tag name.
+    ///
+    /// * `point` - Point to add in the mesh.
+    /// * `name` - Tag name.
+    ///
+    pub fn push_tagged_vertex(&mut self, point: Pnt2d, name: &str)
+    {
+        push_tagged_element(&mut self.vertices, &mut self.vertices_tags, point, name);
+    }
+
+    /// Creating an tagged edge in the mesh.
+    ///
+    /// * `edge` - Edge to add in the mesh.
+    /// * `name` - Tag name.
+    ///
+    pub fn push_tagged_edge(&mut self, edge: Edge, name: &str)
+    {
+        push_tagged_element(&mut self.edges, &mut self.edges_tags, edge, name);
+    }
+
+    /// Creating a view to an edge in a mesh from the input edge itself.
+    ///
+    /// * `edge` - Edge in the mesh.
+    ///
+    pub fn get_edge_view<'a>(&'a self, edge: &Edge) -> EdgeView2d<'a>
+    {
+        EdgeView2d { points: get_two_vertices_view(&self.vertices, &edge.indexes) }
+    }
+
+    /// Creating an tagged triangle in the mesh.
+    ///
+    /// * `tri` - Triangle to add in the mesh.
+    /// * `name` - tag name.
+    ///
+    pub fn push_tagged_triangle(&mut self, tri: Tri, name: &str)
+    {
+        push_tagged_element(&mut self.triangles, &mut self.triangles_tags, tri, name);
+    }
+
+    /// Making a view to a triangle in a mesh from the element itself.
+    ///
+    /// * `tri` - Triangle in the mesh.
+    ///
+    pub fn get_tri_view<'a>(&'a self, tri: &Tri) -> TriView2d<'a>
+    {
+        TriView2d { points: get_three_vertices_view(&self.vertices, &tri.indexes) }
+    }
+
+    /// Creating an tagged quadrangle in the mesh.
+    ///
+    /// * `quad` - Quadrangle to add in the mesh.
+    /// * `name` - Tag name.
+    ///
+    pub fn push_tagged_quadrangle(&mut self, quad: Quad, name: &str)
+    {
+        push_tagged_element(&mut self.quadrangles, &mut self.quadrangles_tags, quad, name);
+    }
+
+    /// Making a view to a quadrangle in a mesh from the element itself.
+    ///
+    /// * `quad` - Quadrangle in the mesh.
+    ///
+    pub fn get_quad_view<'a>(&'a self, quad: &Quad) -> QuadView2d<'a>
+    {
+        QuadView2d { points: get_four_vertices_view(&self.vertices, &quad.indexes) }
+    }
+
+    /// Translating all vertices of the mesh. In-place function.
+    ///
+    /// * `v` - Translation vector.
+    ///
+    pub fn translate(&mut self, v: &Vec2d) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.add_in(1.0, &v.coords); }
+        self
+    }
+
+    /// Scaling all vertices of the mesh about the origin. In-place function.
+    ///
+    /// * `factor` - Scaling factor.
+    ///
+    pub fn scale(&mut self, factor: f64) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.amplify_in(factor); }
+        self
+    }
+
+    /// Scaling all vertices of the mesh about a center. In-place function.
+    ///
+    /// * `center` - Point left unchanged by the scaling.
+    /// * `factor` - Scaling factor.
+    ///
+    pub fn scale_about(&mut self, center: &Pnt2d, factor: f64) -> &mut Self
+    {
+        for p in &mut self.vertices { p.coords.mlt_add_in(factor, 1.0 - factor, &center.coords); }
+        self
+    }
+
+    /// Rotating all vertices of the mesh around a center, counterclockwise for a positive angle.
+    /// In-place function.
+    ///
+    /// * `angle` - Rotation angle in radians.
+    /// * `center` - Center of the rotation.
+    ///
+    pub fn rotate(&mut self, angle: f64, center: &Pnt2d) -> &mut Self
+    {
+        for p in &mut self.vertices { *p = p.rotate_around(center, angle); }
+        self
+    }
+
+    /// Computing the total area of the triangles and quadrangles of the mesh.
+    ///
+    pub fn get_total_area(&self) -> f64
+    {
+        self.triangles.iter().map(|t| self.get_tri_view(t).get_area()).sum::<f64>()
+            + self.quadrangles.iter().map(|q| self.get_quad_view(q).get_area()).sum::<f64>()
+    }
+
+    /// Computing the total area of the triangles and quadrangles of the mesh, as in
+    /// `get_total_area`.
+    ///
+    pub fn total_area(&self) -> f64
+    {
+        self.get_total_area()
+    }
+
+    /// Computing the area of each triangle of the mesh.
+    ///
+    pub fn triangle_areas(&self) -> Vec<f64>
+    {
+        self.triangles.iter().map(|t| self.get_tri_view(t).get_area()).collect()
+    }
+
+    /// Computing the axis-aligned bounding box of the vertices. Returns `None` if the mesh has no
+    /// vertex.
+    ///
+    pub fn get_bounding_box(&self) -> Option<BoundingBox2d>
+    {
+        BoundingBox2d::from_points(&self.vertices)
+    }
+
+    /// Finding a triangle containing a point, as in `TriLocator2d::locate`. The bounding boxes of
+    /// the triangles are indexed at each call, so a locator built by `build_tri_locator` should
+    /// be reused when locating many points.
+    ///
+    /// * `p` - Point to locate.
+    ///
+    pub fn locate_point(&self, p: &Pnt2d) -> Option<usize>
+    {
+        self.build_tri_locator().locate(p)
+    }
+
+    /// Serializing the mesh to JSON, either pretty-printed or compact.
+    ///
+    /// * `pretty` - Whether the output is pretty-printed.
+    ///
+    pub fn to_json(&self, pretty: bool) -> serde_json::Result<String>
+    {
+        if pretty { serde_json::to_string_pretty(self) } else { serde_json::to_string(self) }
+    }
+
+    /// Deserializing a mesh from JSON.
+    ///
+    /// * `s` - JSON string describing the mesh.
+    ///
+    pub fn from_json(s: &str) -> serde_json::Result<Mesh2d>
+    {
+        serde_json::from_str(s)
+    }
+
+    /// Extracting the boundary edges of the triangles and quadrangles of the mesh, i.e. the edges
+    /// used by a single face, oriented as in their face. The edges are hence oriented outward
+    /// when the faces are counterclockwise.
+    ///
+    pub fn extract_boundary(&self) -> Vec<Edge>
+    {
+        let faces: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..])
+            .chain(self.quadrangles.iter().map(|q| &q.indexes[..]))
+            .collect();
+        let edge_faces = get_edge_faces(&faces);
+        let mut boundary = Vec::new();
+        for f in &faces {
+            for k in 0..f.len() {
+                let (a, b) = (f[k], f[(k + 1) % f.len()]);
+                if edge_faces[&if a < b { (a, b) } else { (b, a) }].len() == 1 { boundary.push(Edge::new([a, b])); }
+            }
+        }
+        boundary
+    }
+
+    /// Pushing the boundary edges of the triangles and quadrangles of the mesh as edges tagged
+    /// with a name.
+    ///
+    /// * `name` - Tag name.
+    ///
+    pub fn tag_boundary(&mut self, name: &str)
+    {
+        for edge in self.extract_boundary() { self.push_tagged_edge(edge, name); }
+    }
+
+    /// Appending another mesh, whose vertices are added after the vertices of the mesh and whose
+    /// elements are renumbered accordingly. Tags are merged, indexes of tags sharing the same
+    /// name being accumulated.
+    ///
+    /// * `other` - Mesh to append.
+    ///
+    pub fn append(&mut self, other: &Mesh2d)
+    {
+        self.append_with_prefix(other, "");
+    }
+
+    /// Appending another mesh as in `append`, prefixing the names of its tags so that they do
+    /// not collide with the tags of the mesh.
+    ///
+    /// * `other` - Mesh to append.
+    /// * `prefix` - Prefix added to the tag names of the appended mesh.
+    ///
+    pub fn append_with_prefix(&mut self, other: &Mesh2d, prefix: &str)
+    {
+        let offset = self.vertices.len();
+        let shift = |indexes: &mut [usize]| for idx in indexes.iter_mut() { *idx += offset; };
+
+        self.vertices_tags.merge(&other.vertices_tags, offset, prefix);
+        self.edges_tags.merge(&other.edges_tags, self.edges.len(), prefix);
+        self.triangles_tags.merge(&other.triangles_tags, self.triangles.len(), prefix);
+        self.quadrangles_tags.merge(&other.quadrangles_tags, self.quadrangles.len(), prefix);
+
+        self.vertices.extend(other.vertices.iter().cloned());
+        for e in &other.edges {
+            let mut indexes = e.indexes;
+            shift(&mut indexes);
+            self.edges.push(Edge::new(indexes));
+        }
+        for t in &other.triangles {
+            let mut indexes = t.indexes;
+            shift(&mut indexes);
+            self.triangles.push(Tri::new(indexes));
+        }
+        for q in &other.quadrangles {
+            let mut indexes = q.indexes;
+            shift(&mut indexes);
+            self.quadrangles.push(Quad::new(indexes));
+        }
+    }
+
+    /// Removing the vertices which are not referenced by any element, as in
+    /// `Mesh3d::remove_unused_vertices`. Returns the former indexes of the removed vertices, in
+    /// increasing order.
+    ///
+    pub fn remove_unused_vertices(&mut self) -> Vec<usize>
+    {
+        let removed = get_unused_vertices(self.vertices.len(), &self.get_elements());
+        let map = remove_elements(&mut self.vertices, &mut self.vertices_tags, &removed);
+        let map: Vec<usize> = map.iter().map(|v| v.unwrap_or(0)).collect();
+        renumber_vertices(self.get_elements_mut(), &map);
+        removed
+    }
+
+    /// Merging vertices closer than a distance threshold, as in `Mesh3d::merge_duplicate_vertices`.
+    /// Merged vertices are removed from the mesh, elements are renumbered and vertex tags point to
+    /// the remaining vertices. Returns the number of removed vertices.
+    ///
+    /// * `tol` - Distance below which vertices are merged. Nothing is merged if not positive.
+    ///
+    pub fn merge_duplicate_vertices(&mut self, tol: f64) -> usize
+    {
+        if tol <= 0.0 { return 0; }
+        let points: Vec<Pnt3d> = self.vertices.iter().map(|p| Pnt3d::new([p.coords.x, p.coords.y, 0.])).collect();
+        let (representatives, n_merged) = get_duplicate_representatives(&points, tol, |p, q| p.distance_to(q) < tol);
+        if n_merged > 0 {
+            let map = merge_vertices(&mut self.vertices, &mut self.vertices_tags, &representatives);
+            renumber_vertices(self.get_elements_mut(), &map);
+        }
+        n_merged
+    }
+
+    /// Labelling the connected components of the mesh, two vertices being connected when they
+    /// share an element. Returns the component of each vertex, components being numbered by
+    /// their lowest vertex index. Vertices not used by any element form their own component.
+    ///
+    pub fn connected_components(&self) -> Vec<usize>
+    {
+        get_vertex_components(self.vertices.len(), &self.get_elements())
+    }
+
+    /// Splitting the mesh into its connected components, two elements being connected when they
+    /// share a vertex. Each component is returned as a compacted mesh carrying its tags, components
+    /// being ordered by their lowest vertex index. Vertices not used by any element are dropped.
+    ///
+    pub fn split_connected_components(&self) -> Vec<Mesh2d>
+    {
+        let elements = self.get_elements();
+        let labels = get_vertex_components(self.vertices.len(), &elements);
+        let mut used = vec![false; self.vertices.len()];
+        for indexes in &elements { for &v in indexes.iter() { used[v] = true; } }
+
+        let mut meshes: Vec<Mesh2d> = Vec::new();
+        let mut components = HashMap::new();
+        let mut component = vec![0; self.vertices.len()];
+        let mut local = vec![None; self.vertices.len()];
+        for v in 0..self.vertices.len() {
+            if !used[v] { continue; }
+            let c = *components.entry(labels[v]).or_insert_with(|| { meshes.push(Mesh2d::default()); meshes.len() - 1 });
+            component[v] = c;
+            local[v] = Some(meshes[c].vertices.len());
+            meshes[c].vertices.push(self.vertices[v].clone());
+        }
+        let localize = |indexes: &mut [usize]| for idx in indexes.iter_mut() { *idx = local[*idx].unwrap_or(0); };
+
+        for e in &self.edges {
+            let mut indexes = e.indexes;
+            localize(&mut indexes);
+            meshes[component[e.indexes[0]]].edges.push(Edge::new(indexes));
+        }
+        for t in &self.triangles {
+            let mut indexes = t.indexes;
+            localize(&mut indexes);
+            meshes[component[t.indexes[0]]].triangles.push(Tri::new(indexes));
+        }
+        for q in &self.quadrangles {
+            let mut indexes = q.indexes;
+            localize(&mut indexes);
+            meshes[component[q.indexes[0]]].quadrangles.push(Quad::new(indexes));
+        }
+
+        let n_components = meshes.len();
+        let edges: Vec<&[usize]> = self.edges.iter().map(|e| &e.indexes[..]).collect();
+        let triangles: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..]).collect();
+        let quadrangles: Vec<&[usize]> = self.quadrangles.iter().map(|q| &q.indexes[..]).collect();
+        let edges_maps = get_component_element_maps(&edges, &component, n_components);
+        let triangles_maps = get_component_element_maps(&triangles, &component, n_components);
+        let quadrangles_maps = get_component_element_maps(&quadrangles, &component, n_components);
+        for (c, mesh) in meshes.iter_mut().enumerate() {
+            let vertices_map: Vec<Option<usize>> = (0..self.vertices.len())
+                .map(|v| if used[v] && component[v] == c { local[v] } else { None })
+                .collect();
+            mesh.vertices_tags = self.vertices_tags.remap(&vertices_map);
+            mesh.edges_tags = self.edges_tags.remap(&edges_maps[c]);
+            mesh.triangles_tags = self.triangles_tags.remap(&triangles_maps[c]);
+            mesh.quadrangles_tags = self.quadrangles_tags.remap(&quadrangles_maps[c]);
+        }
+        meshes
+    }
+
+    /// Replacing every quadrangle by its triangles (P0, P1, P3) and (P1, P2, P3), as named in
+    /// `TriInQuad`. Triangles are appended in the order of their quadrangles and inherit their
+    /// tags. The quadrangles and their tags are cleared.
+    ///
+    pub fn triangulate_quads(&mut self)
+    {
+        let (offset, n_quads) = (self.triangles.len(), self.quadrangles.len());
+        for q in self.quadrangles.drain(..) {
+            self.triangles.push(Tri::new([q.indexes[0], q.indexes[1], q.indexes[3]]));
+            self.triangles.push(Tri::new([q.indexes[1], q.indexes[2], q.indexes[3]]));
+        }
+        self.triangles_tags.merge(&get_split_tags(&self.quadrangles_tags, n_quads, 2), offset, "");
+        self.quadrangles_tags = TagSet::default();
+    }
+
+    /// Refining the triangles uniformly, each triangle being split into four by inserting the
+    /// midpoints of its edges. Midpoints are shared between neighbouring triangles, so that the
+    /// refined triangles remain conforming, and every edge is split into two halves. Children
+    /// inherit the tags of their parent, the children of element i being numbered from 4 * i for
+    /// triangles and 2 * i for edges. Quadrangles are left untouched.
+    ///
+    pub fn refine_uniform(&mut self)
+    {
+        refine_triangles(&mut self.vertices, &mut self.edges, &mut self.edges_tags, &mut self.triangles,
+                         &mut self.triangles_tags, |p, q| p.midpoint_with(q));
+    }
+
+    /// Splitting an edge at its midpoint, inserted as a new vertex, along with the triangles and
+    /// quadrangles incident to it, i.e. one element for a boundary edge and two for an interior
+    /// edge, so that the mesh stays conforming. The first half of the edge and of each triangle
+    /// keeps its index, the second half is appended and both inherit the tags of their parent.
+    /// Each quadrangle (P0, P1, P2, P3) split along its edge (P0, P1) at M is replaced by the
+    /// quadrangle (P0, M, P2, P3), keeping its index, and the triangle (M, P1, P2), appended to
+    /// the triangles and registered under the tags of the quadrangle. Returns the index of the
+    /// new vertex. Panics if the edge index is out of range.
+    ///
+    /// * `edge_index` - Index of the edge to split.
+    ///
+    pub fn split_edge(&mut self, edge_index: usize) -> usize
+    {
+        let [a, b] = self.edges[edge_index].indexes;
+        let m = self.vertices.len();
+        let midpoint = self.vertices[a].midpoint_with(&self.vertices[b]);
+        self.vertices.push(midpoint);
+
+        self.edges[edge_index].indexes[1] = m;
+        self.edges.push(Edge::new([m, b]));
+        copy_tags(&mut self.edges_tags, edge_index, self.edges.len() - 1);
+
+        for i in 0..self.triangles.len() {
+            let t = self.triangles[i].indexes;
+            let k = match (0..3).find(|&k| (t[k] == a && t[(k + 1) % 3] == b) || (t[k] == b && t[(k + 1) % 3] == a)) {
+                Some(k) => k,
+                None => continue,
+            };
+            let mut second = t;
+            self.triangles[i].indexes[(k + 1) % 3] = m;
+            second[k] = m;
+            self.triangles.push(Tri::new(second));
+            copy_tags(&mut self.triangles_tags, i, self.triangles.len() - 1);
+        }
+
+        for i in 0..self.quadrangles.len() {
+            let q = self.quadrangles[i].indexes;
+            let k = match (0..4).find(|&k| (q[k] == a && q[(k + 1) % 4] == b) || (q[k] == b && q[(k + 1) % 4] == a)) {
+                Some(k) => k,
+                None => continue,
+            };
+            self.quadrangles[i].indexes = [q[k], m, q[(k + 2) % 4], q[(k + 3) % 4]];
+            self.triangles.push(Tri::new([m, q[(k + 1) % 4], q[(k + 2) % 4]]));
+            let names: Vec<String> = self.quadrangles_tags.tags_of(i).into_iter().cloned().collect();
+            for name in &names { self.triangles_tags.register(name, self.triangles.len() - 1); }
+        }
+        m
+    }
+
+    /// Extruding the mesh along a direction into a 3D mesh made of n_layers + 1 copies of the
+    /// vertices, lifted in the plane z = 0 and evenly translated up to the direction. Each
+    /// triangle is extruded into prisms split into three tetrahedra, neighbouring prisms sharing
+    /// the diagonals of their common sides, and each quadrangle into hexahedra. Faces on the
+    /// first and last layers are added as triangles and quadrangles tagged "bottom" and "top",
+    /// and the extrusions of the boundary edges as quadrangles tagged "lateral", all oriented
+    /// outward. Vertex tags are carried to every layer, triangle and quadrangle tags to the
+    /// tetrahedra and hexahedra they are extruded into, and tags of boundary edges to their
+    /// lateral quadrangles. Panics if n_layers is zero.
+    ///
+    /// * `direction` - Extrusion vector, from the first layer to the last.
+    /// * `n_layers` - Number of layers of volume elements.
+    ///
+    pub fn extrude(&self, direction: &Vec3d, n_layers: usize) -> Mesh3d
+    {
+        assert!(n_layers > 0, "an extrusion needs at least one layer");
+        let (n_vertices, n_triangles, n_quadrangles) = (self.vertices.len(), self.triangles.len(), self.quadrangles.len());
+        let v = |i: usize, l: usize| i + l * n_vertices;
+        let mut mesh = Mesh3d::default();
+        for l in 0..n_layers + 1 {
+            let offset = Vec3d { coords: direction.coords.amplify_out(l as f64 / n_layers as f64) };
+            for p in &self.vertices { mesh.vertices.push(Pnt3d::new([p.coords.x, p.coords.y, 0.]).translate_by(&offset)); }
+            mesh.vertices_tags.merge(&self.vertices_tags, l * n_vertices, "");
+        }
+
+        // Whether the extrusion of each face is reversed, i.e. whether the face is not
+        // counterclockwise when seen from the side it is extruded toward.
+        let faces: Vec<&[usize]> = self.triangles.iter().map(|t| &t.indexes[..])
+            .chain(self.quadrangles.iter().map(|q| &q.indexes[..]))
+            .collect();
+        let vertices = &self.vertices;
+        let reversed: Vec<bool> = faces.iter().map(|f| {
+            let area: f64 = (1..f.len() - 1).map(|k| orient2d(&vertices[f[0]], &vertices[f[k]], &vertices[f[k + 1]])).sum();
+            (area > 0.) != (direction.coords.z > 0.)
+        }).collect();
+
+        let split_tags = get_split_tags(&self.triangles_tags, n_triangles, 3);
+        for l in 0..n_layers {
+            for tri in &self.triangles {
+                let mut s = tri.indexes;
+                s.sort();
+                let tets = [
+                    [v(s[0], l), v(s[1], l), v(s[2], l), v(s[2], l + 1)],
+                    [v(s[0], l), v(s[1], l), v(s[1], l + 1), v(s[2], l + 1)],
+                    [v(s[0], l), v(s[0], l + 1), v(s[1], l + 1), v(s[2], l + 1)]
+                ];
+                for &indexes in &tets {
+                    let mut tet = Tet::new(indexes);
+                    if mesh.get_tet_view(&tet).get_signed_volume() < 0. { tet.indexes.swap(1, 2); }
+                    mesh.tetrahedra.push(tet);
+                }
+            }
+            mesh.tetrahedra_tags.merge(&split_tags, 3 * l * n_triangles, "");
+
+            for (i, quad) in self.quadrangles.iter().enumerate() {
+                let mut q = quad.indexes;
+                if reversed[n_triangles + i] { q.swap(1, 3); }
+                mesh.hexahedra.push(Hexa::new([
+                    v(q[0], l), v(q[1], l), v(q[2], l), v(q[3], l),
+                    v(q[0], l + 1), v(q[1], l + 1), v(q[2], l + 1), v(q[3], l + 1)
+                ]));
+            }
+            mesh.hexahedra_tags.merge(&self.quadrangles_tags, l * n_quadrangles, "");
+        }
+
+        for (i, tri) in self.triangles.iter().enumerate() {
+            let [a, b, c] = tri.indexes;
+            let (bottom, top) = if reversed[i] { ([a, b, c], [a, c, b]) } else { ([a, c, b], [a, b, c]) };
+            mesh.push_tagged_triangle(Tri::new([v(bottom[0], 0), v(bottom[1], 0), v(bottom[2], 0)]), "bottom");
+            mesh.push_tagged_triangle(Tri::new([v(top[0], n_layers), v(top[1], n_layers), v(top[2], n_layers)]), "top");
+        }
+        for (i, quad) in self.quadrangles.iter().enumerate() {
+            let [a, b, c, d] = quad.indexes;
+            let (bottom, top) = if reversed[n_triangles + i] { ([a, b, c, d], [a, d, c, b]) } else { ([a, d, c, b], [a, b, c, d]) };
+            mesh.push_tagged_quadrangle(Quad::new([v(bottom[0], 0), v(bottom[1], 0), v(bottom[2], 0), v(bottom[3], 0)]), "bottom");
+            mesh.push_tagged_quadrangle(Quad::new([v(top[0], n_layers), v(top[1], n_layers), v(top[2], n_layers), v(top[3], n_layers)]), "top");
+        }
+
+        // Boundary edges, oriented so that their extrusions face outward.
+        let edge_faces = get_edge_faces(&faces);
+        let mut boundary = Vec::new();
+        for (i, f) in faces.iter().enumerate() {
+            for k in 0..f.len() {
+                let (a, b) = (f[k], f[(k + 1) % f.len()]);
+                if edge_faces[&if a < b { (a, b) } else { (b, a) }].len() == 1 { boundary.push(if reversed[i] { (b, a) } else { (a, b) }); }
+            }
+        }
+        let boundary_indexes: HashMap<(usize, usize), usize> = boundary.iter().enumerate()
+            .map(|(j, &(a, b))| (if a < b { (a, b) } else { (b, a) }, j))
+            .collect();
+        let map: Vec<Option<usize>> = self.edges.iter()
+            .map(|e| { let (a, b) = (e.indexes[0], e.indexes[1]); boundary_indexes.get(&if a < b { (a, b) } else { (b, a) }).cloned() })
+            .collect();
+        let lateral_tags = self.edges_tags.remap(&map);
+        for l in 0..n_layers {
+            mesh.quadrangles_tags.merge(&lateral_tags, mesh.quadrangles.len(), "");
+            for &(a, b) in &boundary { mesh.push_tagged_quadrangle(Quad::new([v(a, l), v(b, l), v(b, l + 1), v(a, l + 1)]), "lateral"); }
+        }
+        mesh
+    }
+
+    // Collecting the vertex indexes of all the elements of the mesh.
+    fn get_elements(&self) -> Vec<&[usize]>
+    {
+        self.edges.iter().map(|e| &e.indexes[..])
+            .chain(self.triangles.iter().map(|t| &t.indexes[..]))
+            .chain(self.quadrangles.iter().map(|q| &q.indexes[..]))
+            .collect()
+    }
+
+    // Collecting mutable vertex indexes of all the elements of the mesh.
+    fn get_elements_mut(&mut self) -> Vec<&mut [usize]>
+    {
+        self.edges.iter_mut().map(|e| &mut e.indexes[..])
+            .chain(self.triangles.iter_mut().map(|t| &mut t.indexes[..]))
+            .chain(self.quadrangles.iter_mut().map(|q| &mut q.indexes[..]))
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Element storage.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Implementing the access to the storage of an element type in a 3d mesh.
+macro_rules! impl_mesh3d_element {
+    ($t:ident, $elements:ident, $tags:ident) => {
+        impl Mesh3dElement for $t {
+            fn get_storage(mesh: &mut Mesh3d) -> (&mut Vec<$t>, &mut TagSet) { (&mut mesh.$elements, &mut mesh.$tags) }
+        }
+    }
+}
+
+impl_mesh3d_element!(Edge, edges, edges_tags);
+impl_mesh3d_element!(Tri, triangles, triangles_tags);
+impl_mesh3d_element!(Quad, quadrangles, quadrangles_tags);
+impl_mesh3d_element!(Tet, tetrahedra, tetrahedra_tags);
+impl_mesh3d_element!(Hexa, hexahedra, hexahedra_tags);
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Pushing an element into a vector of elements and registering its associated tag.
+fn push_tagged_element<T>(elements: &mut Vec<T>, tags: &mut TagSet, element: T, name: &str)
+{
+    let idx = elements.len();
+    elements.push(element);
+    tags.register(name, idx);
+}
+
+// Mapping vertices to new positions, in parallel when the rayon feature is enabled.
+#[cfg(feature = "rayon")]
+fn map_vertices<F>(vertices: &[Pnt3d], f: F) -> Vec<Pnt3d> where F: Fn(usize, &Pnt3d) -> Pnt3d + Sync
+{
+    vertices.par_iter().enumerate().map(|(i, p)| f(i, p)).collect()
+}
+
+// Mapping vertices to new positions, in parallel when the rayon feature is enabled.
+#[cfg(not(feature = "rayon"))]
+fn map_vertices<F>(vertices: &[Pnt3d], f: F) -> Vec<Pnt3d> where F: Fn(usize, &Pnt3d) -> Pnt3d + Sync
+{
+    vertices.iter().enumerate().map(|(i, p)| f(i, p)).collect()
+}
+
+// Removing elements, compacting the remaining ones and renumbering them in their tags. Indexes
+// out of range are ignored. Returns the map from former to new element indexes.
+fn remove_elements<T>(elements: &mut Vec<T>, tags: &mut TagSet, indexes: &[usize]) -> Vec<Option<usize>>
+{
+    let mut removed = vec![false; elements.len()];
+    for &i in indexes { if let Some(r) = removed.get_mut(i) { *r = true; } }
+
+    let mut map = Vec::with_capacity(removed.len());
+    let mut n_kept = 0;
+    for &r in &removed {
+        map.push(if r { None } else { n_kept += 1; Some(n_kept - 1) });
+    }
+    let mut i = 0;
+    elements.retain(|_| { i += 1; !removed[i - 1] });
+    *tags = tags.remap(&map);
+    map
+}
+
+// Splitting triangles into four and edges into two by inserting shared edge midpoints, tags
+// being transferred to the children.
+fn refine_triangles<P, F>(vertices: &mut Vec<P>, edges: &mut Vec<Edge>, edges_tags: &mut TagSet, triangles: &mut Vec<Tri>,
+                          triangles_tags: &mut TagSet, midpoint: F) where F: Fn(&P, &P) -> P
+{
+    let mut midpoints = HashMap::new();
+    let mut get_midpoint = |vertices: &mut Vec<P>, a: usize, b: usize| {
+        *midpoints.entry(if a < b { (a, b) } else { (b, a) }).or_insert_with(|| {
+            let p = midpoint(&vertices[a], &vertices[b]);
+            vertices.push(p);
+            vertices.len() - 1
+        })
+    };
+
+    let mut refined_triangles = Vec::with_capacity(4 * triangles.len());
+    for t in triangles.iter() {
+        let [a, b, c] = t.indexes;
+        let (ab, bc, ca) = (get_midpoint(vertices, a, b), get_midpoint(vertices, b, c), get_midpoint(vertices, c, a));
+        refined_triangles.push(Tri::new([a, ab, ca]));
+        refined_triangles.push(Tri::new([ab, b, bc]));
+        refined_triangles.push(Tri::new([ca, bc, c]));
+        refined_triangles.push(Tri::new([ab, bc, ca]));
+    }
+    let mut refined_edges = Vec::with_capacity(2 * edges.len());
+    for e in edges.iter() {
+        let [a, b] = e.indexes;
+        let m = get_midpoint(vertices, a, b);
+        refined_edges.push(Edge::new([a, m]));
+        refined_edges.push(Edge::new([m, b]));
+    }
+
+    *triangles_tags = get_split_tags(triangles_tags, triangles.len(), 4);
+    *edges_tags = get_split_tags(edges_tags, edges.len(), 2);
+    *triangles = refined_triangles;
+    *edges = refined_edges;
+}
+
+// Registering an element under the tags of another element.
+fn copy_tags(tags: &mut TagSet, from: usize, to: usize)
+{
+    let mut map = vec![None; from + 1];
+    map[from] = Some(to);
+    let copy = tags.remap(&map);
+    tags.merge(&copy, 0, "");
+}
+
+// Mapping the tags of split elements to the parts they are split into, the parts of element i
+// being numbered from i * n_parts.
+fn get_split_tags(tags: &TagSet, n_elements: usize, n_parts: usize) -> TagSet
+{
+    let mut split_tags = TagSet::default();
+    for k in 0..n_parts {
+        let map: Vec<Option<usize>> = (0..n_elements).map(|i| Some(i * n_parts + k)).collect();
+        split_tags.merge(&tags.remap(&map), 0, "");
+    }
+    split_tags
+}
+
+// Collecting the indexes of the elements referencing at least one flagged vertex.
+fn get_elements_using<'a, I>(elements: I, flagged: &[bool]) -> Vec<usize> where I: Iterator<Item = &'a [usize]>
+{
+    elements.enumerate()
+        .filter(|&(_, indexes)| indexes.iter().any(|&v| flagged[v]))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+// Collecting, in increasing order, the vertices which are not referenced by any element.
+fn get_unused_vertices(n_vertices: usize, elements: &[&[usize]]) -> Vec<usize>
+{
+    let mut used = vec![false; n_vertices];
+    for indexes in elements { for &v in indexes.iter() { used[v] = true; } }
+    (0..n_vertices).filter(|&v| !used[v]).collect()
+}
+
+// Merging vertices onto representatives, which must be their own representative. Merged
+// vertices are removed and vertex tags are renumbered accordingly. Returns the old to new index
+// map with which elements are renumbered.
+fn merge_vertices<P>(vertices: &mut Vec<P>, vertices_tags: &mut TagSet, representatives: &[usize]) -> Vec<usize>
+{
+    let mut new_indexes = vec![0; vertices.len()];
+    let mut merged = Vec::new();
+    for (v, p) in vertices.drain(..).enumerate() {
+        if representatives[v] == v {
+            new_indexes[v] = merged.len();
+            merged.push(p);
+        }
+    }
+    *vertices = merged;
+
+    let map: Vec<usize> = representatives.iter().map(|&r| new_indexes[r]).collect();
+    let tag_map: Vec<Option<usize>> = map.iter().map(|&v| Some(v)).collect();
+    *vertices_tags = vertices_tags.remap(&tag_map);
+    map
+}
+
+// Finding the representative of each point, i.e. the remaining point of lowest index it is a
+// duplicate of, through a spatial hash of cells of a positive size. Duplicates must be closer
+// than the cell size. Cell coordinates saturate at the bounds of the integer range for tiny
+// cells, the saturated cells being shared by distant points without missing any close pair.
+// Returns the representatives and the number of points merged onto another one.
+fn get_duplicate_representatives<F>(points: &[Pnt3d], cell_size: f64, is_duplicate: F) -> (Vec<usize>, usize)
+    where F: Fn(&Pnt3d, &Pnt3d) -> bool
+{
+    let cell = |p: &Pnt3d| [
+        (p.coords.x / cell_size).floor() as i64, (p.coords.y / cell_size).floor() as i64, (p.coords.z / cell_size).floor() as i64
+    ];
+
+    let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    let mut representatives: Vec<usize> = (0..points.len()).collect();
+    let mut n_merged = 0;
+    for (v, p) in points.iter().enumerate() {
+        let c = cell(p);
+        let mut found = None;
+        for dx in -1..2 {
+            for dy in -1..2 {
+                for dz in -1..2 {
+                    if let Some(candidates) = grid.get(&[c[0].saturating_add(dx), c[1].saturating_add(dy), c[2].saturating_add(dz)]) {
+                        found = candidates.iter().cloned()
+                            .filter(|&w| is_duplicate(&points[w], p))
+                            .chain(found)
+                            .min();
+                    }
+                }
+            }
+        }
+        match found {
+            Some(w) => { representatives[v] = w; n_merged += 1; },
+            None => { grid.entry(c).or_default().push(v); },
+        }
+    }
+    (representatives, n_merged)
+}
+
+// Renumbering vertex indexes of elements using an old to new index map.
+fn renumber_vertices(elements: Vec<&mut [usize]>, map: &[usize])
+{
+    for indexes in elements { for idx in indexes.iter_mut() { *idx = map[*idx]; } }
+}
+
+// Computing the Morton code of coordinates quantized over 21 bits per axis in a bounding box.
+fn get_morton_code(coords: &Coord3d, bbox: &BoundingBox3d) -> u64
+{
+    let quantize = |x: f64, min: f64, max: f64| {
+        let extent = max - min;
+        if extent <= 0.0 { 0 } else { (((x - min) / extent) * 2097151.0).round() as u64 }
+    };
+    spread_bits(quantize(coords.x, bbox.min.x, bbox.max.x))
+        | (spread_bits(quantize(coords.y, bbox.min.y, bbox.max.y)) << 1)
+        | (spread_bits(quantize(coords.z, bbox.min.z, bbox.max.z)) << 2)
+}
+
+// Spreading the lowest 21 bits of an integer so that two zero bits separate consecutive bits.
+fn spread_bits(x: u64) -> u64
+{
+    let mut x = x & 0x1f_ffff;
+    x = (x | (x << 32)) & 0x1f_0000_0000_ffff;
+    x = (x | (x << 16)) & 0x1f_0000_ff00_00ff;
+    x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+    x = (x | (x << 2)) & 0x1249_2492_4924_9249;
+    x
+}
+
+// Collecting the vertex indexes of the triangles and quadrangles of a mesh.
+fn get_surface_faces(mesh: &Mesh3d) -> Vec<&[usize]>
+{
+    mesh.triangles.iter().map(|t| &t.indexes[..])
+        .chain(mesh.quadrangles.iter().map(|q| &q.indexes[..]))
+        .collect()
+}
+
+// Collecting the faces sharing each undirected edge, edges being keyed by ordered vertex indexes.
+fn get_edge_faces(faces: &[&[usize]]) -> HashMap<(usize, usize), Vec<usize>>
+{
+    let mut edge_faces = HashMap::new();
+    for (i, f) in faces.iter().enumerate() {
+        for k in 0..f.len() {
+            let (a, b) = (f[k], f[(k + 1) % f.len()]);
+            edge_faces.entry(if a < b { (a, b) } else { (b, a) }).or_insert_with(Vec::new).push(i);
+        }
+    }
+    edge_faces
+}
+
+// Sorting the vertex indexes of an element so that they can key its shared sub-elements.
+fn get_sorted_key(indexes: &[usize]) -> Vec<usize>
+{
+    let mut key = indexes.to_vec();
+    key.sort();
+    key
+}
+
+// Finding the root of an item in a union-find forest, halving paths along the way.
+fn find_root(parents: &mut [usize], v: usize) -> usize
+{
+    let mut v = v;
+    while parents[v] != v {
+        parents[v] = parents[parents[v]];
+        v = parents[v];
+    }
+    v
+}
+
+// Joining the trees of two items in a union-find forest.
+fn join_roots(parents: &mut [usize], a: usize, b: usize)
+{
+    let (ra, rb) = (find_root(parents, a), find_root(parents, b));
+    if ra != rb { parents[ra] = rb; }
+}
+
+// Labelling the connected components of vertices joined by elements, components being numbered
+// by their lowest vertex index.
+fn get_vertex_components(n_vertices: usize, elements: &[&[usize]]) -> Vec<usize>
+{
+    let mut parents: Vec<usize> = (0..n_vertices).collect();
+    for indexes in elements {
+        for &v in indexes.iter() { join_roots(&mut parents, indexes[0], v); }
+    }
+    let mut labels = HashMap::new();
+    (0..n_vertices).map(|v| {
+        let root = find_root(&mut parents, v);
+        let n_labels = labels.len();
+        *labels.entry(root).or_insert(n_labels)
+    }).collect()
+}
+
+// Computing, for each component, the map from element indexes to indexes in the component.
+// Elements belong to the component of their first vertex.
+fn get_component_element_maps(elements: &[&[usize]], component: &[usize], n_components: usize) -> Vec<Vec<Option<usize>>>
+{
+    let mut maps = vec![vec![None; elements.len()]; n_components];
+    let mut counts = vec![0; n_components];
+    for (i, indexes) in elements.iter().enumerate() {
+        let c = component[indexes[0]];
+        maps[c][i] = Some(counts[c]);
+        counts[c] += 1;
+    }
+    maps
 }
 
 // Extracting reference to vertices of a two vertices element.
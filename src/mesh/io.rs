@@ -0,0 +1,490 @@
+extern crate std;
+
+use super::Mesh3d;
+use super::super::base::*;
+use super::super::elements::*;
+use super::super::views::*;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Data structures.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Errors raised when reading meshes from files.
+#[derive(Debug)]
+pub enum MeshIoError {
+    /// Error raised by the underlying reader.
+    Io(std::io::Error),
+    /// Malformed content.
+    Parse {
+        /// Line number of the malformed content, starting from one.
+        line: usize,
+        /// Description of the problem.
+        message: String,
+    },
+    /// Input ended before the mesh was complete.
+    UnexpectedEnd,
+}
+
+/// Errors raised when reading meshes from OFF files.
+pub type OffError = MeshIoError;
+
+impl std::fmt::Display for MeshIoError {
+    /// Implementing display for mesh input errors.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            MeshIoError::Io(ref e) => write!(formatter, "{}", e),
+            MeshIoError::Parse { line, ref message } => write!(formatter, "line {}: {}", line, message),
+            MeshIoError::UnexpectedEnd => write!(formatter, "unexpected end of input"),
+        }
+    }
+}
+
+impl std::error::Error for MeshIoError {}
+
+impl From<std::io::Error> for MeshIoError {
+    /// Wrapping an error raised by the underlying reader.
+    ///
+    /// * `e` - Input error.
+    ///
+    fn from(e: std::io::Error) -> Self { MeshIoError::Io(e) }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// VTK export.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// VTK cell type of edges.
+pub const VTK_LINE: u8 = 3;
+/// VTK cell type of triangles.
+pub const VTK_TRIANGLE: u8 = 5;
+/// VTK cell type of quadrangles.
+pub const VTK_QUAD: u8 = 9;
+/// VTK cell type of tetrahedra.
+pub const VTK_TETRA: u8 = 10;
+/// VTK cell type of hexahedra.
+pub const VTK_HEXAHEDRON: u8 = 12;
+
+/// Writing a mesh in the ASCII legacy VTK format, as an unstructured grid.
+///
+/// Cells are written in the order edges, triangles, quadrangles, tetrahedra and hexahedra. The
+/// local numbering of elements defined in `elements` matches the VTK one, including for
+/// hexahedra whose bottom face (P0, P1, P2, P3) is followed by the top face (P4, P5, P6, P7),
+/// so that no remapping of the vertices is needed. Tags are not exported.
+///
+/// * `mesh` - Mesh to export.
+/// * `w` - Output writer.
+///
+pub fn write_vtk_legacy(mesh: &Mesh3d, w: &mut impl Write) -> std::io::Result<()>
+{
+    writeln!(w, "# vtk DataFile Version 3.0")?;
+    writeln!(w, "mersh")?;
+    writeln!(w, "ASCII")?;
+    writeln!(w, "DATASET UNSTRUCTURED_GRID")?;
+
+    writeln!(w, "POINTS {} double", mesh.vertices.len())?;
+    for p in &mesh.vertices {
+        writeln!(w, "{} {} {}", p.coords.x, p.coords.y, p.coords.z)?;
+    }
+
+    let cells: Vec<(&[usize], u8)> = mesh.edges.iter().map(|e| (&e.indexes[..], VTK_LINE))
+        .chain(mesh.triangles.iter().map(|e| (&e.indexes[..], VTK_TRIANGLE)))
+        .chain(mesh.quadrangles.iter().map(|e| (&e.indexes[..], VTK_QUAD)))
+        .chain(mesh.tetrahedra.iter().map(|e| (&e.indexes[..], VTK_TETRA)))
+        .chain(mesh.hexahedra.iter().map(|e| (&e.indexes[..], VTK_HEXAHEDRON)))
+        .collect();
+
+    let size: usize = cells.iter().map(|&(indexes, _)| indexes.len() + 1).sum();
+    writeln!(w, "CELLS {} {}", cells.len(), size)?;
+    for &(indexes, _) in &cells {
+        write!(w, "{}", indexes.len())?;
+        for i in indexes { write!(w, " {}", i)?; }
+        writeln!(w)?;
+    }
+
+    writeln!(w, "CELL_TYPES {}", cells.len())?;
+    for &(_, cell_type) in &cells {
+        writeln!(w, "{}", cell_type)?;
+    }
+
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// OBJ export.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Writing the surface of a mesh in the Wavefront OBJ format. Every vertex is written, followed
+/// by the triangles and quadrangles as faces with 1-based vertex indexes. Edges and volume
+/// elements are skipped, as well as tags.
+///
+/// * `mesh` - Mesh to export.
+/// * `w` - Output writer.
+///
+pub fn write_obj(mesh: &Mesh3d, w: &mut impl Write) -> std::io::Result<()>
+{
+    for p in &mesh.vertices {
+        writeln!(w, "v {} {} {}", p.coords.x, p.coords.y, p.coords.z)?;
+    }
+    let faces = mesh.triangles.iter().map(|t| &t.indexes[..])
+        .chain(mesh.quadrangles.iter().map(|q| &q.indexes[..]));
+    for indexes in faces {
+        write!(w, "f")?;
+        for i in indexes { write!(w, " {}", i + 1)?; }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// STL export.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Writing the surface of a mesh in the binary STL format. Triangles are written first, followed
+/// by the quadrangles split into the triangles (P0, P1, P3) and (P1, P2, P3). Each facet stores
+/// the normal of the triangle, set to zero for degenerate triangles, and its vertices as
+/// little-endian single precision floats. Edges and volume elements are skipped.
+///
+/// * `mesh` - Mesh to export.
+/// * `w` - Output writer.
+///
+pub fn write_stl_binary(mesh: &Mesh3d, w: &mut impl Write) -> std::io::Result<()>
+{
+    let mut facets: Vec<TriView3d> = mesh.triangles.iter().map(|t| mesh.get_tri_view(t)).collect();
+    for q in &mesh.quadrangles {
+        let view = mesh.get_quad_view(q);
+        facets.push(view.get_tri_view(TriInQuad::Tri013));
+        facets.push(view.get_tri_view(TriInQuad::Tri123));
+    }
+
+    let mut header = [0u8; 80];
+    header[..5].copy_from_slice(b"mersh");
+    w.write_all(&header)?;
+    w.write_all(&(facets.len() as u32).to_le_bytes())?;
+
+    for facet in &facets {
+        let normal = facet.get_normal();
+        let normal = if normal.coords.sq_norm().is_finite() { normal.coords } else { Coord3d::default() };
+        write_stl_coords(w, &normal)?;
+        for p in &facet.points { write_stl_coords(w, &p.coords)?; }
+        w.write_all(&[0u8; 2])?;
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// PLY export.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Writing the surface of a mesh in the ASCII PLY format. Every vertex is written, followed by
+/// the triangles and quadrangles as faces prefixed by their number of vertices. Edges and volume
+/// elements are skipped, as well as tags.
+///
+/// * `mesh` - Mesh to export.
+/// * `w` - Output writer.
+///
+pub fn write_ply_ascii(mesh: &Mesh3d, w: &mut impl Write) -> std::io::Result<()>
+{
+    writeln!(w, "ply")?;
+    writeln!(w, "format ascii 1.0")?;
+    writeln!(w, "comment mersh")?;
+    writeln!(w, "element vertex {}", mesh.vertices.len())?;
+    writeln!(w, "property double x")?;
+    writeln!(w, "property double y")?;
+    writeln!(w, "property double z")?;
+    writeln!(w, "element face {}", mesh.triangles.len() + mesh.quadrangles.len())?;
+    writeln!(w, "property list uchar int vertex_indices")?;
+    writeln!(w, "end_header")?;
+
+    for p in &mesh.vertices {
+        writeln!(w, "{} {} {}", p.coords.x, p.coords.y, p.coords.z)?;
+    }
+    let faces = mesh.triangles.iter().map(|t| &t.indexes[..])
+        .chain(mesh.quadrangles.iter().map(|q| &q.indexes[..]));
+    for indexes in faces {
+        write!(w, "{}", indexes.len())?;
+        for i in indexes { write!(w, " {}", i)?; }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// OFF export.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Writing the surface of a mesh in the ASCII Object File Format. The `OFF` header is followed by
+/// the numbers of vertices, faces and unique edges, then by every vertex and by the triangles and
+/// quadrangles as faces prefixed by their number of vertices. Edges and volume elements are
+/// skipped, as well as tags.
+///
+/// * `mesh` - Mesh to export.
+/// * `w` - Output writer.
+///
+pub fn write_off(mesh: &Mesh3d, w: &mut impl Write) -> std::io::Result<()>
+{
+    writeln!(w, "OFF")?;
+    let n_faces = mesh.triangles.len() + mesh.quadrangles.len();
+    writeln!(w, "{} {} {}", mesh.vertices.len(), n_faces, mesh.unique_edges().len())?;
+    for p in &mesh.vertices {
+        writeln!(w, "{} {} {}", p.coords.x, p.coords.y, p.coords.z)?;
+    }
+    let faces = mesh.triangles.iter().map(|t| &t.indexes[..])
+        .chain(mesh.quadrangles.iter().map(|q| &q.indexes[..]));
+    for indexes in faces {
+        write!(w, "{}", indexes.len())?;
+        for i in indexes { write!(w, " {}", i)?; }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Gmsh import.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Reading a mesh in the ASCII Gmsh format, version 2.2.
+///
+/// Nodes are numbered densely in their order of appearance, Gmsh node ids being possibly sparse.
+/// Elements of type 1 (edge), 2 (triangle), 3 (quadrangle), 4 (tetrahedron) and 5 (hexahedron)
+/// are read, other element types being skipped. The local numbering of these elements in Gmsh
+/// matches the one defined in `elements`. The physical tag of an element, i.e. its first tag, is
+/// registered in the tag set of its kind, under its name from the `$PhysicalNames` section if
+/// any or under its number otherwise. Other sections are skipped.
+///
+/// * `r` - Input reader.
+///
+pub fn read_gmsh_22(r: &mut impl BufRead) -> Result<Mesh3d, MeshIoError>
+{
+    let mut mesh = Mesh3d::default();
+    let mut lines = r.lines().enumerate();
+    let mut nodes = HashMap::new();
+    let mut names = HashMap::new();
+
+    while let Some((i, line)) = lines.next() {
+        let line = line?;
+        match line.trim() {
+            "$MeshFormat" => {
+                let (i, header) = get_next_line(&mut lines)?;
+                let fields: Vec<&str> = header.split_whitespace().collect();
+                if fields.len() < 2 || !fields[0].starts_with("2.") {
+                    return Err(get_parse_error(i, "only version 2 of the Gmsh format is supported"));
+                }
+                if fields[1] != "0" {
+                    return Err(get_parse_error(i, "only ASCII Gmsh files are supported"));
+                }
+            },
+            "$PhysicalNames" => {
+                for _ in 0..get_count(&mut lines)? {
+                    let (i, entry) = get_next_line(&mut lines)?;
+                    let mut fields = entry.splitn(3, char::is_whitespace);
+                    let _dim: usize = parse_field(fields.next(), i)?;
+                    let tag: usize = parse_field(fields.next(), i)?;
+                    let name = fields.next().map(|n| n.trim().trim_matches('"')).unwrap_or("");
+                    names.insert(tag, String::from(name));
+                }
+            },
+            "$Nodes" => {
+                for _ in 0..get_count(&mut lines)? {
+                    let (i, node) = get_next_line(&mut lines)?;
+                    let mut fields = node.split_whitespace();
+                    let id: usize = parse_field(fields.next(), i)?;
+                    let xyz = [parse_field(fields.next(), i)?, parse_field(fields.next(), i)?, parse_field(fields.next(), i)?];
+                    nodes.insert(id, mesh.insert_vertex(Pnt3d::new(xyz)));
+                }
+            },
+            "$Elements" => {
+                for _ in 0..get_count(&mut lines)? {
+                    let (i, element) = get_next_line(&mut lines)?;
+                    read_gmsh_element(&mut mesh, &nodes, &names, &element, i)?;
+                }
+            },
+            l if l.starts_with('$') && !l.starts_with("$End") => {
+                let end = format!("$End{}", &l[1..]);
+                loop {
+                    let (_, skipped) = get_next_line(&mut lines)?;
+                    if skipped.trim() == end { break; }
+                }
+            },
+            l if l.is_empty() || l.starts_with("$End") => {},
+            _ => { return Err(get_parse_error(i + 1, "unexpected content outside of a section")); }
+        }
+    }
+    Ok(mesh)
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// OFF import.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Reading a mesh in the ASCII Object File Format.
+///
+/// The `OFF` header is followed by the numbers of vertices, faces and edges, the number of edges
+/// being ignored, then by the vertex coordinates and by the faces, each prefixed by its number of
+/// vertices. Faces with more than three vertices are fan-triangulated from their first vertex,
+/// and trailing face colours are ignored. Comments starting with `#` and blank lines are skipped.
+///
+/// * `r` - Input reader.
+///
+pub fn read_off(r: &mut impl BufRead) -> Result<Mesh3d, MeshIoError>
+{
+    let mut mesh = Mesh3d::default();
+    let mut lines = r.lines().enumerate();
+
+    let (i, header) = get_next_off_line(&mut lines)?;
+    let mut fields = header.split_whitespace();
+    if fields.next() != Some("OFF") { return Err(get_parse_error(i, "missing OFF header")); }
+
+    // Counts may follow the header on the same line.
+    let rest: Vec<&str> = fields.collect();
+    let (i, counts) = if rest.is_empty() { get_next_off_line(&mut lines)? } else { (i, rest.join(" ")) };
+    let mut fields = counts.split_whitespace();
+    let n_vertices: usize = parse_field(fields.next(), i)?;
+    let n_faces: usize = parse_field(fields.next(), i)?;
+
+    mesh.reserve_vertices(n_vertices);
+    for _ in 0..n_vertices {
+        let (i, vertex) = get_next_off_line(&mut lines)?;
+        let mut fields = vertex.split_whitespace();
+        let xyz = [parse_field(fields.next(), i)?, parse_field(fields.next(), i)?, parse_field(fields.next(), i)?];
+        mesh.vertices.push(Pnt3d::new(xyz));
+    }
+
+    for _ in 0..n_faces {
+        let (i, face) = get_next_off_line(&mut lines)?;
+        let mut fields = face.split_whitespace();
+        let n: usize = parse_field(fields.next(), i)?;
+        if n < 3 { return Err(get_parse_error(i, "face with less than three vertices")); }
+        let mut indexes = Vec::with_capacity(n);
+        for _ in 0..n {
+            let idx: usize = parse_field(fields.next(), i)?;
+            if idx >= n_vertices { return Err(get_parse_error(i, &format!("unknown vertex {}", idx))); }
+            indexes.push(idx);
+        }
+        for k in 1..n - 1 { mesh.triangles.push(Tri::new([indexes[0], indexes[k], indexes[k + 1]])); }
+    }
+    Ok(mesh)
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Writing coordinates as little-endian single precision floats.
+fn write_stl_coords(w: &mut impl Write, coords: &Coord3d) -> std::io::Result<()>
+{
+    for &x in &[coords.x, coords.y, coords.z] {
+        w.write_all(&(x as f32).to_le_bytes())?;
+    }
+    Ok(())
+}
+
+// Reading the next line of an enumerated line iterator, with its number starting from one.
+fn get_next_line<I>(lines: &mut I) -> Result<(usize, String), MeshIoError>
+    where I: Iterator<Item = (usize, std::io::Result<String>)>
+{
+    match lines.next() {
+        Some((i, line)) => Ok((i + 1, line?)),
+        None => Err(MeshIoError::UnexpectedEnd),
+    }
+}
+
+// Reading the next line of an OFF file holding content, stripped of its comment.
+fn get_next_off_line<I>(lines: &mut I) -> Result<(usize, String), MeshIoError>
+    where I: Iterator<Item = (usize, std::io::Result<String>)>
+{
+    loop {
+        let (i, line) = get_next_line(lines)?;
+        let content = line.split('#').next().unwrap_or("").trim();
+        if !content.is_empty() { return Ok((i, String::from(content))); }
+    }
+}
+
+// Reading the number of entries at the beginning of a section.
+fn get_count<I>(lines: &mut I) -> Result<usize, MeshIoError>
+    where I: Iterator<Item = (usize, std::io::Result<String>)>
+{
+    let (i, line) = get_next_line(lines)?;
+    parse_field(Some(line.trim()), i)
+}
+
+// Parsing a whitespace separated field of a line.
+fn parse_field<T: std::str::FromStr>(field: Option<&str>, line: usize) -> Result<T, MeshIoError>
+{
+    match field {
+        Some(f) => f.parse().map_err(|_| get_parse_error(line, &format!("invalid field '{}'", f))),
+        None => Err(get_parse_error(line, "missing field")),
+    }
+}
+
+// Creating a parse error at a given line.
+fn get_parse_error(line: usize, message: &str) -> MeshIoError
+{
+    MeshIoError::Parse { line, message: String::from(message) }
+}
+
+// Reading an element line of a Gmsh file and adding the element to the mesh.
+fn read_gmsh_element(mesh: &mut Mesh3d, nodes: &HashMap<usize, usize>, names: &HashMap<usize, String>, element: &str, line: usize) -> Result<(), MeshIoError>
+{
+    let fields = element.split_whitespace()
+        .map(|f| parse_field(Some(f), line))
+        .collect::<Result<Vec<usize>, MeshIoError>>()?;
+    if fields.len() < 3 { return Err(get_parse_error(line, "missing field")); }
+    let (element_type, n_tags) = (fields[1], fields[2]);
+    let n_vertices = match element_type {
+        1 => 2,
+        2 => 3,
+        3 | 4 => 4,
+        5 => 8,
+        _ => return Ok(()),
+    };
+    if fields.len() != 3 + n_tags + n_vertices {
+        return Err(get_parse_error(line, "unexpected number of fields for the element type"));
+    }
+
+    let mut indexes = [0; 8];
+    for (idx, id) in indexes.iter_mut().zip(&fields[3 + n_tags..]) {
+        *idx = *nodes.get(id).ok_or_else(|| get_parse_error(line, &format!("unknown node {}", id)))?;
+    }
+    let name = if n_tags > 0 {
+        Some(names.get(&fields[3]).cloned().unwrap_or_else(|| fields[3].to_string()))
+    } else {
+        None
+    };
+
+    match (element_type, name) {
+        (1, Some(n)) => mesh.push_tagged_edge(Edge::new([indexes[0], indexes[1]]), &n),
+        (1, None) => mesh.edges.push(Edge::new([indexes[0], indexes[1]])),
+        (2, Some(n)) => mesh.push_tagged_triangle(Tri::new([indexes[0], indexes[1], indexes[2]]), &n),
+        (2, None) => mesh.triangles.push(Tri::new([indexes[0], indexes[1], indexes[2]])),
+        (3, Some(n)) => mesh.push_tagged_quadrangle(Quad::new([indexes[0], indexes[1], indexes[2], indexes[3]]), &n),
+        (3, None) => mesh.quadrangles.push(Quad::new([indexes[0], indexes[1], indexes[2], indexes[3]])),
+        (4, Some(n)) => mesh.push_tagged_tetrahedron(Tet::new([indexes[0], indexes[1], indexes[2], indexes[3]]), &n),
+        (4, None) => mesh.tetrahedra.push(Tet::new([indexes[0], indexes[1], indexes[2], indexes[3]])),
+        (_, Some(n)) => mesh.push_tagged_hexahedron(Hexa::new(indexes), &n),
+        (_, None) => mesh.hexahedra.push(Hexa::new(indexes)),
+    }
+    Ok(())
+}
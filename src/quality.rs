@@ -0,0 +1,273 @@
+extern crate std;
+
+use super::elements::*;
+use super::mesh::*;
+use super::views::*;
+use std::collections::HashSet;
+use std::vec::*;
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Data structures.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Histogram of values over a range split into bins of equal width.
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    /// Lower bound of the range.
+    pub min: f64,
+    /// Upper bound of the range.
+    pub max: f64,
+    /// Number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+/// Indexes of elements, sorted by element family.
+#[derive(Clone, Default, Debug)]
+pub struct ElementIndexes {
+    /// Indexes of edges.
+    pub edges: Vec<usize>,
+    /// Indexes of triangles.
+    pub triangles: Vec<usize>,
+    /// Indexes of quadrangles.
+    pub quadrangles: Vec<usize>,
+    /// Indexes of tetrahedra.
+    pub tetrahedra: Vec<usize>,
+    /// Indexes of hexahedra.
+    pub hexahedra: Vec<usize>,
+}
+
+/// Report of elements not complying with a target size.
+#[derive(Clone, Default, Debug)]
+pub struct SizeComplianceReport {
+    /// Elements whose longest edge exceeds the target size times the tolerance factor.
+    pub too_coarse: ElementIndexes,
+    /// Elements whose shortest edge is below the target size divided by the tolerance factor.
+    pub too_fine: ElementIndexes,
+}
+
+/// Minimum, maximum and mean of a set of values.
+#[derive(Clone, Debug)]
+pub struct Statistics {
+    /// Minimum value.
+    pub min: f64,
+    /// Maximum value.
+    pub max: f64,
+    /// Mean value.
+    pub mean: f64,
+}
+
+/// Statistics of the quality metrics of the triangles of a mesh.
+#[derive(Clone, Debug)]
+pub struct TriangleQualityReport {
+    /// Statistics of the aspect ratios, see `TriView3d::get_aspect_ratio`.
+    pub aspect_ratio: Statistics,
+    /// Statistics of the minimum angles, see `TriView3d::get_min_angle`.
+    pub min_angle: Statistics,
+    /// Statistics of the edge length ratios, see `TriView3d::get_max_min_edge_ratio`.
+    pub edge_ratio: Statistics,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl Histogram {
+    /// Creating a histogram from values. Values outside of the range are not counted, the upper
+    /// bound being counted in the last bin, and neither are non-finite values, e.g. NaN metrics of
+    /// degenerate elements. When no range is given, the range spans the finite values.
+    ///
+    /// * `values` - Values to count.
+    /// * `n_bins` - Number of bins.
+    /// * `range` - Optional range of the histogram.
+    ///
+    pub fn new(values: &[f64], n_bins: usize, range: Option<(f64, f64)>) -> Self
+    {
+        let (min, max) = range.unwrap_or_else(|| {
+            let finite = values.iter().filter(|v| v.is_finite());
+            let (lo, hi) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+            if lo > hi { (0.0, 0.0) } else { (lo, hi) }
+        });
+
+        let mut histogram = Histogram { min, max, counts: vec![0; n_bins] };
+        if n_bins == 0 { return histogram; }
+        for &v in values {
+            if !v.is_finite() { continue; }
+            if v < min || v > max { continue; }
+            let bin = if max > min { ((v - min) / histogram.bin_width()) as usize } else { 0 };
+            histogram.counts[bin.min(n_bins - 1)] += 1;
+        }
+        histogram
+    }
+
+    /// Computing width of the bins.
+    ///
+    pub fn bin_width(&self) -> f64
+    {
+        (self.max - self.min) / (self.counts.len() as f64)
+    }
+
+    /// Computing total number of counted values.
+    ///
+    pub fn total(&self) -> usize
+    {
+        self.counts.iter().sum()
+    }
+}
+
+impl std::fmt::Display for Histogram {
+    /// Implementing display for histograms as an ASCII bar chart, one line per bin.
+    ///
+    /// * `formatter` - input reference to formatter.
+    ///
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        const BAR_WIDTH: usize = 40;
+        let max_count = self.counts.iter().cloned().max().unwrap_or(0).max(1);
+        for (i, &count) in self.counts.iter().enumerate() {
+            let lo = self.min + (i as f64) * self.bin_width();
+            let bar = "#".repeat(count * BAR_WIDTH / max_count);
+            writeln!(formatter, "[{:.6}, {:.6}] {:>8} {}", lo, lo + self.bin_width(), count, bar)?;
+        }
+        Ok(())
+    }
+}
+
+impl Statistics {
+    /// Computing statistics of values. Returns `None` if no value is given.
+    ///
+    /// * `values` - Values to gather.
+    ///
+    pub fn new(values: &[f64]) -> Option<Self>
+    {
+        if values.is_empty() { return None; }
+        Some(Statistics {
+            min: values.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            mean: values.iter().sum::<f64>() / (values.len() as f64),
+        })
+    }
+}
+
+impl ElementIndexes {
+    /// Checking whether no element index is stored.
+    ///
+    pub fn is_empty(&self) -> bool
+    {
+        self.edges.is_empty() && self.triangles.is_empty() && self.quadrangles.is_empty()
+            && self.tetrahedra.is_empty() && self.hexahedra.is_empty()
+    }
+}
+
+impl SizeComplianceReport {
+    /// Checking whether all elements comply with the target size.
+    ///
+    pub fn is_empty(&self) -> bool
+    {
+        self.too_coarse.is_empty() && self.too_fine.is_empty()
+    }
+}
+
+impl Mesh3d {
+    /// Computing histogram of the lengths of the unique edges of all elements of the mesh.
+    ///
+    /// * `n_bins` - Number of bins.
+    /// * `range` - Optional range of the histogram, spanning all lengths by default.
+    ///
+    pub fn edge_length_histogram(&self, n_bins: usize, range: Option<(f64, f64)>) -> Histogram
+    {
+        let mut visited = HashSet::new();
+        let mut lengths = Vec::new();
+        {
+            let mut add_edges = |indexes: &[usize], local_edges: &[[usize; 2]]| {
+                for e in local_edges {
+                    let (a, b) = (indexes[e[0]], indexes[e[1]]);
+                    if visited.insert(if a < b { (a, b) } else { (b, a) }) {
+                        lengths.push(self.vertices[a].distance_to(&self.vertices[b]));
+                    }
+                }
+            };
+            for e in &self.edges { add_edges(&e.indexes, &Edge::EDGES); }
+            for t in &self.triangles { add_edges(&t.indexes, &Tri::EDGES); }
+            for q in &self.quadrangles { add_edges(&q.indexes, &Quad::EDGES); }
+            for t in &self.tetrahedra { add_edges(&t.indexes, &Tet::EDGES); }
+            for h in &self.hexahedra { add_edges(&h.indexes, &Hexa::EDGES); }
+        }
+        Histogram::new(&lengths, n_bins, range)
+    }
+
+    /// Computing statistics of the quality metrics of the triangles of the mesh. Returns `None`
+    /// if the mesh has no triangle.
+    ///
+    pub fn quality_report(&self) -> Option<TriangleQualityReport>
+    {
+        let views: Vec<TriView3d> = self.triangles.iter().map(|t| self.get_tri_view(t)).collect();
+        let aspect_ratios: Vec<f64> = views.iter().map(|t| t.get_aspect_ratio()).collect();
+        let min_angles: Vec<f64> = views.iter().map(|t| t.get_min_angle()).collect();
+        let edge_ratios: Vec<f64> = views.iter().map(|t| t.get_max_min_edge_ratio()).collect();
+        Some(TriangleQualityReport {
+            aspect_ratio: Statistics::new(&aspect_ratios)?,
+            min_angle: Statistics::new(&min_angles)?,
+            edge_ratio: Statistics::new(&edge_ratios)?,
+        })
+    }
+
+    /// Finding the tetrahedra with a negative signed volume, see `TetView3d::is_inverted`.
+    ///
+    pub fn find_inverted_tets(&self) -> Vec<usize>
+    {
+        self.tetrahedra.iter().enumerate()
+            .filter(|&(_, t)| self.get_tet_view(t).is_inverted())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Checking compliance of the elements with a target size. An element is too coarse when
+    /// its longest edge exceeds `target * tolerance_factor` and too fine when its shortest edge
+    /// is below `target / tolerance_factor`.
+    ///
+    /// * `target` - Target element size.
+    /// * `tolerance_factor` - Tolerance factor on the target size, greater than one.
+    ///
+    pub fn check_size_field(&self, target: f64, tolerance_factor: f64) -> SizeComplianceReport
+    {
+        let bounds = (target / tolerance_factor, target * tolerance_factor);
+        let mut report = SizeComplianceReport::default();
+        let (coarse, fine) = (&mut report.too_coarse, &mut report.too_fine);
+        for (i, e) in self.edges.iter().enumerate() {
+            check_size(self, i, &e.indexes, &Edge::EDGES, bounds, &mut coarse.edges, &mut fine.edges);
+        }
+        for (i, t) in self.triangles.iter().enumerate() {
+            check_size(self, i, &t.indexes, &Tri::EDGES, bounds, &mut coarse.triangles, &mut fine.triangles);
+        }
+        for (i, q) in self.quadrangles.iter().enumerate() {
+            check_size(self, i, &q.indexes, &Quad::EDGES, bounds, &mut coarse.quadrangles, &mut fine.quadrangles);
+        }
+        for (i, t) in self.tetrahedra.iter().enumerate() {
+            check_size(self, i, &t.indexes, &Tet::EDGES, bounds, &mut coarse.tetrahedra, &mut fine.tetrahedra);
+        }
+        for (i, h) in self.hexahedra.iter().enumerate() {
+            check_size(self, i, &h.indexes, &Hexa::EDGES, bounds, &mut coarse.hexahedra, &mut fine.hexahedra);
+        }
+        report
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Registering an element as too fine or too coarse from the extreme lengths of its edges.
+fn check_size(mesh: &Mesh3d, idx: usize, indexes: &[usize], local_edges: &[[usize; 2]], bounds: (f64, f64),
+              coarse: &mut Vec<usize>, fine: &mut Vec<usize>)
+{
+    let (min_length, max_length) = local_edges.iter()
+        .map(|e| mesh.vertices[indexes[e[0]]].distance_to(&mesh.vertices[indexes[e[1]]]))
+        .fold((f64::INFINITY, 0.0f64), |(lo, hi), l| (lo.min(l), hi.max(l)));
+    if max_length > bounds.1 { coarse.push(idx); }
+    if min_length < bounds.0 { fine.push(idx); }
+}
@@ -0,0 +1,491 @@
+extern crate std;
+
+use super::base::*;
+use super::mesh::*;
+use std::vec::*;
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Data structures.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Spatial index over a set of points, sorting them into the cells of a uniform grid covering
+/// their bounding box. The index stores a copy of the points, so it must be rebuilt when the
+/// points move.
+#[derive(Clone, Default, Debug)]
+pub struct PointIndex3d {
+    /// Indexed points.
+    points: Vec<Pnt3d>,
+    /// Lower corner of the grid.
+    origin: Coord3d,
+    /// Edge length of the cubic cells.
+    cell_size: f64,
+    /// Number of cells along each axis.
+    dims: [usize; 3],
+    /// Offsets of the points of each cell in `sorted`, the points of cell c lying from
+    /// `offsets[c]` to `offsets[c + 1]`.
+    offsets: Vec<usize>,
+    /// Point indexes sorted by cell.
+    sorted: Vec<usize>,
+}
+
+/// Spatial index over a set of bounding boxes, e.g. those of mesh elements, registering each
+/// box in the cells of a uniform grid it overlaps. Boxes are listed by increasing index in each
+/// cell.
+#[derive(Clone, Default, Debug)]
+pub struct BoxIndex3d {
+    /// Bounding box of the indexed boxes.
+    bbox: BoundingBox3d,
+    /// Edge length of the cubic cells.
+    cell_size: f64,
+    /// Number of cells along each axis.
+    dims: [usize; 3],
+    /// Offsets of the boxes of each cell in `sorted`, the boxes of cell c lying from
+    /// `offsets[c]` to `offsets[c + 1]`.
+    offsets: Vec<usize>,
+    /// Box indexes sorted by cell.
+    sorted: Vec<usize>,
+}
+
+/// Spatial index over a set of 2d bounding boxes, registering each box in the cells of a uniform
+/// grid it overlaps. Boxes are listed by increasing index in each cell.
+#[derive(Clone, Default, Debug)]
+pub struct BoxIndex2d {
+    /// Bounding box of the indexed boxes.
+    bbox: BoundingBox2d,
+    /// Edge length of the square cells.
+    cell_size: f64,
+    /// Number of cells along each axis.
+    dims: [usize; 2],
+    /// Offsets of the boxes of each cell in `sorted`, the boxes of cell c lying from
+    /// `offsets[c]` to `offsets[c + 1]`.
+    offsets: Vec<usize>,
+    /// Box indexes sorted by cell.
+    sorted: Vec<usize>,
+}
+
+/// Locator of points in the triangles of a 2d mesh, indexing their bounding boxes once so that
+/// each query only checks the triangles close to the point. The locator borrows the mesh, so it
+/// must be rebuilt once the mesh is modified.
+#[derive(Clone)]
+pub struct TriLocator2d<'a> {
+    /// Mesh whose triangles are located.
+    mesh: &'a Mesh2d,
+    /// Index of the bounding boxes of the triangles.
+    index: BoxIndex2d,
+}
+
+/// Locator of points in the tetrahedra of a 3d mesh, indexing their bounding boxes once so that
+/// each query only checks the tetrahedra close to the point. The locator borrows the mesh, so it
+/// must be rebuilt once the mesh is modified.
+#[derive(Clone)]
+pub struct TetLocator3d<'a> {
+    /// Mesh whose tetrahedra are located.
+    mesh: &'a Mesh3d,
+    /// Index of the bounding boxes of the tetrahedra.
+    index: BoxIndex3d,
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Implementation.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl PointIndex3d {
+    /// Creating the index of a set of points. Cells are sized so that they hold about one
+    /// point each on average.
+    ///
+    /// * `points` - Points to index.
+    ///
+    pub fn new(points: &[Pnt3d]) -> PointIndex3d
+    {
+        let bbox = match BoundingBox3d::from_points(points) {
+            Some(bbox) => bbox,
+            None => return PointIndex3d::default(),
+        };
+        let diagonal = bbox.diagonal_length();
+        let cell_size = if diagonal > 0. { diagonal / (points.len() as f64).cbrt() } else { 1.0 };
+        let extent = bbox.max.add_out(-1.0, &bbox.min);
+        let dims = [
+            (extent.x / cell_size) as usize + 1,
+            (extent.y / cell_size) as usize + 1,
+            (extent.z / cell_size) as usize + 1
+        ];
+
+        let mut index = PointIndex3d { points: points.to_vec(), origin: bbox.min, cell_size, dims, offsets: Vec::new(), sorted: Vec::new() };
+        let entries: Vec<(usize, usize)> = points.iter().enumerate().map(|(i, p)| (index.get_cell_number(index.get_cell(p)), i)).collect();
+        let (offsets, sorted) = get_sorted_by_cell(dims[0] * dims[1] * dims[2], &entries);
+        index.offsets = offsets;
+        index.sorted = sorted;
+        index
+    }
+
+    /// Rebuilding the index from a new set of points, e.g. after they have moved.
+    ///
+    /// * `points` - Points to index.
+    ///
+    pub fn rebuild(&mut self, points: &[Pnt3d])
+    {
+        *self = PointIndex3d::new(points);
+    }
+
+    /// Accessing the number of indexed points.
+    ///
+    pub fn len(&self) -> usize
+    {
+        self.points.len()
+    }
+
+    /// Checking whether no point is indexed.
+    ///
+    pub fn is_empty(&self) -> bool
+    {
+        self.points.is_empty()
+    }
+
+    /// Finding the indexed point closest to a probe point, returning its index and distance.
+    /// Among points at the same distance, the lowest index is returned. Returns `None` if no
+    /// point is indexed.
+    ///
+    /// * `p` - Probe point.
+    ///
+    pub fn nearest(&self, p: &Pnt3d) -> Option<(usize, f64)>
+    {
+        self.k_nearest(p, 1).pop()
+    }
+
+    /// Finding the k indexed points closest to a probe point, returned with their distances by
+    /// increasing distance, ties being sorted by index. Fewer points are returned if less than
+    /// k points are indexed.
+    ///
+    /// * `p` - Probe point.
+    /// * `k` - Number of points to find.
+    ///
+    pub fn k_nearest(&self, p: &Pnt3d, k: usize) -> Vec<(usize, f64)>
+    {
+        let mut found: Vec<(usize, f64)> = Vec::with_capacity(k + 1);
+        if k == 0 || self.is_empty() { return found; }
+
+        // Visiting rings of cells at increasing Chebyshev distance from the cell of the probe,
+        // until the points of the next ring cannot be closer than the k-th point found.
+        let center = self.get_cell(p);
+        let max_ring = (0..3).map(|a| center[a].max(self.dims[a] - 1 - center[a])).max().unwrap_or(0);
+        for ring in 0..max_ring + 1 {
+            self.visit_ring(center, ring, |i| {
+                let d = self.points[i].distance_to(p);
+                let rank = found.iter().position(|&(j, e)| d < e || (d == e && i < j)).unwrap_or(found.len());
+                if rank < k {
+                    found.insert(rank, (i, d));
+                    found.truncate(k);
+                }
+            });
+            if found.len() == k && found[k - 1].1 < self.get_ring_distance(center, ring, p) { break; }
+        }
+        found
+    }
+
+    /// Finding the indexed points lying within a distance of a probe point, sorted by index.
+    ///
+    /// * `p` - Probe point.
+    /// * `r` - Search radius.
+    ///
+    pub fn within_radius(&self, p: &Pnt3d, r: f64) -> Vec<usize>
+    {
+        let mut found = Vec::new();
+        if self.is_empty() || r < 0. { return found; }
+        let lower = self.get_cell(&Pnt3d { coords: p.coords.add_out(-1.0, &Coord3d::new([r, r, r])) });
+        let upper = self.get_cell(&Pnt3d { coords: p.coords.add_out(1.0, &Coord3d::new([r, r, r])) });
+        for k in lower[2]..upper[2] + 1 {
+            for j in lower[1]..upper[1] + 1 {
+                for i in lower[0]..upper[0] + 1 {
+                    let c = self.get_cell_number([i, j, k]);
+                    found.extend(self.sorted[self.offsets[c]..self.offsets[c + 1]].iter().filter(|&&v| self.points[v].distance_to(p) <= r));
+                }
+            }
+        }
+        found.sort();
+        found
+    }
+
+    // Computing the cell containing a point, points outside the grid being clamped to its
+    // boundary cells.
+    fn get_cell(&self, p: &Pnt3d) -> [usize; 3]
+    {
+        get_cell(&p.coords, &self.origin, self.cell_size, self.dims)
+    }
+
+    // Computing the number of a cell from its position in the grid.
+    fn get_cell_number(&self, cell: [usize; 3]) -> usize
+    {
+        cell[0] + self.dims[0] * (cell[1] + self.dims[1] * cell[2])
+    }
+
+    // Visiting the points of the cells at a given Chebyshev distance from a cell.
+    fn visit_ring<F>(&self, center: [usize; 3], ring: usize, mut visit: F) where F: FnMut(usize)
+    {
+        let lower: Vec<usize> = (0..3).map(|a| center[a].saturating_sub(ring)).collect();
+        let upper: Vec<usize> = (0..3).map(|a| (center[a] + ring).min(self.dims[a] - 1)).collect();
+        for k in lower[2]..upper[2] + 1 {
+            for j in lower[1]..upper[1] + 1 {
+                for i in lower[0]..upper[0] + 1 {
+                    let cell = [i, j, k];
+                    if (0..3).all(|a| cell[a] + ring != center[a] && cell[a] != center[a] + ring) { continue; }
+                    let c = self.get_cell_number(cell);
+                    for &v in &self.sorted[self.offsets[c]..self.offsets[c + 1]] { visit(v); }
+                }
+            }
+        }
+    }
+
+    // Computing a lower bound of the distance from a point to the cells farther than a given
+    // Chebyshev distance from a cell, i.e. the distance from the point to the outside of the
+    // block of cells within that distance. It is zero if the point lies outside the block.
+    fn get_ring_distance(&self, center: [usize; 3], ring: usize, p: &Pnt3d) -> f64
+    {
+        let local = [
+            p.coords.x - self.origin.x,
+            p.coords.y - self.origin.y,
+            p.coords.z - self.origin.z
+        ];
+        (0..3).map(|a| {
+            let lower = (center[a] as f64 - ring as f64) * self.cell_size;
+            let upper = (center[a] + ring + 1) as f64 * self.cell_size;
+            (local[a] - lower).min(upper - local[a]).max(0.)
+        }).fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl BoxIndex3d {
+    /// Creating the index of a set of boxes. Cells are sized after the mean diagonal of the
+    /// boxes, enlarged if needed so that there are at most about eight cells per box.
+    ///
+    /// * `boxes` - Boxes to index.
+    ///
+    pub fn new(boxes: &[BoundingBox3d]) -> BoxIndex3d
+    {
+        let mut bbox = match boxes.first() {
+            Some(first) => first.clone(),
+            None => return BoxIndex3d::default(),
+        };
+        for b in &boxes[1..] {
+            bbox.extend(&Pnt3d { coords: b.min.clone() });
+            bbox.extend(&Pnt3d { coords: b.max.clone() });
+        }
+        let mean_diagonal = boxes.iter().map(|b| b.diagonal_length()).sum::<f64>() / boxes.len() as f64;
+        let mut cell_size = if mean_diagonal > 0. { mean_diagonal } else if bbox.diagonal_length() > 0. { bbox.diagonal_length() } else { 1.0 };
+        let extent = bbox.max.add_out(-1.0, &bbox.min);
+        let get_dims = |size: f64| [(extent.x / size) as usize + 1, (extent.y / size) as usize + 1, (extent.z / size) as usize + 1];
+        while get_dims(cell_size).iter().product::<usize>() > 8 * boxes.len() { cell_size *= 2.0; }
+        let dims = get_dims(cell_size);
+
+        let mut entries = Vec::new();
+        for (i, b) in boxes.iter().enumerate() {
+            let lower = get_cell(&b.min, &bbox.min, cell_size, dims);
+            let upper = get_cell(&b.max, &bbox.min, cell_size, dims);
+            for k in lower[2]..upper[2] + 1 {
+                for j in lower[1]..upper[1] + 1 {
+                    for l in lower[0]..upper[0] + 1 { entries.push((l + dims[0] * (j + dims[1] * k), i)); }
+                }
+            }
+        }
+        let (offsets, sorted) = get_sorted_by_cell(dims[0] * dims[1] * dims[2], &entries);
+        BoxIndex3d { bbox, cell_size, dims, offsets, sorted }
+    }
+
+    /// Accessing the indexes of the boxes registered in the cell containing a point, by
+    /// increasing index. It includes all the boxes containing the point, and is empty if the
+    /// point lies outside the bounding box of all the boxes.
+    ///
+    /// * `p` - Probe point.
+    ///
+    pub fn candidates(&self, p: &Pnt3d) -> &[usize]
+    {
+        if self.sorted.is_empty() || !self.bbox.contains(p, 0.) { return &[]; }
+        let cell = get_cell(&p.coords, &self.bbox.min, self.cell_size, self.dims);
+        let c = cell[0] + self.dims[0] * (cell[1] + self.dims[1] * cell[2]);
+        &self.sorted[self.offsets[c]..self.offsets[c + 1]]
+    }
+}
+
+impl BoxIndex2d {
+    /// Creating the index of a set of 2d boxes. Cells are sized after the mean diagonal of the
+    /// boxes, enlarged if needed so that there are at most about four cells per box.
+    ///
+    /// * `boxes` - Boxes to index.
+    ///
+    pub fn new(boxes: &[BoundingBox2d]) -> BoxIndex2d
+    {
+        let mut bbox = match boxes.first() {
+            Some(first) => first.clone(),
+            None => return BoxIndex2d::default(),
+        };
+        for b in &boxes[1..] {
+            bbox.extend(&Pnt2d { coords: b.min.clone() });
+            bbox.extend(&Pnt2d { coords: b.max.clone() });
+        }
+        let mean_diagonal = boxes.iter().map(|b| b.diagonal_length()).sum::<f64>() / boxes.len() as f64;
+        let mut cell_size = if mean_diagonal > 0. { mean_diagonal } else if bbox.diagonal_length() > 0. { bbox.diagonal_length() } else { 1.0 };
+        let extent = bbox.max.add_out(-1.0, &bbox.min);
+        let get_dims = |size: f64| [(extent.x / size) as usize + 1, (extent.y / size) as usize + 1];
+        while get_dims(cell_size).iter().product::<usize>() > 4 * boxes.len() { cell_size *= 2.0; }
+        let dims = get_dims(cell_size);
+
+        let mut entries = Vec::new();
+        for (i, b) in boxes.iter().enumerate() {
+            let lower = get_cell_2d(&b.min, &bbox.min, cell_size, dims);
+            let upper = get_cell_2d(&b.max, &bbox.min, cell_size, dims);
+            for j in lower[1]..upper[1] + 1 {
+                for l in lower[0]..upper[0] + 1 { entries.push((l + dims[0] * j, i)); }
+            }
+        }
+        let (offsets, sorted) = get_sorted_by_cell(dims[0] * dims[1], &entries);
+        BoxIndex2d { bbox, cell_size, dims, offsets, sorted }
+    }
+
+    /// Accessing the indexes of the boxes registered in the cell containing a point, by
+    /// increasing index. It includes all the boxes containing the point, and is empty if the
+    /// point lies outside the bounding box of all the boxes.
+    ///
+    /// * `p` - Probe point.
+    ///
+    pub fn candidates(&self, p: &Pnt2d) -> &[usize]
+    {
+        if self.sorted.is_empty() || !self.bbox.contains(p, 0.) { return &[]; }
+        let cell = get_cell_2d(&p.coords, &self.bbox.min, self.cell_size, self.dims);
+        let c = cell[0] + self.dims[0] * cell[1];
+        &self.sorted[self.offsets[c]..self.offsets[c + 1]]
+    }
+}
+
+impl<'a> TriLocator2d<'a> {
+    /// Creating the locator of the triangles of a mesh, indexing their bounding boxes enlarged by
+    /// the geometrical tolerance relative to their diagonal.
+    ///
+    /// * `mesh` - Mesh whose triangles are located.
+    ///
+    pub fn new(mesh: &'a Mesh2d) -> TriLocator2d<'a>
+    {
+        let boxes: Vec<BoundingBox2d> = mesh.triangles.iter().map(|t| {
+            let bbox = mesh.get_tri_view(t).get_bounding_box();
+            let margin = GEOMETRICAL_TOLERANCE * bbox.diagonal_length();
+            let shift = Coord2d::new([margin, margin]);
+            BoundingBox2d { min: bbox.min.add_out(-1.0, &shift), max: bbox.max.add_out(1.0, &shift) }
+        }).collect();
+        TriLocator2d { mesh, index: BoxIndex2d::new(&boxes) }
+    }
+
+    /// Finding a triangle containing a point, i.e. whose barycentric coordinates of the point are
+    /// all at least `-GEOMETRICAL_TOLERANCE`. Only the triangles whose bounding box overlaps the
+    /// grid cell of the point are checked. Points on edges shared by several triangles are
+    /// located in the lowest one. Returns `None` if no triangle contains the point.
+    ///
+    /// * `p` - Point to locate.
+    ///
+    pub fn locate(&self, p: &Pnt2d) -> Option<usize>
+    {
+        let mesh = self.mesh;
+        self.index.candidates(p).iter().cloned()
+            .find(|&t| mesh.get_tri_view(&mesh.triangles[t]).contains_point(p, GEOMETRICAL_TOLERANCE))
+    }
+}
+
+impl<'a> TetLocator3d<'a> {
+    /// Creating the locator of the tetrahedra of a mesh, indexing their bounding boxes enlarged
+    /// by the geometrical tolerance relative to their diagonal.
+    ///
+    /// * `mesh` - Mesh whose tetrahedra are located.
+    ///
+    pub fn new(mesh: &'a Mesh3d) -> TetLocator3d<'a>
+    {
+        let boxes: Vec<BoundingBox3d> = mesh.tetrahedra.iter().map(|t| {
+            let bbox = mesh.get_tet_view(t).get_bounding_box();
+            let margin = GEOMETRICAL_TOLERANCE * bbox.diagonal_length();
+            let shift = Coord3d::new([margin, margin, margin]);
+            BoundingBox3d { min: bbox.min.add_out(-1.0, &shift), max: bbox.max.add_out(1.0, &shift) }
+        }).collect();
+        TetLocator3d { mesh, index: BoxIndex3d::new(&boxes) }
+    }
+
+    /// Finding a tetrahedron containing a point, i.e. whose barycentric coordinates of the point
+    /// are all at least `-GEOMETRICAL_TOLERANCE`. Only the tetrahedra whose bounding box overlaps
+    /// the grid cell of the point are checked. Points on faces shared by several tetrahedra are
+    /// located in the lowest one. Returns `None` if no tetrahedron contains the point.
+    ///
+    /// * `p` - Point to locate.
+    ///
+    pub fn locate(&self, p: &Pnt3d) -> Option<usize>
+    {
+        let mesh = self.mesh;
+        self.index.candidates(p).iter().cloned()
+            .find(|&t| mesh.get_tet_view(&mesh.tetrahedra[t]).contains_point(p, GEOMETRICAL_TOLERANCE))
+    }
+}
+
+impl Mesh3d {
+    /// Building a spatial index over the vertices of the mesh, to be rebuilt when vertices move.
+    ///
+    pub fn build_vertex_index(&self) -> PointIndex3d
+    {
+        PointIndex3d::new(&self.vertices)
+    }
+
+    /// Building a locator of points in the tetrahedra of the mesh, to be reused over queries and
+    /// rebuilt when the mesh is modified.
+    ///
+    pub fn build_tet_locator<'a>(&'a self) -> TetLocator3d<'a>
+    {
+        TetLocator3d::new(self)
+    }
+}
+
+impl Mesh2d {
+    /// Building a locator of points in the triangles of the mesh, to be reused over queries and
+    /// rebuilt when the mesh is modified.
+    ///
+    pub fn build_tri_locator<'a>(&'a self) -> TriLocator2d<'a>
+    {
+        TriLocator2d::new(self)
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Computing the cell of a uniform grid containing coordinates, coordinates outside the grid
+// being clamped to its boundary cells.
+fn get_cell(coords: &Coord3d, origin: &Coord3d, cell_size: f64, dims: [usize; 3]) -> [usize; 3]
+{
+    let local = coords.add_out(-1.0, origin);
+    let clamp = |x: f64, n: usize| ((x / cell_size).floor().max(0.) as usize).min(n - 1);
+    [clamp(local.x, dims[0]), clamp(local.y, dims[1]), clamp(local.z, dims[2])]
+}
+
+// Computing the cell of a uniform 2d grid containing coordinates, coordinates outside the grid
+// being clamped to its boundary cells.
+fn get_cell_2d(coords: &Coord2d, origin: &Coord2d, cell_size: f64, dims: [usize; 2]) -> [usize; 2]
+{
+    let local = coords.add_out(-1.0, origin);
+    let clamp = |x: f64, n: usize| ((x / cell_size).floor().max(0.) as usize).min(n - 1);
+    [clamp(local.x, dims[0]), clamp(local.y, dims[1])]
+}
+
+// Sorting items by cell from (cell, item) entries, returning the offsets of the items of each
+// cell along with the sorted items. Items keep the order of the entries within each cell.
+fn get_sorted_by_cell(n_cells: usize, entries: &[(usize, usize)]) -> (Vec<usize>, Vec<usize>)
+{
+    let mut offsets = vec![0; n_cells + 1];
+    for &(c, _) in entries { offsets[c + 1] += 1; }
+    for c in 0..n_cells { offsets[c + 1] += offsets[c]; }
+    let mut next = offsets.clone();
+    let mut sorted = vec![0; entries.len()];
+    for &(c, i) in entries {
+        sorted[next[c]] = i;
+        next[c] += 1;
+    }
+    (offsets, sorted)
+}
@@ -1,6 +1,7 @@
 extern crate std;
 
-use std::collections::HashMap;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::vec::*;
 use std::string::String;
 
@@ -14,6 +15,8 @@ use std::string::String;
 #[derive(Default, Serialize, Deserialize)]
 pub struct TagSet {
     /// Mapping between tag, represented as String, and set of indexes.
+    /// Serialized with tags sorted by name so that the output is deterministic.
+    #[serde(serialize_with = "serialize_sorted")]
     tag_map: HashMap<String, Vec<usize>>,
 }
 
@@ -47,4 +50,106 @@ impl TagSet {
         }
         self.tag_map.insert(String::from(name), vec![idx]);
     }
-}
\ No newline at end of file
+
+    /// Checking whether an index is associated to a tag name.
+    ///
+    /// * `name` - Name of the tag.
+    /// * `idx` - Index to look for.
+    ///
+    pub fn contains(&self, name: &str, idx: usize) -> bool
+    {
+        self.tag_map.get(name).is_some_and(|indexes| indexes.contains(&idx))
+    }
+
+    /// Listing the names of the tags associated to an index, sorted by name.
+    ///
+    /// * `idx` - Index to look for.
+    ///
+    pub fn tags_of(&self, idx: usize) -> Vec<&String>
+    {
+        let mut names: Vec<&String> = self.tag_map.iter()
+            .filter(|&(_, indexes)| indexes.contains(&idx))
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Removing a tag, returning its indexes if the tag was in the set.
+    ///
+    /// * `name` - Name of the tag to remove.
+    ///
+    pub fn remove(&mut self, name: &str) -> Option<Vec<usize>>
+    {
+        self.tag_map.remove(name)
+    }
+
+    /// Renaming a tag. If the new name is already used, the indexes of the renamed tag missing
+    /// from it are appended. Returns whether the tag to rename was in the set.
+    ///
+    /// * `old` - Current name of the tag.
+    /// * `new` - New name of the tag.
+    ///
+    pub fn rename(&mut self, old: &str, new: &str) -> bool
+    {
+        if old == new { return self.tag_map.contains_key(old); }
+        let indexes = match self.tag_map.remove(old) {
+            Some(indexes) => indexes,
+            None => return false,
+        };
+        let target = self.tag_map.entry(String::from(new)).or_default();
+        for idx in indexes {
+            if !target.contains(&idx) { target.push(idx); }
+        }
+        true
+    }
+
+    /// Merging another tag set, shifting its indexes by an offset and prefixing its tag names.
+    /// Indexes of tags already in the set are accumulated.
+    ///
+    /// * `other` - Tag set to merge.
+    /// * `offset` - Offset added to the indexes of the merged tag set.
+    /// * `prefix` - Prefix added to the tag names of the merged tag set.
+    ///
+    pub fn merge(&mut self, other: &TagSet, offset: usize, prefix: &str)
+    {
+        for (name, indexes) in &other.tag_map {
+            let name = format!("{}{}", prefix, name);
+            for idx in indexes { self.register(&name, idx + offset); }
+        }
+    }
+
+    /// Creating a new tag set by mapping every registered index through an index map.
+    /// Indexes mapped to `None` are dropped, as are tags left without any index.
+    ///
+    /// * `map` - Mapping from old indexes to new ones.
+    ///
+    pub fn remap(&self, map: &[Option<usize>]) -> TagSet
+    {
+        let mut tag_set = TagSet::default();
+        for (name, indexes) in &self.tag_map {
+            let mut visited = HashSet::new();
+            let new_indexes: Vec<usize> = indexes.iter()
+                .filter_map(|&idx| map.get(idx).and_then(|new_idx| *new_idx))
+                .filter(|&new_idx| visited.insert(new_idx))
+                .collect();
+            if !new_indexes.is_empty() {
+                tag_set.tag_map.insert(name.clone(), new_indexes);
+            }
+        }
+        tag_set
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Serializing a tag map with entries sorted by tag name.
+fn serialize_sorted<S: Serializer>(tag_map: &HashMap<String, Vec<usize>>, serializer: S) -> Result<S::Ok, S::Error>
+{
+    let sorted: BTreeMap<&String, &Vec<usize>> = tag_map.iter().collect();
+    sorted.serialize(serializer)
+}
@@ -37,6 +37,30 @@ pub struct HexaView3d<'a> {
     pub points: [&'a Pnt3d; 8]
 }
 
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D data structure.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+/// Structure for defining a 2d edge view.
+pub struct EdgeView2d<'a> {
+    /// Reference to vertices of the edge.
+    pub points: [&'a Pnt2d; 2]
+}
+
+/// Structure for defining a 2d tri view.
+pub struct TriView2d<'a> {
+    /// Reference to vertices of the triangle.
+    pub points: [&'a Pnt2d; 3]
+}
+
+/// Structure for defining a 2d quad view.
+pub struct QuadView2d<'a> {
+    /// Reference to vertices of the quadrangle.
+    pub points: [&'a Pnt2d; 4]
+}
+
 //////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////
 // 3D implementations.
@@ -50,6 +74,78 @@ impl<'a> EdgeView3d<'a> {
     {
         self.points[0].to(self.points[1]).coords.norm()
     }
+
+    /// Computing barycenter of an edge.
+    ///
+    pub fn get_barycenter(&self) -> Pnt3d
+    {
+        barycenter_3d(&self.points)
+    }
+
+    /// Computing axis-aligned bounding box of an edge.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox3d
+    {
+        BoundingBox3d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+
+    /// Projecting a point on an edge, returning the closest point of the edge and its parametric
+    /// coordinate in [0, 1] from the first vertex. The first vertex is returned for edges of
+    /// zero length.
+    ///
+    /// * `p` - Point to project.
+    ///
+    pub fn project_point(&self, p: &Pnt3d) -> (Pnt3d, f64)
+    {
+        let d = self.points[0].to(self.points[1]);
+        let sq_length = d.dot(&d);
+        if sq_length <= GEOMETRICAL_TOLERANCE { return (self.points[0].clone(), 0.0); }
+        let t = (d.dot(&self.points[0].to(p)) / sq_length).clamp(0.0, 1.0);
+        (self.points[0].lerp(self.points[1], t), t)
+    }
+
+    /// Computing the closest points between two edges, the first one lying on the calling edge.
+    /// Parallel edges are handled by picking one of the closest pairs.
+    ///
+    /// * `other` - Second edge.
+    ///
+    pub fn closest_points_between(&self, other: &EdgeView3d) -> (Pnt3d, Pnt3d)
+    {
+        let d1 = self.points[0].to(self.points[1]);
+        let d2 = other.points[0].to(other.points[1]);
+        let r = other.points[0].to(self.points[0]);
+        let (a, e, f) = (d1.dot(&d1), d2.dot(&d2), d2.dot(&r));
+
+        let (s, t) = if a <= GEOMETRICAL_TOLERANCE && e <= GEOMETRICAL_TOLERANCE {
+            (0.0, 0.0)
+        } else if a <= GEOMETRICAL_TOLERANCE {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(&r);
+            if e <= GEOMETRICAL_TOLERANCE {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                let s = if denom > GEOMETRICAL_TOLERANCE * a * e { ((b * f - c * e) / denom).clamp(0.0, 1.0) } else { 0.0 };
+                let t = (b * s + f) / e;
+                if t < 0.0 { ((-c / a).clamp(0.0, 1.0), 0.0) }
+                else if t > 1.0 { (((b - c) / a).clamp(0.0, 1.0), 1.0) }
+                else { (s, t) }
+            }
+        };
+        (self.points[0] + d1 * s, other.points[0] + d2 * t)
+    }
+
+    /// Computing the minimum distance between two edges, which is zero for intersecting edges.
+    ///
+    /// * `other` - Second edge.
+    ///
+    pub fn distance_to_edge(&self, other: &EdgeView3d) -> f64
+    {
+        let (p, q) = self.closest_points_between(other);
+        p.distance_to(&q)
+    }
 }
 
 impl<'a> TriView3d<'a> {
@@ -62,6 +158,168 @@ impl<'a> TriView3d<'a> {
         u.cross_out(&v).normalize_out()
     }
 
+    /// Computing area of a triangle.
+    ///
+    pub fn get_area(&self) -> f64
+    {
+        let u : Vec3d = self.points[0].to(self.points[1]);
+        let v : Vec3d = self.points[0].to(self.points[2]);
+        0.5 * u.cross_out(&v).coords.norm()
+    }
+
+    /// Computing circumcenter of a triangle, i.e. the center of the circle passing through its
+    /// vertices. Returns `None` for nearly collinear vertices, i.e. when the sine of the largest
+    /// angle is below `GEOMETRICAL_TOLERANCE`.
+    ///
+    pub fn get_circumcenter(&self) -> Option<Pnt3d>
+    {
+        let a : Vec3d = self.points[0].to(self.points[1]);
+        let b : Vec3d = self.points[0].to(self.points[2]);
+        let n = a.cross_out(&b);
+        if is_nearly_collinear(2.0 * self.get_area(), self.get_edge_lengths()) { return None; }
+        let w = (&b * a.dot(&a) - &a * b.dot(&b)).cross_out(&n) / (2.0 * n.dot(&n));
+        Some(self.points[0] + w)
+    }
+
+    /// Computing circumradius of a triangle. Returns `None` for nearly collinear vertices, as
+    /// `get_circumcenter`.
+    ///
+    pub fn get_circumradius(&self) -> Option<f64>
+    {
+        self.get_circumcenter().map(|c| c.distance_to(self.points[0]))
+    }
+
+    /// Computing incenter of a triangle, i.e. the center of its inscribed circle, as the mean of
+    /// its vertices weighted by the lengths of the opposite edges.
+    ///
+    pub fn get_incenter(&self) -> Pnt3d
+    {
+        let l = self.get_edge_lengths();
+        let perimeter = l[0] + l[1] + l[2];
+        if perimeter <= 0.0 { return self.points[0].clone(); }
+        let mut coords = self.points[0].coords.amplify_out(l[1]);
+        coords.add_in(l[2], &self.points[1].coords).add_in(l[0], &self.points[2].coords);
+        Pnt3d { coords: coords.amplify_out(1.0 / perimeter) }
+    }
+
+    /// Computing inradius of a triangle, i.e. the ratio of its area over its half perimeter,
+    /// which is zero for triangles of zero area.
+    ///
+    pub fn get_inradius(&self) -> f64
+    {
+        let l = self.get_edge_lengths();
+        let perimeter = l[0] + l[1] + l[2];
+        if perimeter <= 0.0 { 0.0 } else { 2.0 * self.get_area() / perimeter }
+    }
+
+    /// Computing aspect ratio of a triangle, defined as its circumradius over twice its
+    /// inradius, i.e. abc / (8 (s - a) (s - b) (s - c)) with a, b, c the edge lengths and s the
+    /// half perimeter. It equals one for equilateral triangles and grows as triangles
+    /// degenerate, being infinite for triangles of zero area.
+    ///
+    pub fn get_aspect_ratio(&self) -> f64
+    {
+        get_aspect_ratio(self.get_edge_lengths(), self.get_area())
+    }
+
+    /// Computing smallest angle in radians of a triangle, which is pi / 3 for equilateral
+    /// triangles and zero for triangles of zero area.
+    ///
+    pub fn get_min_angle(&self) -> f64
+    {
+        (0..3).map(|k| {
+            let u : Vec3d = self.points[k].to(self.points[(k + 1) % 3]);
+            let v : Vec3d = self.points[k].to(self.points[(k + 2) % 3]);
+            u.angle_to(&v)
+        }).fold(f64::INFINITY, f64::min)
+    }
+
+    /// Computing ratio of the longest edge length over the shortest one, which is one for
+    /// equilateral triangles and infinite for triangles with an edge of zero length.
+    ///
+    pub fn get_max_min_edge_ratio(&self) -> f64
+    {
+        get_max_min_edge_ratio(self.get_edge_lengths())
+    }
+
+    // Computing lengths of the edges of a triangle.
+    fn get_edge_lengths(&self) -> [f64; 3]
+    {
+        [
+            self.points[0].distance_to(self.points[1]),
+            self.points[1].distance_to(self.points[2]),
+            self.points[2].distance_to(self.points[0])
+        ]
+    }
+
+    /// Projecting a point orthogonally on the plane supporting a triangle.
+    ///
+    /// * `p` - Point to project.
+    ///
+    pub fn project_point_to_plane(&self, p: &Pnt3d) -> Pnt3d
+    {
+        let n = self.get_normal();
+        let v = self.points[0].to(p);
+        Pnt3d { coords: p.coords.add_out(-v.coords.dot(&n.coords), &n.coords) }
+    }
+
+    /// Computing barycentric coordinates of the orthogonal projection of a point on the plane
+    /// supporting a triangle. The coordinates sum to one and are all in [0, 1] when the
+    /// projection lies inside the triangle.
+    ///
+    /// * `p` - Point whose barycentric coordinates are computed.
+    ///
+    pub fn get_barycentric_coords(&self, p: &Pnt3d) -> [f64; 3]
+    {
+        let q = self.project_point_to_plane(p);
+        let u : Vec3d = self.points[0].to(self.points[1]);
+        let v : Vec3d = self.points[0].to(self.points[2]);
+        let w : Vec3d = self.points[0].to(&q);
+        get_barycentric_coords([u.dot(&u), u.dot(&v), v.dot(&v)], [w.dot(&u), w.dot(&v)])
+    }
+
+    /// Checking whether the orthogonal projection of a point on the plane supporting a triangle
+    /// lies inside the triangle, i.e. whether all its barycentric coordinates are at least `-tol`.
+    ///
+    /// * `p` - Point to locate.
+    /// * `tol` - Tolerance on barycentric coordinates.
+    ///
+    pub fn contains_point(&self, p: &Pnt3d, tol: f64) -> bool
+    {
+        self.get_barycentric_coords(p).iter().all(|&l| l >= -tol)
+    }
+
+    /// Computing the closest point to a given point on a triangle, i.e. the orthogonal
+    /// projection on its supporting plane if it lies inside the triangle, or the closest point on
+    /// its edges otherwise.
+    ///
+    /// * `p` - Point to project.
+    ///
+    pub fn closest_point(&self, p: &Pnt3d) -> Pnt3d
+    {
+        if self.contains_point(p, 0.0) { return self.project_point_to_plane(p); }
+        let mut closest = self.get_edge_view(EdgeInTri::Edge01).project_point(p).0;
+        for &e in &[EdgeInTri::Edge12, EdgeInTri::Edge20] {
+            let q = self.get_edge_view(e).project_point(p).0;
+            if q.distance_to(p) < closest.distance_to(p) { closest = q; }
+        }
+        closest
+    }
+
+    /// Computing barycenter of a triangle.
+    ///
+    pub fn get_barycenter(&self) -> Pnt3d
+    {
+        barycenter_3d(&self.points)
+    }
+
+    /// Computing axis-aligned bounding box of a triangle.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox3d
+    {
+        BoundingBox3d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+
     /// Accessing view to a local edge in a triangle
     ///
     /// * `edge_name` - Local name of the edge in the triangle.
@@ -74,4 +332,410 @@ impl<'a> TriView3d<'a> {
             EdgeInTri::Edge20 => EdgeView3d{ points: [self.points[2], self.points[0]] },
         }
     }
-}
\ No newline at end of file
+}
+
+impl<'a> QuadView3d<'a> {
+    /// Computing area of a quadrangle as the sum of the areas of its triangles (P0, P1, P3) and
+    /// (P1, P2, P3), as named in `TriInQuad`. For non-planar quadrangles, this is the area of
+    /// the two triangles split along the diagonal joining P1 and P3.
+    ///
+    pub fn get_area(&self) -> f64
+    {
+        self.get_tri_view(TriInQuad::Tri013).get_area() + self.get_tri_view(TriInQuad::Tri123).get_area()
+    }
+
+    /// Accessing view to a local triangle in a quadrangle.
+    ///
+    /// * `tri_name` - Local name of the triangle in the quadrangle.
+    ///
+    pub fn get_tri_view<'b>(&'b self, tri_name: TriInQuad) -> TriView3d<'a>
+    {
+        match tri_name {
+            TriInQuad::Tri013 => TriView3d{ points: [self.points[0], self.points[1], self.points[3]] },
+            TriInQuad::Tri123 => TriView3d{ points: [self.points[1], self.points[2], self.points[3]] },
+        }
+    }
+
+    /// Computing barycenter of a quadrangle.
+    ///
+    pub fn get_barycenter(&self) -> Pnt3d
+    {
+        barycenter_3d(&self.points)
+    }
+
+    /// Computing axis-aligned bounding box of a quadrangle.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox3d
+    {
+        BoundingBox3d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+}
+
+impl<'a> TetView3d<'a> {
+    /// Computing signed volume of a tetrahedron, i.e. the scalar triple product of its edges
+    /// from the first vertex divided by six. The volume is positive when the fourth vertex lies
+    /// on the side the normal of the first three vertices points to, and negative for inverted
+    /// elements.
+    ///
+    pub fn get_signed_volume(&self) -> f64
+    {
+        let u : Vec3d = self.points[0].to(self.points[1]);
+        let v : Vec3d = self.points[0].to(self.points[2]);
+        let w : Vec3d = self.points[0].to(self.points[3]);
+        u.dot(&v.cross_out(&w)) / 6.0
+    }
+
+    /// Computing volume of a tetrahedron.
+    ///
+    pub fn get_volume(&self) -> f64
+    {
+        self.get_signed_volume().abs()
+    }
+
+    /// Computing mean ratio quality of a tetrahedron, defined as 12 (3 |V|)^(2/3) / S with V its
+    /// volume and S the sum of its squared edge lengths. It equals one for regular tetrahedra and
+    /// tends to zero as tetrahedra degenerate. Tetrahedra with all vertices merged get zero.
+    ///
+    pub fn get_quality(&self) -> f64
+    {
+        let sq_lengths: f64 = Tet::EDGES.iter()
+            .map(|e| self.points[e[0]].to(self.points[e[1]]).coords.sq_norm())
+            .sum();
+        if sq_lengths <= 0.0 { return 0.0; }
+        12.0 * (3.0 * self.get_volume()).powf(2.0 / 3.0) / sq_lengths
+    }
+
+    /// Checking whether a tetrahedron is inverted, i.e. whether its signed volume is negative
+    /// with respect to the local numbering order documented in `Tet`.
+    ///
+    pub fn is_inverted(&self) -> bool
+    {
+        self.get_signed_volume() < 0.0
+    }
+
+    /// Computing barycentric coordinates of a point with respect to a tetrahedron, i.e. the
+    /// signed volumes of the tetrahedra obtained by replacing each vertex by the point, over the
+    /// signed volume of the tetrahedron. The coordinates sum to one and are all in [0, 1] when
+    /// the point lies inside the tetrahedron. They are not finite for tetrahedra of zero volume.
+    ///
+    /// * `p` - Point whose barycentric coordinates are computed.
+    ///
+    pub fn get_barycentric_coords(&self, p: &Pnt3d) -> [f64; 4]
+    {
+        let volume = self.get_signed_volume();
+        let mut coords = [0.; 4];
+        for (k, l) in coords.iter_mut().enumerate() {
+            let mut points = self.points;
+            points[k] = p;
+            *l = TetView3d { points }.get_signed_volume() / volume;
+        }
+        coords
+    }
+
+    /// Checking whether a point lies inside a tetrahedron, i.e. whether all its barycentric
+    /// coordinates are at least `-tol`. Tetrahedra of zero volume contain no point.
+    ///
+    /// * `p` - Point to locate.
+    /// * `tol` - Tolerance on barycentric coordinates.
+    ///
+    pub fn contains_point(&self, p: &Pnt3d, tol: f64) -> bool
+    {
+        self.get_barycentric_coords(p).iter().all(|&l| l >= -tol)
+    }
+
+    /// Computing barycenter of a tetrahedron.
+    ///
+    pub fn get_barycenter(&self) -> Pnt3d
+    {
+        barycenter_3d(&self.points)
+    }
+
+    /// Computing axis-aligned bounding box of a tetrahedron.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox3d
+    {
+        BoundingBox3d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+}
+
+impl<'a> HexaView3d<'a> {
+    /// Computing signed volume of a hexahedron as the sum of the signed volumes of the six
+    /// tetrahedra of `Hexa::TETRAHEDRA`, sharing the diagonal joining P0 and P6. For non-planar
+    /// faces, the volume of this decomposition is returned. The volume is negative for inverted
+    /// elements.
+    ///
+    pub fn get_signed_volume(&self) -> f64
+    {
+        let p = &self.points;
+        Hexa::TETRAHEDRA.iter()
+            .map(|t| TetView3d { points: [p[t[0]], p[t[1]], p[t[2]], p[t[3]]] }.get_signed_volume())
+            .sum()
+    }
+
+    /// Computing volume of a hexahedron.
+    ///
+    pub fn get_volume(&self) -> f64
+    {
+        self.get_signed_volume().abs()
+    }
+
+    /// Computing barycenter of a hexahedron.
+    ///
+    pub fn get_barycenter(&self) -> Pnt3d
+    {
+        barycenter_3d(&self.points)
+    }
+
+    /// Computing axis-aligned bounding box of a hexahedron.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox3d
+    {
+        BoundingBox3d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// 2D implementations.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+impl<'a> EdgeView2d<'a> {
+    /// Computing length of a 2d view to an edge in a mesh.
+    ///
+    pub fn get_length(&self) -> f64
+    {
+        self.points[0].distance_to(self.points[1])
+    }
+
+    /// Computing barycenter of an edge.
+    ///
+    pub fn get_barycenter(&self) -> Pnt2d
+    {
+        barycenter_2d(&self.points)
+    }
+}
+
+impl<'a> TriView2d<'a> {
+    /// Computing area of a triangle.
+    ///
+    pub fn get_area(&self) -> f64
+    {
+        let u : Vec2d = self.points[0].to(self.points[1]);
+        let v : Vec2d = self.points[0].to(self.points[2]);
+        0.5 * u.cross_out(&v).abs()
+    }
+
+    /// Computing barycenter of a triangle.
+    ///
+    pub fn get_barycenter(&self) -> Pnt2d
+    {
+        barycenter_2d(&self.points)
+    }
+
+    /// Computing circumcenter of a triangle, i.e. the center of the circle passing through its
+    /// vertices. Returns `None` for nearly collinear vertices, as for 3d triangles.
+    ///
+    pub fn get_circumcenter(&self) -> Option<Pnt2d>
+    {
+        let a : Vec2d = self.points[0].to(self.points[1]);
+        let b : Vec2d = self.points[0].to(self.points[2]);
+        let d = 2.0 * a.cross_out(&b);
+        if is_nearly_collinear(d.abs(), self.get_edge_lengths()) { return None; }
+        let (sq_a, sq_b) = (a.dot(&a), b.dot(&b));
+        let w = Vec2d::new([
+            (b.coords.y * sq_a - a.coords.y * sq_b) / d,
+            (a.coords.x * sq_b - b.coords.x * sq_a) / d
+        ]);
+        Some(self.points[0] + w)
+    }
+
+    /// Computing circumradius of a triangle. Returns `None` for nearly collinear vertices, as
+    /// `get_circumcenter`.
+    ///
+    pub fn get_circumradius(&self) -> Option<f64>
+    {
+        self.get_circumcenter().map(|c| c.distance_to(self.points[0]))
+    }
+
+    /// Computing incenter of a triangle, i.e. the center of its inscribed circle, as the mean of
+    /// its vertices weighted by the lengths of the opposite edges.
+    ///
+    pub fn get_incenter(&self) -> Pnt2d
+    {
+        let l = self.get_edge_lengths();
+        let perimeter = l[0] + l[1] + l[2];
+        if perimeter <= 0.0 { return self.points[0].clone(); }
+        let mut coords = self.points[0].coords.amplify_out(l[1]);
+        coords.add_in(l[2], &self.points[1].coords).add_in(l[0], &self.points[2].coords);
+        Pnt2d { coords: coords.amplify_out(1.0 / perimeter) }
+    }
+
+    /// Computing inradius of a triangle, i.e. the ratio of its area over its half perimeter,
+    /// which is zero for triangles of zero area.
+    ///
+    pub fn get_inradius(&self) -> f64
+    {
+        let l = self.get_edge_lengths();
+        let perimeter = l[0] + l[1] + l[2];
+        if perimeter <= 0.0 { 0.0 } else { 2.0 * self.get_area() / perimeter }
+    }
+
+    /// Computing aspect ratio of a triangle, defined as its circumradius over twice its
+    /// inradius, as for 3d triangles.
+    ///
+    pub fn get_aspect_ratio(&self) -> f64
+    {
+        get_aspect_ratio(self.get_edge_lengths(), self.get_area())
+    }
+
+    /// Computing smallest angle in radians of a triangle, which is pi / 3 for equilateral
+    /// triangles and zero for triangles of zero area.
+    ///
+    pub fn get_min_angle(&self) -> f64
+    {
+        (0..3).map(|k| {
+            let u : Vec2d = self.points[k].to(self.points[(k + 1) % 3]);
+            let v : Vec2d = self.points[k].to(self.points[(k + 2) % 3]);
+            u.cross_out(&v).abs().atan2(u.dot(&v))
+        }).fold(f64::INFINITY, f64::min)
+    }
+
+    /// Computing ratio of the longest edge length over the shortest one, which is one for
+    /// equilateral triangles and infinite for triangles with an edge of zero length.
+    ///
+    pub fn get_max_min_edge_ratio(&self) -> f64
+    {
+        get_max_min_edge_ratio(self.get_edge_lengths())
+    }
+
+    // Computing lengths of the edges of a triangle.
+    fn get_edge_lengths(&self) -> [f64; 3]
+    {
+        [
+            self.points[0].distance_to(self.points[1]),
+            self.points[1].distance_to(self.points[2]),
+            self.points[2].distance_to(self.points[0])
+        ]
+    }
+
+    /// Computing barycentric coordinates of a point with respect to a triangle. The coordinates
+    /// sum to one and are all in [0, 1] when the point lies inside the triangle.
+    ///
+    /// * `p` - Point whose barycentric coordinates are computed.
+    ///
+    pub fn get_barycentric_coords(&self, p: &Pnt2d) -> [f64; 3]
+    {
+        let u : Vec2d = self.points[0].to(self.points[1]);
+        let v : Vec2d = self.points[0].to(self.points[2]);
+        let w : Vec2d = self.points[0].to(p);
+        get_barycentric_coords([u.dot(&u), u.dot(&v), v.dot(&v)], [w.dot(&u), w.dot(&v)])
+    }
+
+    /// Checking whether a point lies inside a triangle, i.e. whether all its barycentric
+    /// coordinates are at least `-tol`.
+    ///
+    /// * `p` - Point to locate.
+    /// * `tol` - Tolerance on barycentric coordinates.
+    ///
+    pub fn contains_point(&self, p: &Pnt2d, tol: f64) -> bool
+    {
+        self.get_barycentric_coords(p).iter().all(|&l| l >= -tol)
+    }
+
+    /// Checking whether a point lies inside a triangle from the orientations of the point with
+    /// respect to its edges, which must not have opposite signs. Points on an edge or on a
+    /// vertex are inside, whatever the orientation of the triangle.
+    ///
+    /// * `p` - Point to locate.
+    ///
+    pub fn contains(&self, p: &Pnt2d) -> bool
+    {
+        let d0 = orient2d(self.points[0], self.points[1], p);
+        let d1 = orient2d(self.points[1], self.points[2], p);
+        let d2 = orient2d(self.points[2], self.points[0], p);
+        let has_negative = d0 < 0. || d1 < 0. || d2 < 0.;
+        let has_positive = d0 > 0. || d1 > 0. || d2 > 0.;
+        !(has_negative && has_positive)
+    }
+
+    /// Computing axis-aligned bounding box of a triangle.
+    ///
+    pub fn get_bounding_box(&self) -> BoundingBox2d
+    {
+        BoundingBox2d::from_points(self.points.iter().cloned()).unwrap_or_default()
+    }
+}
+
+impl<'a> QuadView2d<'a> {
+    /// Computing area of a quadrangle as the sum of the areas of its triangles (P0, P1, P3) and
+    /// (P1, P2, P3), as named in `TriInQuad`.
+    ///
+    pub fn get_area(&self) -> f64
+    {
+        self.get_tri_view(TriInQuad::Tri013).get_area() + self.get_tri_view(TriInQuad::Tri123).get_area()
+    }
+
+    /// Accessing view to a local triangle in a quadrangle.
+    ///
+    /// * `tri_name` - Local name of the triangle in the quadrangle.
+    ///
+    pub fn get_tri_view<'b>(&'b self, tri_name: TriInQuad) -> TriView2d<'a>
+    {
+        match tri_name {
+            TriInQuad::Tri013 => TriView2d{ points: [self.points[0], self.points[1], self.points[3]] },
+            TriInQuad::Tri123 => TriView2d{ points: [self.points[1], self.points[2], self.points[3]] },
+        }
+    }
+
+    /// Computing barycenter of a quadrangle.
+    ///
+    pub fn get_barycenter(&self) -> Pnt2d
+    {
+        barycenter_2d(&self.points)
+    }
+}
+
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+// Private implementation methods.
+//////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////
+
+// Computing barycentric coordinates of a point in a triangle from the Gram matrix of the edges
+// (u, v) leaving the first vertex, and the dot products of these edges with the vector w from
+// the first vertex to the point.
+fn get_barycentric_coords(gram: [f64; 3], rhs: [f64; 2]) -> [f64; 3]
+{
+    let denom = gram[0] * gram[2] - gram[1] * gram[1];
+    let l1 = (gram[2] * rhs[0] - gram[1] * rhs[1]) / denom;
+    let l2 = (gram[0] * rhs[1] - gram[1] * rhs[0]) / denom;
+    [1.0 - l1 - l2, l1, l2]
+}
+
+// Checking whether a triangle is nearly collinear from twice its area and its edge lengths,
+// comparing the sine of its largest angle with the geometrical tolerance.
+fn is_nearly_collinear(double_area: f64, lengths: [f64; 3]) -> bool
+{
+    let mut sorted = lengths;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    double_area <= GEOMETRICAL_TOLERANCE * sorted[1] * sorted[2]
+}
+
+// Computing aspect ratio of a triangle from its edge lengths and area.
+fn get_aspect_ratio(lengths: [f64; 3], area: f64) -> f64
+{
+    if area <= 0.0 { return f64::INFINITY; }
+    let half_perimeter = 0.5 * (lengths[0] + lengths[1] + lengths[2]);
+    let circumradius = lengths[0] * lengths[1] * lengths[2] / (4.0 * area);
+    let inradius = area / half_perimeter;
+    circumradius / (2.0 * inradius)
+}
+
+// Computing ratio of the longest edge length over the shortest one.
+fn get_max_min_edge_ratio(lengths: [f64; 3]) -> f64
+{
+    let min = lengths.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = lengths.iter().cloned().fold(0.0, f64::max);
+    if min <= 0.0 { f64::INFINITY } else { max / min }
+}
@@ -1,4 +1,5 @@
 extern crate mersh;
+extern crate serde_json;
 
 mod coord3d {
 
@@ -11,6 +12,13 @@ mod coord3d {
         assert_eq!("(1.000000, 0.000000, 3.000000)", formatted_coords);
     }
 
+    #[test]
+    fn fmt_precision() {
+        let coords = Coord3d::new([1.0, 0.25, 3.0]);
+        assert_eq!("(1.000, 0.250, 3.000)", format!("{:.3}", coords));
+        assert_eq!("(1, 0, 3)", format!("{:.0}", coords));
+    }
+
     #[test]
     fn new() {
         let coords = Coord3d::new([1.0, 0.0, 3.0]);
@@ -96,6 +104,13 @@ mod coord3d {
         assert!(c0.equals(&c2, GEOMETRICAL_TOLERANCE));
     }
 
+    #[test]
+    fn dot() {
+        let c0 = Coord3d::new([1.0, 2.0, 3.0]);
+        let c1 = Coord3d::new([4.0, -5.0, 6.0]);
+        assert!((c0.dot(&c1) - 12.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
     #[test]
     fn sq_norm() {
         let c0 = Coord3d::new([2.0, 2.0, 1.0]);
@@ -107,12 +122,45 @@ mod coord3d {
         let c0 = Coord3d::new([2.0, 2.0, 23.1]);
         assert!((c0.norm() - c0.sq_norm().sqrt()) < GEOMETRICAL_TOLERANCE);
     }
+
+    #[test]
+    fn clamp() {
+        let min = Coord3d::new([0.0, 0.0, 0.0]);
+        let max = Coord3d::new([1.0, 2.0, 3.0]);
+        let c = Coord3d::new([-1.0, 1.5, 4.0]).clamp(&min, &max);
+        assert!(c.equals(&Coord3d::new([0.0, 1.5, 3.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn mul_div() {
+        let c = Coord3d::new([1.0, -2.0, 4.0]);
+        assert!((&c * 2.0).equals(&Coord3d::new([2.0, -4.0, 8.0]), GEOMETRICAL_TOLERANCE));
+        assert!((c / 4.0).equals(&Coord3d::new([0.25, -0.5, 1.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn operators() {
+        let a = Coord3d::new([1.0, -2.0, 4.0]);
+        let b = Coord3d::new([0.5, 3.0, -1.0]);
+        assert!((&a + &b).equals(&a.add_out(1.0, &b), GEOMETRICAL_TOLERANCE));
+        assert!((&a - &b).equals(&a.add_out(-1.0, &b), GEOMETRICAL_TOLERANCE));
+        assert!((-&a).equals(&a.amplify_out(-1.0), GEOMETRICAL_TOLERANCE));
+        assert!((2.0 * &a).equals(&a.amplify_out(2.0), GEOMETRICAL_TOLERANCE));
+        assert!((a.clone() + b.clone() - a * 2.0).equals(&Coord3d::new([-0.5, 5.0, -5.0]), GEOMETRICAL_TOLERANCE));
+    }
 }
 
 mod pnt3d {
 
     use mersh::base::*;
 
+    #[test]
+    fn fmt() {
+        let p = Pnt3d::new([1.0, 0.5, -2.0]);
+        assert_eq!("(1.000000, 0.500000, -2.000000)", format!("{}", p));
+        assert_eq!("(1.00, 0.50, -2.00)", format!("{:.2}", p));
+    }
+
     #[test]
     fn new() {
         let p = Pnt3d::new([1.0, 0.0, 2.0]);
@@ -156,18 +204,154 @@ mod pnt3d {
         assert!(v0.coords.equals(&Coord3d { x: 1.0, y: 0.0, z: 0.0 }, GEOMETRICAL_TOLERANCE));
         assert!(v1.coords.equals(&Coord3d { x: -1.0, y: 0.0, z: 0.0 }, GEOMETRICAL_TOLERANCE));
     }
+
+    #[test]
+    fn clamp_to_box_0() {
+        let bbox = Aabb3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let p = Pnt3d::new([0.2, 0.5, 0.7]).clamp_to_box(&bbox);
+        assert!(p.coords.equals(&Coord3d::new([0.2, 0.5, 0.7]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn clamp_to_box_1() {
+        let bbox = Aabb3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let p = Pnt3d::new([1.0, 0.0, 0.5]).clamp_to_box(&bbox);
+        assert!(p.coords.equals(&Coord3d::new([1.0, 0.0, 0.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn clamp_to_box_2() {
+        let bbox = Aabb3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let p = Pnt3d::new([2.0, -3.0, 0.5]).clamp_to_box(&bbox);
+        assert!(p.coords.equals(&Coord3d::new([1.0, 0.0, 0.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn rotate_around_0() {
+        let axis = Vec3d::new([0.0, 0.0, 1.0]).normalize_out();
+        let p = Pnt3d::new([1.0, 0.0, 0.0]).rotate_around(&Pnt3d::new([0.0, 0.0, 0.0]), &axis, 0.5 * std::f64::consts::PI);
+        assert!(p.coords.equals(&Coord3d::new([0.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn rotate_around_1() {
+        let axis = Vec3d::new([0.0, 0.0, 1.0]).normalize_out();
+        let origin = Pnt3d::new([1.0, 1.0, 5.0]);
+        let p = Pnt3d::new([2.0, 1.0, 3.0]).rotate_around(&origin, &axis, std::f64::consts::PI);
+        assert!(p.coords.equals(&Coord3d::new([0.0, 1.0, 3.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn rotate_around_2() {
+        // A third of a turn around the main diagonal permutes the axes.
+        let axis = Vec3d::new([1.0, 1.0, 1.0]).normalize_out();
+        let origin = Pnt3d::new([0.0, 0.0, 0.0]);
+        let p = Pnt3d::new([1.0, 0.0, 0.0]).rotate_around(&origin, &axis, 2.0 * std::f64::consts::PI / 3.0);
+        assert!(p.coords.equals(&Coord3d::new([0.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn add_sub() {
+        let p = Pnt3d::new([1.0, 2.0, 3.0]);
+        let q = Pnt3d::new([3.0, 2.0, 1.0]);
+        let v = Vec3d::new([1.0, 1.0, 1.0]);
+        assert!((&p + &v).coords.equals(&Coord3d::new([2.0, 3.0, 4.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&p - &v).coords.equals(&Coord3d::new([0.0, 1.0, 2.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&q - &p).coords.equals(&Coord3d::new([2.0, 0.0, -2.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&q - &p).coords.equals(&p.to(&q).coords, GEOMETRICAL_TOLERANCE));
+        assert!((&p + &v).coords.equals(&p.translate_by(&v).coords, GEOMETRICAL_TOLERANCE));
+        assert!((p.clone() + v).coords.equals(&Coord3d::new([2.0, 3.0, 4.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn chained_operators() {
+        let p = Pnt3d::new([1.0, 2.0, 3.0]);
+        let q = Pnt3d::new([3.0, 6.0, -1.0]);
+        let m = &p + (&q - &p) * 0.5;
+        assert!(m.coords.equals(&Coord3d::new([2.0, 4.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        let r = q - (-(&m - &p) + Vec3d::new([1.0, 1.0, 1.0])) / 2.0;
+        assert!(r.coords.equals(&Coord3d::new([3.0, 6.5, -2.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn lerp_0() {
+        let p = Pnt3d::new([0.1, -0.7, 1e5]);
+        let q = Pnt3d::new([1e-3, 3.3, -2.9]);
+        assert!(p.lerp(&q, 0.0).coords.equals(&p.coords, GEOMETRICAL_TOLERANCE));
+        assert!(p.lerp(&q, 1.0).coords.equals(&q.coords, GEOMETRICAL_TOLERANCE));
+        assert_eq!(p.lerp(&q, 1.0).coords.z, q.coords.z);
+    }
+
+    #[test]
+    fn lerp_1() {
+        let p = Pnt3d::new([0.0, 1.0, 2.0]);
+        let q = Pnt3d::new([4.0, 1.0, -2.0]);
+        assert!(p.lerp(&q, 0.25).coords.equals(&Coord3d::new([1.0, 1.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(p.lerp(&q, 1.5).coords.equals(&Coord3d::new([6.0, 1.0, -4.0]), GEOMETRICAL_TOLERANCE));
+        assert!(p.lerp(&q, -1.0).coords.equals(&Coord3d::new([-4.0, 1.0, 6.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn midpoint_with() {
+        let p = Pnt3d::new([0.0, 1.0, 2.0]);
+        let q = Pnt3d::new([4.0, 1.0, -2.0]);
+        assert!(p.midpoint_with(&q).coords.equals(&Coord3d::new([2.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+        assert!(q.midpoint_with(&p).coords.equals(&Coord3d::new([2.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn barycenter_3d_0() {
+        let p = Pnt3d::new([0.0, 0.0, 0.0]);
+        let q = Pnt3d::new([3.0, 0.0, 3.0]);
+        let r = Pnt3d::new([0.0, 6.0, 0.0]);
+        assert!(barycenter_3d(&[&p, &q, &r]).coords.equals(&Coord3d::new([1.0, 2.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(barycenter_3d(&[&q]).coords.equals(&q.coords, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    #[should_panic]
+    fn barycenter_3d_1() {
+        barycenter_3d(&[]);
+    }
 }
 
 mod vec3d {
 
     use mersh::base::*;
 
+    #[test]
+    fn fmt() {
+        let v = Vec3d::new([0.125, 0.0, 4.0]);
+        assert_eq!("(0.125000, 0.000000, 4.000000)", format!("{}", v));
+        assert_eq!("(0.1, 0.0, 4.0)", format!("{:.1}", v));
+    }
+
     #[test]
     fn new() {
         let v = Vec3d::new([1.0, 0.0, 0.0]);
         assert!(v.coords.equals(&Coord3d::new([1.0, 0.0, 0.0]), GEOMETRICAL_TOLERANCE));
     }
 
+    #[test]
+    fn dot_0() {
+
+        let u = Vec3d::new([1.0, 0.0, 0.0]);
+        let v = Vec3d::new([1.0, 2.0, 0.0]);
+
+        assert!((u.dot(&v) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((v.dot(&u) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn dot_1() {
+
+        let u = Vec3d::new([1.0, 0.0, 0.0]);
+        let v = Vec3d::new([0.0, 2.0, 3.0]);
+
+        assert!(u.dot(&v).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(u.dot(&u.cross_out(&v)).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
     #[test]
     fn cross_out_0() {
 
@@ -188,6 +372,36 @@ mod vec3d {
         assert!(w.coords.equals(&Coord3d::new([0.0, 0.0, -2.0]), GEOMETRICAL_TOLERANCE));
     }
 
+    #[test]
+    fn angle_to_0() {
+
+        let u = Vec3d::new([2.0, 0.0, 0.0]);
+        let v = Vec3d::new([0.0, 0.0, 3.0]);
+
+        assert!((u.angle_to(&v) - 0.5 * std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn angle_to_1() {
+
+        let u = Vec3d::new([1.0, 1.0, 0.0]);
+        let v = Vec3d::new([2.0, 2.0, 0.0]);
+        let w = Vec3d::new([-1.0, -1.0, 0.0]);
+
+        assert!(u.angle_to(&v).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((u.angle_to(&w) - std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn angle_to_2() {
+
+        let u = Vec3d::new([1.0, 0.0, 0.0]);
+        let v = Vec3d::new([1.0, 1e-7, 0.0]);
+
+        assert!((u.angle_to(&v) - 1e-7).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(u.angle_to(&Vec3d::default()).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
     #[test]
     fn normalize_out() {
 
@@ -203,4 +417,685 @@ mod vec3d {
         assert!(d.coords.equals(&Coord3d::new([0.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
         assert!(l.coords.equals(&Coord3d::new([0.0,-1.0, 0.0]), GEOMETRICAL_TOLERANCE));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn operators() {
+        let u = Vec3d::new([1.0, 2.0, 3.0]);
+        let v = Vec3d::new([0.5, -1.0, 2.0]);
+        assert!((&u + &v).coords.equals(&Coord3d::new([1.5, 1.0, 5.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&u - &v).coords.equals(&Coord3d::new([0.5, 3.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!((-&u).coords.equals(&Coord3d::new([-1.0, -2.0, -3.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&u * 2.0).coords.equals(&Coord3d::new([2.0, 4.0, 6.0]), GEOMETRICAL_TOLERANCE));
+        assert!((2.0 * &u).coords.equals(&u.coords.amplify_out(2.0), GEOMETRICAL_TOLERANCE));
+        assert!((&u + &v).coords.equals(&u.coords.add_out(1.0, &v.coords), GEOMETRICAL_TOLERANCE));
+        assert!((u / 2.0).coords.equals(&Coord3d::new([0.5, 1.0, 1.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn lerp() {
+        let u = Vec3d::new([1.0, 2.0, -2.0]);
+        let v = Vec3d::new([3.0, 0.0, 4.0]);
+        assert!(u.lerp(&v, 0.5).coords.equals(&Coord3d::new([2.0, 1.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(u.lerp(&v, 0.0).coords.equals(&u.coords, GEOMETRICAL_TOLERANCE));
+        assert!(u.lerp(&v, 1.0).coords.equals(&v.coords, GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod dir3d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn fmt() {
+        let d = Vec3d::new([0.0, 3.0, 4.0]).normalize_out();
+        assert_eq!("(0.000000, 0.600000, 0.800000)", format!("{}", d));
+        assert_eq!("(0.0000, 0.6000, 0.8000)", format!("{:.4}", d));
+    }
+
+    #[test]
+    fn dot() {
+
+        let d0 = Vec3d::new([1.0, 1.0, 0.0]).normalize_out();
+        let d1 = Vec3d::new([0.0, 1.0, 0.0]).normalize_out();
+
+        assert!((d0.dot(&d1) - 0.5f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn angle_to_0() {
+
+        let d0 = Vec3d::new([1.0, 0.0, 0.0]).normalize_out();
+        let d1 = Vec3d::new([0.0, 1.0, 0.0]).normalize_out();
+
+        assert!((d0.angle_to(&d1) - 0.5 * std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn angle_to_1() {
+
+        let d0 = Vec3d::new([1.0, 2.0, 3.0]).normalize_out();
+        let d1 = Vec3d::new([-1.0, -2.0, -3.0]).normalize_out();
+
+        assert!(d0.angle_to(&d0).abs() < 1e-6);
+        assert!((d0.angle_to(&d1) - std::f64::consts::PI).abs() < 1e-6);
+        assert!(!d0.angle_to(&d0).is_nan());
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        let normals = [
+            Vec3d::new([0.0, 0.0, 1.0]), Vec3d::new([0.0, 0.0, -1.0]), Vec3d::new([1.0, 0.0, 0.0]),
+            Vec3d::new([0.0, -1.0, 0.0]), Vec3d::new([1.0, 2.0, -3.0]), Vec3d::new([1e-9, -1e-9, -1.0])
+        ];
+        for v in &normals {
+            let n = v.normalize_out();
+            let (t, b) = n.orthonormal_basis();
+            assert!((t.coords.norm() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+            assert!((b.coords.norm() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+            assert!(t.dot(&b).abs() < GEOMETRICAL_TOLERANCE);
+            assert!(t.dot(&n).abs() < GEOMETRICAL_TOLERANCE);
+            assert!(b.dot(&n).abs() < GEOMETRICAL_TOLERANCE);
+
+            let tv = Vec3d { coords: t.coords.clone() };
+            let bv = Vec3d { coords: b.coords.clone() };
+            assert!(tv.cross_out(&bv).coords.equals(&n.coords, GEOMETRICAL_TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn slerp_0() {
+        let d0 = Vec3d::new([1.0, 0.0, 0.0]).normalize_out();
+        let d1 = Vec3d::new([0.0, 1.0, 0.0]).normalize_out();
+        let h = 0.5f64.sqrt();
+        assert!(d0.slerp(&d1, 0.5).coords.equals(&Coord3d::new([h, h, 0.0]), GEOMETRICAL_TOLERANCE));
+        let d = d0.slerp(&d1, 1.0 / 3.0);
+        assert!((d.angle_to(&d0) - std::f64::consts::PI / 6.0).abs() < 1e-9);
+        assert!((d.coords.norm() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn slerp_1() {
+        let d0 = Vec3d::new([0.0, 0.0, 1.0]).normalize_out();
+        assert!(d0.slerp(&d0, 0.3).coords.equals(&d0.coords, GEOMETRICAL_TOLERANCE));
+
+        // Opposite directions are joined along a great circle.
+        let d1 = Vec3d::new([0.0, 0.0, -1.0]).normalize_out();
+        let d = d0.slerp(&d1, 0.5);
+        assert!((d.coords.norm() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(d.dot(&d0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(d0.slerp(&d1, 1.0).coords.equals(&d1.coords, GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod bounding_box3d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn from_points() {
+        assert!(BoundingBox3d::from_points(&[]).is_none());
+
+        let points = vec![Pnt3d::new([1.0, -1.0, 2.0]), Pnt3d::new([0.0, 3.0, 1.0]), Pnt3d::new([2.0, 0.0, 1.5])];
+        match BoundingBox3d::from_points(&points) {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord3d::new([0.0, -1.0, 1.0]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord3d::new([2.0, 3.0, 2.0]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        // Iterator over point references.
+        match BoundingBox3d::from_points(points.iter().skip(1)) {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord3d::new([0.0, 0.0, 1.0]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord3d::new([2.0, 3.0, 1.5]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn extend() {
+        let mut bbox = BoundingBox3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        bbox.extend(&Pnt3d::new([0.5, 2.0, -1.0])).extend(&Pnt3d::new([0.5, 0.5, 0.5]));
+        assert!(bbox.min.equals(&Coord3d::new([0.0, 0.0, -1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(bbox.max.equals(&Coord3d::new([1.0, 2.0, 1.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn contains() {
+        let bbox = BoundingBox3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        assert!(bbox.contains(&Pnt3d::new([0.5, 0.5, 0.5]), 0.0));
+        assert!(bbox.contains(&Pnt3d::new([1.0, 0.0, 0.5]), 0.0));
+        assert!(!bbox.contains(&Pnt3d::new([1.1, 0.5, 0.5]), 0.0));
+        assert!(bbox.contains(&Pnt3d::new([1.1, 0.5, 0.5]), 0.2));
+    }
+
+    #[test]
+    fn intersects() {
+        let bbox = BoundingBox3d::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        assert!(bbox.intersects(&BoundingBox3d::new([0.5, 0.5, 0.5], [2.0, 2.0, 2.0])));
+        assert!(bbox.intersects(&BoundingBox3d::new([1.0, 0.0, 0.0], [2.0, 1.0, 1.0])));
+        assert!(!bbox.intersects(&BoundingBox3d::new([0.0, 0.0, 1.5], [1.0, 1.0, 2.0])));
+    }
+
+    #[test]
+    fn center_and_diagonal_length() {
+        let bbox = BoundingBox3d::new([0.0, -1.0, 2.0], [2.0, 1.0, 3.0]);
+        assert!(bbox.center().coords.equals(&Coord3d::new([1.0, 0.0, 2.5]), GEOMETRICAL_TOLERANCE));
+        assert!((bbox.diagonal_length() - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn serialize() {
+        let bbox = BoundingBox3d::new([0.0, -1.0, 2.0], [2.0, 1.0, 3.0]);
+        let json = serde_json::to_string(&bbox).unwrap();
+        let read: BoundingBox3d = serde_json::from_str(&json).unwrap();
+        assert!(read.min.equals(&bbox.min, GEOMETRICAL_TOLERANCE));
+        assert!(read.max.equals(&bbox.max, GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod mat3 {
+
+    use mersh::base::*;
+
+    #[test]
+    fn mlt_and_apply_to() {
+        let a = Mat3::new([[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 3.0]]);
+        let b = Mat3::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        let c = a.mlt(&b).apply_to(&Coord3d::new([1.0, 1.0, 1.0]));
+        let d = a.apply_to(&b.apply_to(&Coord3d::new([1.0, 1.0, 1.0])));
+        assert!(c.equals(&d, GEOMETRICAL_TOLERANCE));
+        assert!(c.equals(&Coord3d::new([1.0, 1.0, 3.0]), GEOMETRICAL_TOLERANCE));
+        assert!((a.determinant() - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn from_axis_angle() {
+        let axis = Vec3d::new([1.0, 2.0, -1.0]).normalize_out();
+        let m = Mat3::from_axis_angle(&axis, 0.7);
+        let p = Pnt3d::new([0.3, -1.0, 2.0]);
+        let q = p.rotate_around(&Pnt3d::new([0.0, 0.0, 0.0]), &axis, 0.7);
+        assert!(m.apply_to(&p.coords).equals(&q.coords, GEOMETRICAL_TOLERANCE));
+        assert!((m.determinant() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Mat3::new([[2.0, 1.0, 0.0], [0.0, 1.0, -1.0], [1.0, 0.0, 3.0]]);
+        match m.inverse() {
+            Some(inv) => {
+                let id = inv.mlt(&m);
+                for (row, expected) in id.rows.iter().zip(Mat3::identity().rows.iter()) {
+                    for (x, y) in row.iter().zip(expected) { assert!((x - y).abs() < GEOMETRICAL_TOLERANCE); }
+                }
+            },
+            None => { panic!(); }
+        }
+        assert!(Mat3::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]).inverse().is_none());
+        assert!(Mat3::default().inverse().is_none());
+    }
+}
+
+mod transform3d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn apply_and_compose() {
+        let rotation = Transform3d::new(
+            Mat3::from_axis_angle(&Vec3d::new([0.0, 0.0, 1.0]).normalize_out(), 0.5 * std::f64::consts::PI),
+            Vec3d::default());
+        let translation = Transform3d::new(Mat3::identity(), Vec3d::new([1.0, 0.0, 2.0]));
+
+        let p = Pnt3d::new([1.0, 0.0, 0.0]);
+        let q = rotation.compose(&translation).apply_to_point(&p);
+        assert!(q.coords.equals(&Coord3d::new([0.0, 2.0, 2.0]), GEOMETRICAL_TOLERANCE));
+        let q = translation.compose(&rotation).apply_to_point(&p);
+        assert!(q.coords.equals(&Coord3d::new([1.0, 1.0, 2.0]), GEOMETRICAL_TOLERANCE));
+
+        let v = translation.compose(&rotation).apply_to_vector(&Vec3d::new([1.0, 0.0, 0.0]));
+        assert!(v.coords.equals(&Coord3d::new([0.0, 1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+        assert!(Transform3d::identity().apply_to_point(&p).coords.equals(&p.coords, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn inverse() {
+        let t = Transform3d::new(
+            Mat3::from_axis_angle(&Vec3d::new([1.0, -1.0, 2.0]).normalize_out(), 1.3).mlt(&Mat3::new([[2.0, 0.0, 0.0], [0.0, 0.5, 0.0], [0.0, 0.0, 3.0]])),
+            Vec3d::new([0.5, -2.0, 1.0]));
+        match t.inverse() {
+            Some(inv) => {
+                let id = inv.compose(&t);
+                for (row, expected) in id.linear.rows.iter().zip(Mat3::identity().rows.iter()) {
+                    for (x, y) in row.iter().zip(expected) { assert!((x - y).abs() < GEOMETRICAL_TOLERANCE); }
+                }
+                assert!(id.translation.coords.equals(&Coord3d::default(), GEOMETRICAL_TOLERANCE));
+                let p = Pnt3d::new([0.3, 4.0, -1.0]);
+                assert!(inv.apply_to_point(&t.apply_to_point(&p)).coords.equals(&p.coords, GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        let singular = Transform3d::new(Mat3::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]), Vec3d::default());
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn serialize() {
+        let t = Transform3d::new(Mat3::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]), Vec3d::new([1.0, 0.0, -1.0]));
+        let json = serde_json::to_string(&t).unwrap();
+        let read: Transform3d = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.linear.rows, t.linear.rows);
+        assert!(read.translation.coords.equals(&t.translation.coords, GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod coord2d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn fmt() {
+        let coords = Coord2d::new([1.0, 3.0]);
+        let formatted_coords = format!("{}", coords);
+        assert_eq!("(1.000000, 3.000000)", formatted_coords);
+    }
+
+    #[test]
+    fn new() {
+        let coords = Coord2d::new([1.0, 3.0]);
+        assert!((coords.x - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((coords.y - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn amplify_in() {
+        let mut c = Coord2d::new([1.0, 2.0]);
+        c.amplify_in(3.0);
+        assert!((c.x - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((c.y - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn amplify_out() {
+        let c0 = Coord2d::new([1.0, 2.0]);
+        let c1 = c0.amplify_out(3.0);
+        assert!((c1.x - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((c1.y - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn add_in() {
+        let mut c = Coord2d::new([1.0, 2.0]);
+        c.add_in(1.0, &Coord2d::new([10.0, 10.0]));
+        assert!((c.x - 11.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((c.y - 12.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn add_out() {
+        let c0 = Coord2d::new([1.0, 2.0]);
+        let c1 = c0.add_out(1.0, &Coord2d::new([10.0, 10.0]));
+        assert!((c1.x - 11.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((c1.y - 12.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn mlt_add_in() {
+
+        let a = 4.;
+        let mut c0 = Coord2d::new([1.0, 5.0]);
+        let b = -2.;
+        let c1 = Coord2d::new([10.0, 10.0]);
+
+        c0.mlt_add_in(a, b, &c1);
+
+        assert!((c0.x + 16.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(c0.y.abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn mlt_add_out() {
+
+        let a = 4.;
+        let c0 = Coord2d::new([1.0, 5.0]);
+        let b = -2.;
+        let c1 = Coord2d::new([10.0, 10.0]);
+
+        let c2 = c0.mlt_add_out(a, b, &c1);
+
+        assert!((c2.x + 16.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(c2.y.abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn equals() {
+
+        let c0 = Coord2d::new([1.0, 3.4]);
+        let c1 = Coord2d::new([6.0, -13.0]);
+        let c2 = c0.clone();
+
+        assert!(!c0.equals(&c1, GEOMETRICAL_TOLERANCE));
+        assert!(c0.equals(&c2, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn dot() {
+        let c0 = Coord2d::new([1.0, 2.0]);
+        let c1 = Coord2d::new([4.0, -5.0]);
+        assert!((c0.dot(&c1) + 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn sq_norm() {
+        let c0 = Coord2d::new([3.0, 4.0]);
+        assert!((c0.sq_norm() - 25.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn norm() {
+        let c0 = Coord2d::new([3.0, 4.0]);
+        assert!((c0.norm() - 5.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn mul_div() {
+        let c = Coord2d::new([1.0, -2.0]);
+        assert!((&c * 2.0).equals(&Coord2d::new([2.0, -4.0]), GEOMETRICAL_TOLERANCE));
+        assert!((c / 4.0).equals(&Coord2d::new([0.25, -0.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn operators() {
+        let a = Coord2d::new([1.0, -2.0]);
+        let b = Coord2d::new([0.5, 3.0]);
+        assert!((&a + &b).equals(&a.add_out(1.0, &b), GEOMETRICAL_TOLERANCE));
+        assert!((&a - &b).equals(&a.add_out(-1.0, &b), GEOMETRICAL_TOLERANCE));
+        assert!((-a.clone()).equals(&a.amplify_out(-1.0), GEOMETRICAL_TOLERANCE));
+        assert!((2.0 * a).equals(&Coord2d::new([2.0, -4.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn clamp() {
+        let min = Coord2d::new([0.0, 0.0]);
+        let max = Coord2d::new([1.0, 2.0]);
+        let c = Coord2d::new([-1.0, 2.5]).clamp(&min, &max);
+        assert!(c.equals(&Coord2d::new([0.0, 2.0]), GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod pnt2d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn new() {
+        let p = Pnt2d::new([1.0, 2.0]);
+        assert!(p.coords.equals(&Coord2d::new([1.0, 2.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn distance_to_0() {
+        let p = Pnt2d::default();
+        let q = Pnt2d::new([0.0, 1.0]);
+        assert!((p.distance_to(&q) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn distance_to_1() {
+        let p = Pnt2d::new([2.4, -1.9]);
+        assert!(p.distance_to(&p) < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn translate_by() {
+
+        let p = Pnt2d::new([1.45, 3.0]);
+        let v = Vec2d::new([-0.1, 4.09]);
+        let q = p.translate_by(&v);
+
+        assert!((q.coords.x - p.coords.x - v.coords.x).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((q.coords.y - p.coords.y - v.coords.y).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn to() {
+
+        let p = Pnt2d::default();
+        let q = Pnt2d::new([1.0, 0.0]);
+
+        let v0 = p.to(&q);
+        let v1 = q.to(&p);
+
+        assert!(v0.coords.equals(&Coord2d { x: 1.0, y: 0.0 }, GEOMETRICAL_TOLERANCE));
+        assert!(v1.coords.equals(&Coord2d { x: -1.0, y: 0.0 }, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn chained_operators() {
+        let p = Pnt2d::new([1.0, 2.0]);
+        let q = Pnt2d::new([3.0, 6.0]);
+        let m = &p + (&q - &p) * 0.5;
+        assert!(m.coords.equals(&Coord2d::new([2.0, 4.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&m - Vec2d::new([1.0, 1.0])).coords.equals(&Coord2d::new([1.0, 3.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&q - &p).coords.equals(&p.to(&q).coords, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn lerp_0() {
+        let p = Pnt2d::new([0.1, 1e5]);
+        let q = Pnt2d::new([3.3, -2.9]);
+        assert!(p.lerp(&q, 0.0).coords.equals(&p.coords, GEOMETRICAL_TOLERANCE));
+        assert!(p.lerp(&q, 1.0).coords.equals(&q.coords, GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn lerp_1() {
+        let p = Pnt2d::new([0.0, 2.0]);
+        let q = Pnt2d::new([4.0, -2.0]);
+        assert!(p.lerp(&q, 0.25).coords.equals(&Coord2d::new([1.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(p.lerp(&q, 2.0).coords.equals(&Coord2d::new([8.0, -6.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn midpoint_with() {
+        let p = Pnt2d::new([0.0, 2.0]);
+        let q = Pnt2d::new([4.0, -2.0]);
+        assert!(p.midpoint_with(&q).coords.equals(&Coord2d::new([2.0, 0.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn barycenter_2d_0() {
+        let p = Pnt2d::new([0.0, 0.0]);
+        let q = Pnt2d::new([4.0, 2.0]);
+        assert!(barycenter_2d(&[&p, &q]).coords.equals(&Coord2d::new([2.0, 1.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    #[should_panic]
+    fn barycenter_2d_1() {
+        barycenter_2d(&[]);
+    }
+
+    #[test]
+    fn orient2d_0() {
+        let (a, b) = (Pnt2d::new([0.0, 0.0]), Pnt2d::new([2.0, 0.0]));
+        assert!((orient2d(&a, &b, &Pnt2d::new([1.0, 1.0])) - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((orient2d(&a, &b, &Pnt2d::new([1.0, -1.0])) + 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(orient2d(&a, &b, &Pnt2d::new([3.0, 0.0])), 0.0);
+    }
+
+    #[test]
+    fn fmt() {
+        let p = Pnt2d::new([1.0, -2.0]);
+        assert_eq!("(1.000000, -2.000000)", format!("{}", p));
+        assert_eq!("(1.00, -2.00)", format!("{:.2}", p));
+    }
+
+    #[test]
+    fn clamp_to_box() {
+        let bbox = BoundingBox2d::new([0.0, 0.0], [1.0, 1.0]);
+        let p = Pnt2d::new([2.0, 0.5]).clamp_to_box(&bbox);
+        assert!(p.coords.equals(&Coord2d::new([1.0, 0.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn rotate_around() {
+        let p = Pnt2d::new([2.0, 1.0]).rotate_around(&Pnt2d::new([1.0, 1.0]), 0.5 * std::f64::consts::PI);
+        assert!(p.coords.equals(&Coord2d::new([1.0, 2.0]), GEOMETRICAL_TOLERANCE));
+        let q = p.rotate_around(&Pnt2d::new([0.0, 0.0]), -0.3).rotate_around(&Pnt2d::new([0.0, 0.0]), 0.3);
+        assert!(q.coords.equals(&p.coords, GEOMETRICAL_TOLERANCE));
+    }
+}
+
+mod vec2d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn new() {
+        let v = Vec2d::new([1.0, 0.0]);
+        assert!(v.coords.equals(&Coord2d::new([1.0, 0.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn dot() {
+
+        let u = Vec2d::new([1.0, 0.0]);
+        let v = Vec2d::new([0.0, 2.0]);
+
+        assert!(u.dot(&v).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((v.dot(&v) - 4.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn cross_out_0() {
+
+        let u = Vec2d::new([1.0, 0.0]);
+        let v = Vec2d::new([1.0, 2.0]);
+
+        assert!((u.cross_out(&v) - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn cross_out_1() {
+
+        let u = Vec2d::new([1.0, 0.0]);
+        let v = Vec2d::new([1.0, 2.0]);
+
+        assert!((v.cross_out(&u) + 2.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn normalize_out() {
+
+        let p = Pnt2d::default();
+        let q = Pnt2d::new([0.0, 3.0]);
+
+        let d = p.to(&q).normalize_out();
+        let l = q.to(&p).normalize_out();
+
+        assert!(d.coords.equals(&Coord2d::new([0.0, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(l.coords.equals(&Coord2d::new([0.0,-1.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn operators() {
+        let u = Vec2d::new([1.0, 2.0]);
+        let v = Vec2d::new([0.5, -1.0]);
+        assert!((&u + &v).coords.equals(&Coord2d::new([1.5, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!((&u - &v).coords.equals(&Coord2d::new([0.5, 3.0]), GEOMETRICAL_TOLERANCE));
+        assert!((-&u).coords.equals(&Coord2d::new([-1.0, -2.0]), GEOMETRICAL_TOLERANCE));
+        assert!((0.5 * &u).coords.equals(&u.coords.amplify_out(0.5), GEOMETRICAL_TOLERANCE));
+        assert!((u / 2.0 * 4.0).coords.equals(&Coord2d::new([2.0, 4.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn fmt() {
+        let v = Vec2d::new([0.125, 4.0]);
+        assert_eq!("(0.125000, 4.000000)", format!("{}", v));
+        assert_eq!("(0.1, 4.0)", format!("{:.1}", v));
+    }
+
+    #[test]
+    fn angle_to() {
+        let u = Vec2d::new([2.0, 0.0]);
+        assert!((u.angle_to(&Vec2d::new([0.0, -3.0])) - 0.5 * std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((u.angle_to(&Vec2d::new([-1.0, 0.0])) - std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(u.angle_to(&Vec2d::new([4.0, 0.0])).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(u.angle_to(&Vec2d::new([0.0, 0.0])), 0.0);
+    }
+}
+
+mod dir2d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn fmt() {
+        let d = Vec2d::new([3.0, 4.0]).normalize_out();
+        assert_eq!("(0.600000, 0.800000)", format!("{}", d));
+    }
+
+    #[test]
+    fn dot() {
+        let d0 = Vec2d::new([1.0, 1.0]).normalize_out();
+        let d1 = Vec2d::new([0.0, 1.0]).normalize_out();
+        assert!((d0.dot(&d1) - 0.5f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn angle_to() {
+        let d0 = Vec2d::new([1.0, 0.0]).normalize_out();
+        let d1 = Vec2d::new([-1.0, 1.0]).normalize_out();
+        assert!((d0.angle_to(&d1) - 0.75 * std::f64::consts::PI).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(d0.angle_to(&d0), 0.0);
+    }
+}
+
+mod bounding_box2d {
+
+    use mersh::base::*;
+
+    #[test]
+    fn from_points() {
+        assert!(BoundingBox2d::from_points(&[]).is_none());
+
+        let points = vec![Pnt2d::new([1.0, -1.0]), Pnt2d::new([0.0, 3.0]), Pnt2d::new([2.0, 0.0])];
+        match BoundingBox2d::from_points(&points) {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([0.0, -1.0]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord2d::new([2.0, 3.0]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        // Iterator over point references.
+        let refs = [&points[0], &points[2]];
+        match BoundingBox2d::from_points(refs.iter().cloned()) {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([1.0, -1.0]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord2d::new([2.0, 0.0]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn contains_and_intersects() {
+        let bbox = BoundingBox2d::new([0.0, 0.0], [1.0, 1.0]);
+        assert!(bbox.contains(&Pnt2d::new([0.5, 1.0]), 0.0));
+        assert!(!bbox.contains(&Pnt2d::new([0.5, 1.1]), 0.0));
+        assert!(bbox.intersects(&BoundingBox2d::new([0.5, 0.5], [2.0, 2.0])));
+        assert!(!bbox.intersects(&BoundingBox2d::new([1.5, 0.0], [2.0, 1.0])));
+    }
+
+    #[test]
+    fn center_and_diagonal_length() {
+        let bbox = BoundingBox2d::new([0.0, -1.0], [3.0, 3.0]);
+        assert!(bbox.center().coords.equals(&Coord2d::new([1.5, 1.0]), GEOMETRICAL_TOLERANCE));
+        assert!((bbox.diagonal_length() - 5.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+}
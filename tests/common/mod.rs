@@ -0,0 +1,10 @@
+use mersh::mesh::*;
+
+// Computing the volume enclosed by the triangles of a closed surface, positive if they are oriented outward.
+pub fn get_enclosed_volume(mesh: &Mesh3d) -> f64 {
+
+    mesh.triangles.iter().map(|t| {
+        let (a, b, c) = (&mesh.vertices[t.indexes[0]].coords, &mesh.vertices[t.indexes[1]].coords, &mesh.vertices[t.indexes[2]].coords);
+        (a.x * (b.y * c.z - b.z * c.y) - a.y * (b.x * c.z - b.z * c.x) + a.z * (b.x * c.y - b.y * c.x)) / 6.0
+    }).sum()
+}
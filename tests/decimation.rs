@@ -0,0 +1,118 @@
+extern crate mersh;
+
+mod common;
+
+mod decimation {
+
+    use common::*;
+    use mersh::base::*;
+    use mersh::decimation::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use std::collections::HashMap;
+
+    // Building an open hemisphere whose rim is the unit circle in the plane z = 0.
+    fn make_hemisphere(n_lat: usize, n_lon: usize) -> Mesh3d {
+
+        let mut mesh = Mesh3d::default();
+        for i in 0..n_lat {
+            let phi = 0.5 * std::f64::consts::PI * (i as f64) / (n_lat as f64);
+            for j in 0..n_lon {
+                let theta = 2.0 * std::f64::consts::PI * (j as f64) / (n_lon as f64);
+                mesh.vertices.push(Pnt3d::new([phi.cos() * theta.cos(), phi.cos() * theta.sin(), phi.sin()]));
+            }
+        }
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+
+        for i in 0..n_lat - 1 {
+            for j in 0..n_lon {
+                let (a, b) = (i * n_lon + j, i * n_lon + (j + 1) % n_lon);
+                let (c, d) = (a + n_lon, b + n_lon);
+                mesh.triangles.push(Tri::new([a, b, d]));
+                mesh.triangles.push(Tri::new([a, d, c]));
+            }
+        }
+        let pole = n_lat * n_lon;
+        for j in 0..n_lon {
+            let (a, b) = ((n_lat - 1) * n_lon + j, (n_lat - 1) * n_lon + (j + 1) % n_lon);
+            mesh.triangles.push(Tri::new([a, b, pole]));
+        }
+        mesh
+    }
+
+    // Collecting the vertices lying on edges used by a single triangle.
+    fn boundary_vertices(mesh: &Mesh3d) -> Vec<usize> {
+        let mut count: HashMap<(usize, usize), usize> = HashMap::new();
+        for tri in &mesh.triangles {
+            for k in 0..3 {
+                let (a, b) = (tri.indexes[k], tri.indexes[(k + 1) % 3]);
+                *count.entry(if a < b { (a, b) } else { (b, a) }).or_insert(0) += 1;
+            }
+        }
+        let mut vertices: Vec<usize> = count.iter()
+            .filter(|&(_, &c)| c == 1)
+            .flat_map(|(&(a, b), _)| vec![a, b])
+            .collect();
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    #[test]
+    fn decimate_qem_volume() {
+
+        let mesh = Mesh3d::new_icosphere(3, 1.0);
+        let target = mesh.triangles.len() / 5;
+        let decimated = mesh.decimate_qem(target, QemOptions::default());
+
+        assert!(decimated.triangles.len() <= target);
+        let (v0, v1) = (get_enclosed_volume(&mesh), get_enclosed_volume(&decimated));
+        assert!(((v1 - v0) / v0).abs() < 0.02);
+    }
+
+    #[test]
+    fn decimate_qem_boundary() {
+
+        let mesh = make_hemisphere(8, 32);
+        let target = mesh.triangles.len() / 5;
+        let decimated = mesh.decimate_qem(target, QemOptions::default());
+
+        assert!(decimated.triangles.len() <= target);
+        let rim = boundary_vertices(&decimated);
+        assert!(rim.len() >= 3);
+        for v in rim {
+            let c = &decimated.vertices[v].coords;
+            assert!(c.z.abs() < GEOMETRICAL_TOLERANCE);
+            assert!(((c.x * c.x + c.y * c.y).sqrt() - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn decimate_qem_feature_edges() {
+
+        let mut mesh = Mesh3d::new_icosphere(2, 1.0);
+        for t in 0..10 {
+            let indexes = mesh.triangles[7 * t].indexes;
+            mesh.push_tagged_edge(Edge::new([indexes[0], indexes[1]]), "feature");
+        }
+
+        let options = QemOptions { feature_tag: Some(String::from("feature")), .. QemOptions::default() };
+        let decimated = mesh.decimate_qem(mesh.triangles.len() / 5, options);
+
+        match decimated.edges_tags.get_registered_indexes("feature") {
+            Some(indexes) => {
+                assert_eq!(indexes.len(), 10);
+                for (k, &e) in indexes.iter().enumerate() {
+                    let original = mesh.get_edge_view(&mesh.edges[k]);
+                    let edge = decimated.get_edge_view(&decimated.edges[e]);
+                    assert!(edge.points[0].coords.equals(&original.points[0].coords, GEOMETRICAL_TOLERANCE));
+                    assert!(edge.points[1].coords.equals(&original.points[1].coords, GEOMETRICAL_TOLERANCE));
+
+                    let [a, b] = decimated.edges[e].indexes;
+                    assert!(decimated.triangles.iter().any(|tri| tri.indexes.contains(&a) && tri.indexes.contains(&b)));
+                }
+            },
+            None => { panic!(); }
+        }
+    }
+}
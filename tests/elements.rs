@@ -62,4 +62,24 @@ mod hexa {
         assert_eq!(hexa.indexes, idx);
     }
 
-}
\ No newline at end of file
+}
+mod element {
+
+    use mersh::elements::*;
+
+    // Checking the element trait features of an element built from given indexes.
+    fn check<E: Element>(element: &E, idx: &[usize], kind: ElementKind) {
+        assert_eq!(E::NVERTICES, idx.len());
+        assert_eq!(E::KIND, kind);
+        assert_eq!(element.vertex_indices(), idx);
+    }
+
+    #[test]
+    fn vertex_indices() {
+        check(&Edge::new([0, 13]), &[0, 13], ElementKind::Edge);
+        check(&Tri::new([0, 13, 24]), &[0, 13, 24], ElementKind::Tri);
+        check(&Quad::new([0, 13, 53, 21]), &[0, 13, 53, 21], ElementKind::Quad);
+        check(&Tet::new([0, 13, 34, 98]), &[0, 13, 34, 98], ElementKind::Tet);
+        check(&Hexa::new([0, 13, 34, 98, 35, 69, 90, 43]), &[0, 13, 34, 98, 35, 69, 90, 43], ElementKind::Hexa);
+    }
+}
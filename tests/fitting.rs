@@ -0,0 +1,160 @@
+extern crate mersh;
+
+mod fitting {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::fitting::*;
+    use mersh::mesh::*;
+
+    // Building a triangulated grid of n x n unit squares in the plane z = x + y.
+    fn make_tilted_grid(n: usize) -> Mesh3d {
+        let mut mesh = Mesh3d::default();
+        for j in 0..n + 1 {
+            for i in 0..n + 1 { mesh.vertices.push(Pnt3d::new([i as f64, j as f64, (i + j) as f64])); }
+        }
+        for j in 0..n {
+            for i in 0..n {
+                let p = j * (n + 1) + i;
+                mesh.triangles.push(Tri::new([p, p + 1, p + n + 2]));
+                mesh.triangles.push(Tri::new([p, p + n + 2, p + n + 1]));
+            }
+        }
+        mesh
+    }
+
+    // Building the lateral surface of a cylinder of axis (1, 1, 0) passing through (1, 2, 3).
+    fn make_cylinder(radius: f64, height: f64, n_rings: usize, n_sectors: usize) -> Mesh3d {
+        let mut mesh = Mesh3d::new_cylinder(n_sectors, n_rings, radius, height);
+        mesh.remove_triangles_by_tag("bottom");
+        mesh.remove_triangles_by_tag("top");
+        mesh.remove_unused_vertices();
+        mesh.rotate(&Vec3d::new([-1., 1., 0.]).normalize_out(), 0.5 * std::f64::consts::PI, &Pnt3d::new([0., 0., 0.]))
+            .translate(&Vec3d::new([1., 2., 3.]));
+        mesh
+    }
+
+    #[test]
+    fn fit_to_sphere_0() {
+
+        let mut mesh = Mesh3d::new_icosphere(2, 3.0);
+        mesh.translate(&Vec3d::new([1., -2., 0.5]));
+        match mesh.fit_to_sphere() {
+            Ok(fit) => {
+                assert!(fit.center.coords.equals(&Coord3d::new([1., -2., 0.5]), GEOMETRICAL_TOLERANCE));
+                assert!((fit.radius - 3.0).abs() < 1e-10);
+                assert!(fit.max_deviation < 1e-10);
+                assert!(fit.rms_deviation <= fit.max_deviation);
+            },
+            Err(_) => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn fit_to_sphere_1() {
+
+        let mesh = make_tilted_grid(3);
+        assert_eq!(mesh.fit_to_sphere().unwrap_err(), FitError::Degenerate);
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        assert_eq!(mesh.fit_to_sphere().unwrap_err(), FitError::NotEnoughPoints { required: 4, found: 1 });
+    }
+
+    #[test]
+    fn fit_to_plane_0() {
+
+        let mesh = make_tilted_grid(4);
+        match mesh.fit_to_plane() {
+            Ok(fit) => {
+                let expected = Vec3d::new([1., 1., -1.]).normalize_out();
+                assert!((fit.normal.dot(&expected).abs() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+                assert!(fit.origin.coords.equals(&Coord3d::new([2., 2., 4.]), GEOMETRICAL_TOLERANCE));
+                assert!(fit.max_deviation < 1e-10);
+            },
+            Err(_) => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn fit_to_plane_1() {
+
+        let mut mesh = Mesh3d::default();
+        for i in 0..5 { mesh.vertices.push(Pnt3d::new([i as f64, 2.0 * (i as f64), 1.0])); }
+        assert_eq!(mesh.fit_to_plane().unwrap_err(), FitError::Degenerate);
+    }
+
+    #[test]
+    fn fit_to_cylinder_0() {
+
+        let mesh = make_cylinder(0.75, 4.0, 5, 24);
+        match mesh.fit_to_cylinder() {
+            Ok(fit) => {
+                let expected = Vec3d::new([1., 1., 0.]).normalize_out();
+                assert!((fit.axis.dot(&expected).abs() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+                assert!((fit.radius - 0.75).abs() < 1e-10);
+                assert!(fit.max_deviation < 1e-10);
+
+                // The fitted origin lies on the generating axis.
+                let w = Vec3d { coords: fit.origin.coords.add_out(-1.0, &Coord3d::new([1., 2., 3.])) };
+                assert!(w.cross_out(&Vec3d { coords: expected.coords.clone() }).coords.norm() < 1e-10);
+            },
+            Err(_) => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn fit_to_cylinder_1() {
+
+        let mesh = make_tilted_grid(2);
+        assert_eq!(mesh.fit_to_cylinder().unwrap_err(), FitError::Degenerate);
+    }
+
+    #[test]
+    fn detect_symmetry_plane_0() {
+
+        let mesh = Mesh3d::new_box(2, 2, 2, 2., 2., 2.);
+        match mesh.detect_symmetry_plane(GEOMETRICAL_TOLERANCE) {
+            Some((point, normal)) => {
+                assert!(point.coords.add_out(-1.0, &Coord3d::new([1., 1., 1.])).norm() < GEOMETRICAL_TOLERANCE);
+                assert!((normal.coords.norm() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+                // Every mirrored vertex lands on a vertex.
+                for p in &mesh.vertices {
+                    let offset = p.coords.add_out(-1.0, &point.coords).dot(&normal.coords);
+                    let mirror = p.coords.add_out(-2.0 * offset, &normal.coords);
+                    assert!(mesh.vertices.iter().any(|q| q.coords.add_out(-1.0, &mirror).norm() < GEOMETRICAL_TOLERANCE));
+                }
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn detect_symmetry_plane_1() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([3., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0.5, 2., 0.]));
+        mesh.vertices.push(Pnt3d::new([0.2, 0.7, 1.3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        assert!(mesh.detect_symmetry_plane(1e-3).is_none());
+    }
+
+    #[test]
+    fn detect_symmetry_plane_2() {
+
+        // Cells saturating the integer range for large coordinates.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([-1e8, 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1e8, 0., 0.]));
+        match mesh.detect_symmetry_plane(0.) {
+            Some((point, normal)) => {
+                assert!(point.coords.norm() < GEOMETRICAL_TOLERANCE);
+                assert!((normal.coords.x.abs() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+            },
+            None => { panic!(); }
+        }
+    }
+}
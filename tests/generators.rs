@@ -0,0 +1,183 @@
+extern crate mersh;
+
+mod common;
+
+mod generators {
+
+    use common::*;
+    use mersh::base::*;
+    use mersh::mesh::*;
+
+    #[test]
+    fn new_rectangle() {
+
+        let mesh = Mesh2d::new_rectangle(3, 2, 1.5, 4.0);
+        assert_eq!(mesh.vertices.len(), 12);
+        assert_eq!(mesh.triangles.len(), 12);
+        assert_eq!(mesh.edges.len(), 10);
+        assert!((mesh.get_total_area() - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(mesh.vertices[11].coords.equals(&Coord2d::new([1.5, 4.0]), GEOMETRICAL_TOLERANCE));
+        for tri in &mesh.triangles {
+            let t = mesh.get_tri_view(tri);
+            assert!(t.points[0].to(t.points[1]).cross_out(&t.points[0].to(t.points[2])) > 0.);
+        }
+
+        // Tag name, number of edges, and whether the edges lie on a vertical side at a given abscissa or
+        // on a horizontal side at a given ordinate.
+        let expected = [("bottom", 3, false, 0.0), ("top", 3, false, 4.0), ("left", 2, true, 0.0), ("right", 2, true, 1.5)];
+        for &(name, n, vertical, value) in &expected {
+            match mesh.edges_tags.get_registered_indexes(name) {
+                Some(indexes) => {
+                    assert_eq!(indexes.len(), n);
+                    for &i in indexes {
+                        for &v in &mesh.edges[i].indexes {
+                            let c = &mesh.vertices[v].coords;
+                            assert!((if vertical { c.x } else { c.y } - value).abs() < GEOMETRICAL_TOLERANCE);
+                        }
+                    }
+                },
+                None => { panic!(); }
+            }
+        }
+
+        // Tagged edges are the outward oriented boundary.
+        let boundary = mesh.extract_boundary();
+        assert_eq!(boundary.len(), 10);
+        for edge in &boundary { assert!(mesh.edges.iter().any(|e| e.indexes == edge.indexes)); }
+    }
+
+    #[test]
+    fn new_disk() {
+
+        let mesh = Mesh2d::new_disk(3, 8, 2.0);
+        assert_eq!(mesh.vertices.len(), 25);
+        assert_eq!(mesh.triangles.len(), 40);
+        let area = 4.0 * 4.0 * (std::f64::consts::PI / 4.0).sin();
+        assert!((mesh.get_total_area() - area).abs() < 1e-10);
+        for tri in &mesh.triangles {
+            let t = mesh.get_tri_view(tri);
+            assert!(t.points[0].to(t.points[1]).cross_out(&t.points[0].to(t.points[2])) > 0.);
+        }
+
+        match mesh.edges_tags.get_registered_indexes("boundary") {
+            Some(indexes) => {
+                assert_eq!(indexes.len(), 8);
+                for &i in indexes {
+                    for &v in &mesh.edges[i].indexes {
+                        assert!((mesh.vertices[v].coords.norm() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+                    }
+                }
+            },
+            None => { panic!(); }
+        }
+        assert_eq!(mesh.extract_boundary().len(), 8);
+    }
+
+    #[test]
+    fn new_box() {
+
+        let mesh = Mesh3d::new_box(2, 3, 4, 1.0, 2.0, 3.0);
+        assert_eq!(mesh.vertices.len(), 60);
+        assert_eq!(mesh.hexahedra.len(), 24);
+        assert_eq!(mesh.quadrangles.len(), 2 * (6 + 8 + 12));
+        assert!((mesh.get_total_volume(false) - 6.0).abs() < 1e-10);
+        assert!((mesh.get_total_surface_area() - 2.0 * (2.0 + 3.0 + 6.0)).abs() < 1e-10);
+        assert!(mesh.is_watertight());
+
+        let center = Pnt3d::new([0.5, 1.0, 1.5]);
+        let expected = [("bottom", 6), ("top", 6), ("front", 8), ("back", 8), ("left", 12), ("right", 12)];
+        for &(name, n) in &expected {
+            match mesh.quadrangles_tags.get_registered_indexes(name) {
+                Some(indexes) => {
+                    assert_eq!(indexes.len(), n);
+                    for &i in indexes {
+                        let q = mesh.get_quad_view(&mesh.quadrangles[i]);
+                        let outward = center.to(&q.get_barycenter());
+                        assert!(q.get_tri_view(mersh::elements::TriInQuad::Tri013).get_normal().coords.dot(&outward.coords) > 0.);
+                    }
+                },
+                None => { panic!(); }
+            }
+        }
+        assert_eq!(mesh.extract_boundary_faces().1.len(), mesh.quadrangles.len());
+    }
+
+    #[test]
+    fn new_uv_sphere() {
+
+        let center = Pnt3d::new([1., -2., 0.5]);
+        let mesh = Mesh3d::new_uv_sphere(8, 12, 2.0, center.clone());
+        assert_eq!(mesh.vertices.len(), 2 + 7 * 12);
+        assert_eq!(mesh.triangles.len(), 2 * 7 * 12);
+        assert_eq!(mesh.euler_characteristic(), 2);
+        let (tris, quads) = mesh.extract_boundary_faces();
+        assert!(tris.is_empty() && quads.is_empty());
+        for p in &mesh.vertices {
+            assert!((p.coords.add_out(-1.0, &center.coords).norm() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        }
+        for tri in &mesh.triangles { assert!(mesh.get_tri_view(tri).get_area() > GEOMETRICAL_TOLERANCE); }
+
+        // The area converges toward 4 pi r^2 from below as the resolution increases.
+        let exact = 4.0 * std::f64::consts::PI;
+        let errors: Vec<f64> = [4, 8, 16, 32].iter()
+            .map(|&n| exact - Mesh3d::new_uv_sphere(n, 2 * n, 1.0, Pnt3d::new([0., 0., 0.])).get_total_surface_area())
+            .collect();
+        assert!(errors[0] > 0.);
+        for k in 1..errors.len() { assert!(errors[k] > 0. && errors[k] < errors[k - 1]); }
+        assert!(errors[3] < 0.05);
+        assert!(get_enclosed_volume(&Mesh3d::new_uv_sphere(8, 12, 1.0, Pnt3d::new([0., 0., 0.]))) > 0.);
+    }
+
+    #[test]
+    fn new_icosphere() {
+
+        let mesh = Mesh3d::new_icosphere(2, 1.5);
+        assert_eq!(mesh.vertices.len(), 162);
+        assert_eq!(mesh.triangles.len(), 320);
+        assert_eq!(mesh.euler_characteristic(), 2);
+        let (tris, quads) = mesh.extract_boundary_faces();
+        assert!(tris.is_empty() && quads.is_empty());
+        for p in &mesh.vertices { assert!((p.coords.norm() - 1.5).abs() < GEOMETRICAL_TOLERANCE); }
+        assert!(get_enclosed_volume(&mesh) > 0.);
+
+        // The area converges toward 4 pi r^2 from below as the resolution increases.
+        let exact = 4.0 * std::f64::consts::PI;
+        let errors: Vec<f64> = (0..5).map(|n| exact - Mesh3d::new_icosphere(n, 1.0).get_total_surface_area()).collect();
+        assert!(errors[0] > 0.);
+        for k in 1..errors.len() { assert!(errors[k] > 0. && errors[k] < errors[k - 1]); }
+        assert!(errors[4] < 0.05);
+    }
+
+    #[test]
+    fn new_cylinder() {
+
+        let mesh = Mesh3d::new_cylinder(16, 3, 0.5, 2.0);
+        assert_eq!(mesh.vertices.len(), 4 * 16 + 2);
+        assert_eq!(mesh.triangles.len(), 2 * 3 * 16 + 2 * 16);
+        assert_eq!(mesh.euler_characteristic(), 2);
+        let (tris, quads) = mesh.extract_boundary_faces();
+        assert!(tris.is_empty() && quads.is_empty());
+        assert!(get_enclosed_volume(&mesh) > 0.);
+
+        // Tag name, number of triangles, and the ordinate along the axis of the caps.
+        let expected = [("side", 96, None), ("bottom", 16, Some(0.0)), ("top", 16, Some(2.0))];
+        for &(name, n, z) in &expected {
+            match mesh.triangles_tags.get_registered_indexes(name) {
+                Some(indexes) => {
+                    assert_eq!(indexes.len(), n);
+                    if let Some(z) = z {
+                        for &i in indexes {
+                            for &v in &mesh.triangles[i].indexes { assert!((mesh.vertices[v].coords.z - z).abs() < GEOMETRICAL_TOLERANCE); }
+                        }
+                    }
+                },
+                None => { panic!(); }
+            }
+        }
+
+        // Area of a prism with regular polygonal bases inscribed in the circle.
+        let side = 2.0 * 0.5 * (std::f64::consts::PI / 16.0).sin();
+        let base = 0.5 * 16.0 * 0.25 * (2.0 * std::f64::consts::PI / 16.0).sin();
+        assert!((mesh.get_total_surface_area() - (16.0 * side * 2.0 + 2.0 * base)).abs() < GEOMETRICAL_TOLERANCE);
+    }
+}
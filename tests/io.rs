@@ -0,0 +1,380 @@
+extern crate mersh;
+
+mod vtk {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use mersh::mesh::io::*;
+
+    // Reading the counts following a given keyword in a legacy VTK file.
+    fn get_counts(text: &str, keyword: &str) -> Vec<usize>
+    {
+        let line = text.lines().find(|l| l.starts_with(keyword)).unwrap();
+        line.split_whitespace().skip(1).filter_map(|s| s.parse().ok()).collect()
+    }
+
+    #[test]
+    fn write_vtk_legacy_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 1.]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 3, 4]));
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+
+        let mut buffer = Vec::new();
+        assert!(write_vtk_legacy(&mesh, &mut buffer).is_ok());
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert!(text.starts_with("# vtk DataFile Version 3.0\n"));
+        assert!(text.contains("DATASET UNSTRUCTURED_GRID\n"));
+        assert_eq!(get_counts(&text, "POINTS"), vec![8]);
+        assert_eq!(get_counts(&text, "CELLS"), vec![5, 3 + 4 + 5 + 5 + 9]);
+        assert_eq!(get_counts(&text, "CELL_TYPES"), vec![5]);
+        assert!(text.contains("8 0 1 2 3 4 5 6 7\n"));
+        assert!(text.ends_with("CELL_TYPES 5\n3\n5\n9\n10\n12\n"));
+    }
+
+    #[test]
+    fn write_vtk_legacy_1() {
+
+        let mesh = Mesh3d::default();
+
+        let mut buffer = Vec::new();
+        assert!(write_vtk_legacy(&mesh, &mut buffer).is_ok());
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert_eq!(get_counts(&text, "POINTS"), vec![0]);
+        assert_eq!(get_counts(&text, "CELLS"), vec![0, 0]);
+        assert_eq!(get_counts(&text, "CELL_TYPES"), vec![0]);
+    }
+}
+
+mod obj {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use mersh::mesh::io::*;
+
+    #[test]
+    fn write_obj_0() {
+
+        // Unit square split in two triangles.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+
+        let mut buffer = Vec::new();
+        assert!(write_obj(&mesh, &mut buffer).is_ok());
+        assert_eq!(String::from_utf8(buffer).unwrap(),
+            "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3\nf 1 3 4\n");
+    }
+
+    #[test]
+    fn write_obj_1() {
+
+        // Unit square as a quadrangle, with an edge and a tetrahedron which are skipped.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.5]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+
+        let mut buffer = Vec::new();
+        assert!(write_obj(&mesh, &mut buffer).is_ok());
+        assert_eq!(String::from_utf8(buffer).unwrap(),
+            "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0.5\nf 1 2 3 4\n");
+    }
+}
+
+mod stl {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use mersh::mesh::io::*;
+
+    // Reading a little-endian single precision float at a given offset.
+    fn read_f32(bytes: &[u8], offset: usize) -> f32
+    {
+        f32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+    }
+
+    #[test]
+    fn write_stl_binary_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.triangles.push(Tri::new([0, 4, 1]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 3, 4]));
+
+        let mut buffer = Vec::new();
+        assert!(write_stl_binary(&mesh, &mut buffer).is_ok());
+
+        assert_eq!(buffer.len(), 84 + 3 * 50);
+        assert_eq!(u32::from_le_bytes([buffer[80], buffer[81], buffer[82], buffer[83]]), 3);
+
+        // First facet normal and vertices.
+        assert_eq!([read_f32(&buffer, 84), read_f32(&buffer, 88), read_f32(&buffer, 92)], [0., 1., 0.]);
+        assert_eq!([read_f32(&buffer, 108), read_f32(&buffer, 112), read_f32(&buffer, 116)], [0., 0., 1.]);
+
+        // Facets from the split quadrangle.
+        assert_eq!(read_f32(&buffer, 84 + 50 + 8), 1.);
+        assert_eq!(read_f32(&buffer, 84 + 100 + 8), 1.);
+    }
+
+    #[test]
+    fn write_stl_binary_1() {
+
+        // Degenerate triangle.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 1]));
+
+        let mut buffer = Vec::new();
+        assert!(write_stl_binary(&mesh, &mut buffer).is_ok());
+        assert_eq!(buffer.len(), 84 + 50);
+        assert_eq!([read_f32(&buffer, 84), read_f32(&buffer, 88), read_f32(&buffer, 92)], [0., 0., 0.]);
+    }
+}
+
+mod gmsh {
+
+    use mersh::base::*;
+    use mersh::mesh::io::*;
+
+    #[test]
+    fn read_gmsh_22_0() {
+
+        let mut input = include_str!("data/square.msh").as_bytes();
+        let mesh = match read_gmsh_22(&mut input) {
+            Ok(mesh) => mesh,
+            Err(_) => { panic!(); }
+        };
+
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.edges.len(), 2);
+        assert_eq!(mesh.triangles.len(), 2);
+        assert!(mesh.quadrangles.is_empty());
+        assert!(mesh.vertices[2].coords.equals(&Coord3d::new([1., 1., 0.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 3]);
+        assert_eq!(mesh.edges[1].indexes, [1, 2]);
+
+        match mesh.triangles_tags.get_registered_indexes("square") {
+            Some(indexes) => assert_eq!(*indexes, vec![0, 1]),
+            None => { panic!(); }
+        }
+        match mesh.edges_tags.get_registered_indexes("boundary") {
+            Some(indexes) => assert_eq!(*indexes, vec![0, 1]),
+            None => { panic!(); }
+        }
+        assert!((mesh.get_total_surface_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn read_gmsh_22_1() {
+
+        // Tags without physical names, and an unknown node.
+        let mut input = "$Nodes\n3\n1 0 0 0\n2 1 0 0\n3 0 1 0\n$EndNodes\n$Elements\n1\n1 2 2 7 1 1 2 3\n$EndElements\n".as_bytes();
+        match read_gmsh_22(&mut input) {
+            Ok(mesh) => assert!(mesh.triangles_tags.get_registered_indexes("7").is_some()),
+            Err(_) => { panic!(); }
+        }
+
+        let mut input = "$Nodes\n1\n1 0 0 0\n$EndNodes\n$Elements\n1\n1 1 0 1 2\n$EndElements\n".as_bytes();
+        match read_gmsh_22(&mut input) {
+            Err(MeshIoError::Parse { line, .. }) => assert_eq!(line, 7),
+            _ => { panic!(); }
+        }
+
+        let mut input = "$MeshFormat\n4.1 0 8\n$EndMeshFormat\n".as_bytes();
+        assert!(read_gmsh_22(&mut input).is_err());
+
+        let mut input = "$Nodes\n2\n1 0 0 0\n".as_bytes();
+        match read_gmsh_22(&mut input) {
+            Err(MeshIoError::UnexpectedEnd) => {},
+            _ => { panic!(); }
+        }
+    }
+}
+
+mod off {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use mersh::mesh::io::*;
+
+    #[test]
+    fn read_off_0() {
+
+        let mut input = include_str!("data/cube.off").as_bytes();
+        let mesh = match read_off(&mut input) {
+            Ok(mesh) => mesh,
+            Err(_) => { panic!(); }
+        };
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 12);
+        assert!(mesh.quadrangles.is_empty());
+        assert_eq!(mesh.triangles[0].indexes, [0, 3, 2]);
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 1]);
+        assert!(mesh.is_watertight());
+        assert!((mesh.get_total_surface_area() - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn read_off_1() {
+
+        // Counts on the header line and a trailing face colour.
+        let mut input = "OFF 3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2 255 0 0\n".as_bytes();
+        match read_off(&mut input) {
+            Ok(mesh) => assert_eq!(mesh.triangles.len(), 1),
+            Err(_) => { panic!(); }
+        }
+
+        let mut input = "OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 3\n".as_bytes();
+        match read_off(&mut input) {
+            Err(MeshIoError::Parse { line, .. }) => assert_eq!(line, 6),
+            _ => { panic!(); }
+        }
+
+        let mut input = "COFF\n".as_bytes();
+        assert!(read_off(&mut input).is_err());
+
+        let mut input = "OFF\n3 1 0\n0 0 0\n".as_bytes();
+        match read_off(&mut input) {
+            Err(MeshIoError::UnexpectedEnd) => {},
+            _ => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn read_off_2() {
+
+        match Mesh3d::read_off(include_str!("data/cube.off").as_bytes()) {
+            Ok(mesh) => {
+                assert_eq!(mesh.vertices.len(), 8);
+                assert_eq!(mesh.triangles.len(), 12);
+            },
+            Err(_) => { panic!(); }
+        }
+
+        let error: OffError = match Mesh3d::read_off("OFF\n3 1 0\n0 0 0\n".as_bytes()) {
+            Ok(_) => { panic!(); },
+            Err(e) => e,
+        };
+        assert_eq!(error.to_string(), "unexpected end of input");
+    }
+
+    #[test]
+    fn write_off_0() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        for f in &[[0, 2, 3, 1], [4, 5, 7, 6], [0, 1, 5, 4], [2, 6, 7, 3], [0, 4, 6, 2], [1, 3, 7, 5]] {
+            mesh.quadrangles.push(Quad::new(*f));
+        }
+        mesh.triangles.push(Tri::new([0, 1, 7]));
+
+        let mut output = Vec::new();
+        if write_off(&mesh, &mut output).is_err() { panic!(); }
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "OFF");
+        assert_eq!(lines[1], "8 7 14");
+        assert_eq!(lines[9], "1 1 1");
+        assert_eq!(lines[10], "3 0 1 7");
+        assert_eq!(lines[11], "4 0 2 3 1");
+        assert_eq!(lines.len(), 17);
+
+        match read_off(&mut text.as_bytes()) {
+            Ok(read) => {
+                assert_eq!(read.vertices.len(), 8);
+                assert_eq!(read.triangles.len(), 13);
+            },
+            Err(_) => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn write_off_1() {
+
+        let mut input = include_str!("data/cube.off").as_bytes();
+        let mesh = match read_off(&mut input) {
+            Ok(mesh) => mesh,
+            Err(_) => { panic!(); }
+        };
+
+        let mut output = Vec::new();
+        if mesh.write_off(&mut output).is_err() { panic!(); }
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[1], "8 12 18");
+        assert_eq!(lines[10], "3 0 3 2");
+    }
+}
+
+mod ply {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+    use mersh::mesh::io::*;
+
+    #[test]
+    fn write_ply_ascii_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 4]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+        mesh.edges.push(Edge::new([0, 4]));
+
+        let mut buffer = Vec::new();
+        assert!(write_ply_ascii(&mesh, &mut buffer).is_ok());
+        let text = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "ply");
+        assert!(lines.contains(&"element vertex 5"));
+        assert!(lines.contains(&"element face 2"));
+        let body = match lines.iter().position(|&l| l == "end_header") {
+            Some(i) => &lines[i + 1..],
+            None => { panic!(); }
+        };
+        assert_eq!(body.len(), 5 + 2);
+        assert_eq!(body[4], "0 0 1");
+        assert_eq!(body[5], "3 0 1 4");
+        assert_eq!(body[6], "4 0 1 2 3");
+    }
+}
@@ -1,4 +1,5 @@
 extern crate mersh;
+extern crate serde_json;
 
 mod mesh {
 
@@ -21,7 +22,7 @@ mod mesh {
 
         match mesh.vertices_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 1); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -37,7 +38,7 @@ mod mesh {
 
         match mesh.edges_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 0); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -56,6 +57,31 @@ mod mesh {
         assert!((e.points[1].coords.z - 1.5).abs() < GEOMETRICAL_TOLERANCE);
     }
 
+    #[test]
+    fn push_tagged() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        mesh.push_tagged(Edge::new([0, 1]), "tag");
+        mesh.push_tagged(Tri::new([0, 1, 2]), "tag");
+        mesh.push_tagged(Quad::new([0, 1, 3, 2]), "tag");
+        mesh.push_tagged(Tet::new([0, 1, 2, 4]), "tag");
+        mesh.push_tagged(Tet::new([1, 3, 2, 7]), "tag");
+        mesh.push_tagged(Hexa::new([0, 1, 3, 2, 4, 5, 7, 6]), "other");
+
+        assert_eq!(mesh.edges.len(), 1);
+        assert_eq!(mesh.triangles.len(), 1);
+        assert_eq!(mesh.quadrangles.len(), 1);
+        assert_eq!(mesh.tetrahedra.len(), 2);
+        assert_eq!(mesh.hexahedra.len(), 1);
+        assert_eq!(*mesh.edges_tags.get_registered_indexes("tag").unwrap(), vec![0]);
+        assert_eq!(*mesh.triangles_tags.get_registered_indexes("tag").unwrap(), vec![0]);
+        assert_eq!(*mesh.quadrangles_tags.get_registered_indexes("tag").unwrap(), vec![0]);
+        assert_eq!(*mesh.tetrahedra_tags.get_registered_indexes("tag").unwrap(), vec![0, 1]);
+        assert_eq!(*mesh.hexahedra_tags.get_registered_indexes("other").unwrap(), vec![0]);
+        assert!(mesh.hexahedra_tags.get_registered_indexes("tag").is_none());
+    }
+
     #[test]
     fn push_tagged_triangle() {
 
@@ -69,7 +95,7 @@ mod mesh {
 
         match mesh.triangles_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 0); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -103,7 +129,7 @@ mod mesh {
 
         match mesh.quadrangles_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 0); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -138,7 +164,7 @@ mod mesh {
 
         match mesh.tetrahedra_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 0); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -177,7 +203,7 @@ mod mesh {
 
         match mesh.hexahedra_tags.get_registered_indexes(&name) {
             Some(indexes) => { assert_eq!(indexes[0], 0); },
-            None => { assert!(false); }
+            None => { panic!(); }
         }
     }
 
@@ -201,4 +227,2085 @@ mod mesh {
         assert!((quad.points[7].coords.y - 1.).abs() < GEOMETRICAL_TOLERANCE);
         assert!((quad.points[7].coords.z - 1.).abs() < GEOMETRICAL_TOLERANCE);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn stitch_seam() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.push_tagged_vertex(Pnt3d::new([1., 0., 0.]), "seam_a");
+        mesh.push_tagged_vertex(Pnt3d::new([1. + 1e-6, 0., 0.]), "seam_b");
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.edges.push(Edge::new([2, 3]));
+
+        assert_eq!(mesh.stitch_seam("seam_a", "seam_b", 1e-3), 1);
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.edges[0].indexes[1], mesh.edges[1].indexes[0]);
+
+        let e = mesh.get_edge_view(&mesh.edges[1]);
+        assert!((e.points[1].coords.x - 2.).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(mesh.vertices_tags.get_registered_indexes("seam_a").is_some());
+    }
+
+    #[test]
+    fn serialize() {
+
+        let mut mesh0 = Mesh3d::default();
+        let mut mesh1 = Mesh3d::default();
+
+        mesh0.push_tagged_vertex(Pnt3d::new([0., 0., 0.]), "a");
+        mesh0.push_tagged_vertex(Pnt3d::new([1., 0., 0.]), "b");
+        mesh0.push_tagged_edge(Edge::new([0, 1]), "c");
+        mesh0.push_tagged_edge(Edge::new([1, 0]), "d");
+
+        mesh1.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh1.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh1.vertices_tags.register("b", 1);
+        mesh1.vertices_tags.register("a", 0);
+        mesh1.edges.push(Edge::new([0, 1]));
+        mesh1.edges.push(Edge::new([1, 0]));
+        mesh1.edges_tags.register("d", 1);
+        mesh1.edges_tags.register("c", 0);
+
+        assert_eq!(serde_json::to_string(&mesh0).unwrap(), serde_json::to_string(&mesh1).unwrap());
+    }
+
+    #[test]
+    fn unique_edges() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        mesh.triangles.push(Tri::new([1, 2, 3]));
+        mesh.quadrangles.push(Quad::new([1, 4, 2, 3]));
+
+        let edges = mesh.unique_edges();
+        assert_eq!(edges.len(), 7);
+        assert_eq!(edges[0].indexes, [0, 1]);
+        assert_eq!(edges[5].indexes, [1, 4]);
+    }
+
+    #[test]
+    fn wireframe() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        mesh.triangles.push(Tri::new([1, 2, 3]));
+
+        let wireframe = mesh.wireframe();
+        assert_eq!(wireframe.vertices.len(), 4);
+        assert_eq!(wireframe.edges.len(), 5);
+        assert!(wireframe.triangles.is_empty());
+        assert!(wireframe.quadrangles.is_empty());
+    }
+
+    #[test]
+    fn vertex_neighbours() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        mesh.triangles.push(Tri::new([1, 2, 3]));
+
+        let neighbours = mesh.vertex_neighbours();
+        assert_eq!(neighbours[0], vec![1, 3]);
+        assert_eq!(neighbours[1], vec![0, 2, 3]);
+        assert_eq!(neighbours[2], vec![1, 3]);
+        assert_eq!(neighbours[3], vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn par_update_vertices() {
+
+        let mut mesh = Mesh3d::default();
+        for i in 0..1000 {
+            let t = i as f64;
+            mesh.vertices.push(Pnt3d::new([t.cos(), t.sin(), 0.1 * t]));
+        }
+
+        let f = |i: usize, p: &Pnt3d| Pnt3d { coords: p.coords.amplify_out(2.0).add_out(1.0, &Coord3d::new([i as f64, 0., 0.])) };
+        let expected: Vec<Pnt3d> = mesh.vertices.iter().enumerate().map(|(i, p)| f(i, p)).collect();
+        mesh.par_update_vertices(f);
+
+        for (p, q) in mesh.vertices.iter().zip(expected.iter()) {
+            assert_eq!(p.coords.x, q.coords.x);
+            assert_eq!(p.coords.y, q.coords.y);
+            assert_eq!(p.coords.z, q.coords.z);
+        }
+    }
+
+    #[test]
+    fn par_update_vertices_with_neighbours() {
+
+        let mut mesh = Mesh3d::default();
+        for j in 0..10 {
+            for i in 0..10 {
+                let (x, y) = (i as f64, j as f64);
+                mesh.vertices.push(Pnt3d::new([x, y, (x * y).sin()]));
+            }
+        }
+        for j in 0..9 {
+            for i in 0..9 {
+                let p = 10 * j + i;
+                mesh.triangles.push(Tri::new([p, p + 1, p + 11]));
+                mesh.triangles.push(Tri::new([p, p + 11, p + 10]));
+            }
+        }
+
+        // Reference Laplacian smoothing step computed sequentially.
+        let neighbours = mesh.vertex_neighbours();
+        let mut expected = Vec::new();
+        for (i, p) in mesh.vertices.iter().enumerate() {
+            let mut mean = Coord3d::default();
+            for &n in &neighbours[i] { mean.add_in(1.0 / neighbours[i].len() as f64, &mesh.vertices[n].coords); }
+            expected.push(p.coords.mlt_add_out(0.5, 0.5, &mean));
+        }
+
+        mesh.par_update_vertices_with_neighbours(&neighbours, |_, p, ring| {
+            let mut mean = Coord3d::default();
+            for q in ring { mean.add_in(1.0 / ring.len() as f64, &q.coords); }
+            Pnt3d { coords: p.coords.mlt_add_out(0.5, 0.5, &mean) }
+        });
+
+        for (p, q) in mesh.vertices.iter().zip(expected.iter()) {
+            assert!(p.coords.equals(q, GEOMETRICAL_TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn insert_vertex() {
+
+        let mut mesh = Mesh3d::default();
+        assert_eq!(mesh.insert_vertex(Pnt3d::new([0., 0., 0.])), 0);
+        assert_eq!(mesh.insert_vertex(Pnt3d::new([1., 0., 0.])), 1);
+        assert_eq!(mesh.insert_vertex(Pnt3d::new([0., 1., 0.])), 2);
+    }
+
+    #[test]
+    fn insert_triangle_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.insert_vertex(Pnt3d::new([0., 0., 0.]));
+        mesh.insert_vertex(Pnt3d::new([1., 0., 0.]));
+        mesh.insert_vertex(Pnt3d::new([0., 1., 0.]));
+
+        assert_eq!(mesh.insert_triangle(Tri::new([0, 1, 2])), Ok(0));
+        assert_eq!(mesh.insert_triangle(Tri::new([2, 1, 0])), Ok(1));
+        assert_eq!(mesh.insert_triangle(Tri::new([0, 1, 3])), Err(MeshError::VertexIndexOutOfRange { index: 3, n_vertices: 3 }));
+        assert_eq!(mesh.triangles.len(), 2);
+    }
+
+    #[test]
+    fn insert_triangle_1() {
+
+        // Without validation, elements may reference vertices which are inserted afterwards.
+        let mut mesh = Mesh3d { validation: ValidationMode::Off, ..Mesh3d::default() };
+        assert_eq!(mesh.insert_triangle(Tri::new([0, 1, 3])), Ok(0));
+        assert_eq!(mesh.triangles.len(), 1);
+    }
+
+    #[test]
+    fn insert_elements() {
+
+        let mut mesh = Mesh3d::default();
+        for i in 0..8 { mesh.insert_vertex(Pnt3d::new([i as f64, 0., 0.])); }
+
+        assert_eq!(mesh.insert_edge(Edge::new([0, 1])), Ok(0));
+        assert_eq!(mesh.insert_quadrangle(Quad::new([0, 1, 2, 3])), Ok(0));
+        assert_eq!(mesh.insert_tetrahedron(Tet::new([0, 1, 2, 3])), Ok(0));
+        assert_eq!(mesh.insert_hexahedron(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7])), Ok(0));
+        assert!(mesh.insert_edge(Edge::new([0, 8])).is_err());
+        assert!(mesh.insert_quadrangle(Quad::new([0, 1, 2, 9])).is_err());
+        assert!(mesh.insert_tetrahedron(Tet::new([10, 1, 2, 3])).is_err());
+        assert!(mesh.insert_hexahedron(Hexa::new([0, 1, 2, 3, 4, 5, 6, 8])).is_err());
+    }
+
+    #[test]
+    fn reserve() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.reserve_vertices(100);
+        mesh.reserve_edges(10);
+        mesh.reserve_triangles(200);
+        mesh.reserve_quadrangles(20);
+        mesh.reserve_tetrahedra(30);
+        mesh.reserve_hexahedra(40);
+
+        assert!(mesh.vertices.capacity() >= 100);
+        assert!(mesh.edges.capacity() >= 10);
+        assert!(mesh.triangles.capacity() >= 200);
+        assert!(mesh.quadrangles.capacity() >= 20);
+        assert!(mesh.tetrahedra.capacity() >= 30);
+        assert!(mesh.hexahedra.capacity() >= 40);
+    }
+
+    #[test]
+    fn split_connected_components() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([5., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([5., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.push_tagged_vertex(Pnt3d::new([6., 0., 0.]), "corner");
+        mesh.vertices.push(Pnt3d::new([9., 9., 9.]));
+        mesh.triangles.push(Tri::new([0, 2, 4]));
+        mesh.push_tagged_triangle(Tri::new([1, 5, 3]), "right");
+
+        let components = mesh.split_connected_components();
+        assert_eq!(components.len(), 2);
+
+        assert_eq!(components[0].vertices.len(), 3);
+        assert_eq!(components[0].triangles.len(), 1);
+        assert_eq!(components[0].triangles[0].indexes, [0, 1, 2]);
+        assert!(components[0].vertices_tags.get_registered_indexes("corner").is_none());
+        assert!(components[0].triangles_tags.get_registered_indexes("right").is_none());
+
+        assert_eq!(components[1].vertices.len(), 3);
+        assert_eq!(components[1].triangles.len(), 1);
+        assert_eq!(components[1].triangles[0].indexes, [0, 2, 1]);
+        assert!(components[1].vertices[2].coords.equals(&Coord3d::new([6., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(components[1].vertices_tags.get_registered_indexes("corner"), Some(&vec![2]));
+        assert_eq!(components[1].triangles_tags.get_registered_indexes("right"), Some(&vec![0]));
+
+        // Triangles touching at a single vertex are connected, although not through an edge.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([-1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., -1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 3, 4]));
+        assert_eq!(mesh.triangle_components(), vec![0, 1]);
+        let components = mesh.split_connected_components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].triangles.len(), 2);
+    }
+
+    #[test]
+    fn connected_components() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.append(&make_square([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([9., 9., 9.]));
+
+        assert_eq!(mesh.connected_components(), vec![0, 0, 0, 0, 1, 1, 1, 1, 2]);
+
+        let components = mesh.split_connected_components();
+        assert_eq!(components.len(), 2);
+        for component in &components {
+            assert_eq!(component.vertices.len(), 4);
+            assert_eq!(component.triangles_tags.get_registered_indexes("square"), Some(&vec![0, 1]));
+        }
+    }
+
+    #[test]
+    fn orient_outward() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        // Cube faces split into triangles with arbitrary orientations.
+        let faces = [[0, 1, 3, 2], [4, 6, 7, 5], [0, 4, 5, 1], [2, 3, 7, 6], [0, 2, 6, 4], [1, 5, 7, 3]];
+        for (f, q) in faces.iter().enumerate() {
+            mesh.triangles.push(Tri::new([q[0], q[1], q[2]]));
+            if f % 2 == 0 { mesh.triangles.push(Tri::new([q[0], q[2], q[3]])); }
+            else { mesh.triangles.push(Tri::new([q[0], q[3], q[2]])); }
+        }
+
+        let center = Pnt3d::new([0.5, 0.5, 0.5]);
+        mesh.orient_outward(&center);
+        for tri in &mesh.triangles {
+            let t = mesh.get_tri_view(tri);
+            let n = t.get_normal();
+            let outward = center.to(t.points[0]).coords.add_out(1.0, &center.to(t.points[2]).coords);
+            assert!(n.coords.dot(&outward) > 0.0);
+        }
+    }
+
+    #[test]
+    fn orient_consistently_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 3, 2]));
+
+        mesh.orient_consistently();
+        assert_eq!(mesh.triangles[0].indexes, [0, 1, 2]);
+        let n0 = mesh.get_tri_view(&mesh.triangles[0]).get_normal();
+        let n1 = mesh.get_tri_view(&mesh.triangles[1]).get_normal();
+        assert!((n0.coords.dot(&n1.coords) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn orient_consistently_1() {
+
+        let mut mesh = make_cube_surface();
+        for i in (1..mesh.triangles.len()).step_by(3) { mesh.triangles[i].indexes.swap(0, 1); }
+
+        mesh.orient_consistently();
+        let mut directed_edges = std::collections::HashSet::new();
+        for tri in &mesh.triangles {
+            for k in 0..3 { assert!(directed_edges.insert((tri.indexes[k], tri.indexes[(k + 1) % 3]))); }
+        }
+    }
+
+    // Building the closed surface of the unit cube with outward oriented triangles.
+    fn make_cube_surface() -> Mesh3d {
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        for q in &[[0, 2, 3, 1], [4, 5, 7, 6], [0, 1, 5, 4], [2, 6, 7, 3], [0, 4, 6, 2], [1, 3, 7, 5]] {
+            mesh.triangles.push(Tri::new([q[0], q[1], q[2]]));
+            mesh.triangles.push(Tri::new([q[0], q[2], q[3]]));
+        }
+        mesh
+    }
+
+    // Building a torus made of n x n quadrangles.
+    fn make_torus(n: usize) -> Mesh3d {
+        let mut mesh = Mesh3d::default();
+        for i in 0..n {
+            let u = 2.0 * std::f64::consts::PI * (i as f64) / (n as f64);
+            for j in 0..n {
+                let v = 2.0 * std::f64::consts::PI * (j as f64) / (n as f64);
+                let r = 2.0 + v.cos();
+                mesh.vertices.push(Pnt3d::new([r * u.cos(), r * u.sin(), v.sin()]));
+            }
+        }
+        for i in 0..n {
+            for j in 0..n {
+                let (i1, j1) = ((i + 1) % n, (j + 1) % n);
+                mesh.quadrangles.push(Quad::new([i * n + j, i1 * n + j, i1 * n + j1, i * n + j1]));
+            }
+        }
+        mesh
+    }
+
+    #[test]
+    fn euler_characteristic() {
+
+        assert_eq!(make_cube_surface().euler_characteristic(), 2);
+        assert_eq!(make_torus(6).euler_characteristic(), 0);
+    }
+
+    #[test]
+    fn is_watertight() {
+
+        let mut mesh = make_cube_surface();
+        assert!(mesh.is_watertight());
+        mesh.triangles.pop();
+        assert!(!mesh.is_watertight());
+        assert!(!Mesh3d::default().is_watertight());
+    }
+
+    #[test]
+    fn is_manifold() {
+
+        let mut mesh = make_cube_surface();
+        assert!(mesh.is_manifold());
+
+        // Second cube touching the first one at a single vertex.
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([1.0 + (k & 1) as f64, 1.0 + ((k >> 1) & 1) as f64, 1.0 + ((k >> 2) & 1) as f64])); }
+        let shifted: Vec<[usize; 3]> = mesh.triangles.iter()
+            .map(|t| { let mut i = t.indexes; for v in i.iter_mut() { *v = if *v == 0 { 7 } else { *v + 8 }; } i })
+            .collect();
+        for i in shifted { mesh.triangles.push(Tri::new(i)); }
+        assert!(mesh.is_watertight());
+        assert!(!mesh.is_manifold());
+        assert_eq!(mesh.genus(), None);
+    }
+
+    #[test]
+    fn non_manifold_edges() {
+
+        // Fan of three triangles around edge (0, 1).
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([-1., -1., 0.]));
+        mesh.triangles.push(Tri::new([2, 0, 1]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        assert!(mesh.non_manifold_edges().is_empty());
+
+        mesh.triangles.push(Tri::new([0, 1, 4]));
+        let edges = mesh.unique_edges();
+        let non_manifold = mesh.non_manifold_edges();
+        assert_eq!(non_manifold.len(), 1);
+        let mut indexes = edges[non_manifold[0]].indexes;
+        indexes.sort();
+        assert_eq!(indexes, [0, 1]);
+        assert!(make_cube_surface().non_manifold_edges().is_empty());
+    }
+
+    #[test]
+    fn genus_0() {
+
+        assert_eq!(make_cube_surface().genus(), Some(0));
+    }
+
+    #[test]
+    fn genus_1() {
+
+        assert_eq!(make_torus(8).genus(), Some(1));
+    }
+
+    #[test]
+    fn genus_2() {
+
+        let mut mesh = make_torus(8);
+        mesh.quadrangles.pop();
+        assert_eq!(mesh.genus(), None);
+    }
+
+    #[test]
+    fn local_edge_length() {
+
+        // Triangulated grid of 2 x 2 squares of size 0.5, the central vertex having four
+        // axis-aligned edges and two diagonal ones.
+        let mut mesh = Mesh3d::default();
+        for j in 0..3 {
+            for i in 0..3 { mesh.vertices.push(Pnt3d::new([0.5 * i as f64, 0.5 * j as f64, 0.])); }
+        }
+        mesh.vertices.push(Pnt3d::new([5., 5., 5.]));
+        for j in 0..2 {
+            for i in 0..2 {
+                let p = j * 3 + i;
+                mesh.triangles.push(Tri::new([p, p + 1, p + 4]));
+                mesh.triangles.push(Tri::new([p, p + 4, p + 3]));
+            }
+        }
+
+        let expected = (4.0 * 0.5 + 2.0 * 0.5 * 2f64.sqrt()) / 6.0;
+        match mesh.local_edge_length(4) {
+            Some(l) => { assert!((l - expected).abs() < GEOMETRICAL_TOLERANCE); },
+            None => { panic!(); }
+        }
+        assert!(mesh.local_edge_length(9).is_none());
+    }
+
+    #[test]
+    fn get_total_surface_area() {
+
+        // Unit square at z = 2, made of a quadrangle and of two triangles.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 2.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 2.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 2.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 2.]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+        assert!((mesh.get_total_surface_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+        assert!((mesh.get_total_surface_area() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((make_cube_surface().get_total_surface_area() - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_bounding_box() {
+
+        assert!(Mesh3d::default().get_bounding_box().is_none());
+        let mut mesh = make_cube_surface();
+        mesh.vertices.push(Pnt3d::new([-1., 0.5, 3.]));
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord3d::new([-1., 0., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord3d::new([1., 1., 3.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn bounding_box_0() {
+
+        assert!(Mesh3d::default().bounding_box().is_none());
+    }
+
+    #[test]
+    fn bounding_box_1() {
+
+        let mut mesh = make_cube_surface();
+        match mesh.bounding_box() {
+            Some((min, max)) => {
+                assert!(min.coords.equals(&Coord3d::new([0., 0., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(max.coords.equals(&Coord3d::new([1., 1., 1.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        mesh.vertices.push(Pnt3d::new([-1., 0.5, 3.]));
+        match mesh.bounding_box() {
+            Some((min, max)) => {
+                assert!(min.coords.equals(&Coord3d::new([-1., 0., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(max.coords.equals(&Coord3d::new([1., 1., 3.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn vertex_areas_0() {
+
+        let torus = make_torus(7);
+        let mut mesh = Mesh3d { vertices: torus.vertices.clone(), ..Mesh3d::default() };
+        for q in &torus.quadrangles {
+            mesh.triangles.push(Tri::new([q.indexes[0], q.indexes[1], q.indexes[2]]));
+            mesh.triangles.push(Tri::new([q.indexes[0], q.indexes[2], q.indexes[3]]));
+        }
+
+        let total = mesh.get_total_surface_area();
+        let barycentric = mesh.vertex_areas(false);
+        let mixed = mesh.vertex_areas(true);
+        assert_eq!(barycentric.len(), mesh.vertices.len());
+        assert!((barycentric.iter().sum::<f64>() - total).abs() < 1e-10);
+        assert!((mixed.iter().sum::<f64>() - total).abs() < 1e-10);
+    }
+
+    #[test]
+    fn vertex_areas_1() {
+
+        // Right isosceles triangle: the Voronoi region of the right angle vertex is half the area.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 2., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let mixed = mesh.vertex_areas(true);
+        assert!((mixed[0] - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mixed[1] - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mixed[2] - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+
+        // Obtuse triangle: half the area at the obtuse vertex and a quarter at the others.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0.2, 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let mixed = mesh.vertex_areas(true);
+        assert!((mixed[0] - 0.05).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mixed[1] - 0.05).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mixed[2] - 0.1).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_total_volume() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        mesh.vertices.push(Pnt3d::new([0., 0., 2.]));
+        mesh.hexahedra.push(Hexa::new([0, 1, 3, 2, 4, 5, 7, 6]));
+        mesh.tetrahedra.push(Tet::new([4, 5, 6, 8]));
+        assert!((mesh.get_total_volume(false) - (1.0 + 1.0 / 6.0)).abs() < GEOMETRICAL_TOLERANCE);
+
+        // Inverted tetrahedron.
+        mesh.tetrahedra.push(Tet::new([4, 6, 5, 8]));
+        assert!((mesh.get_total_volume(false) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_total_volume(true) - (1.0 + 2.0 / 6.0)).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn stats() {
+
+        assert_eq!(Mesh3d::default().stats(), MeshStats::default());
+
+        let mut mesh = Mesh3d::new_box(1, 1, 1, 1., 1., 1.);
+        mesh.tetrahedralize_hexas();
+        let stats = mesh.stats();
+        assert_eq!(stats.n_vertices, 8);
+        assert_eq!(stats.n_edges, 0);
+        assert_eq!(stats.n_triangles, 0);
+        assert_eq!(stats.n_quadrangles, 6);
+        assert_eq!(stats.n_tetrahedra, 6);
+        assert_eq!(stats.n_hexahedra, 0);
+        assert!((stats.surface_area - 6.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((stats.volume - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn translate_scale() {
+
+        let mut mesh = make_cube_surface();
+        mesh.translate(&Vec3d::new([1., -1., 0.])).scale(2.0);
+        match mesh.bounding_box() {
+            Some((min, max)) => {
+                assert!(min.coords.equals(&Coord3d::new([2., -2., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(max.coords.equals(&Coord3d::new([4., 0., 2.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn scale_about() {
+
+        let mut mesh = make_cube_surface();
+        mesh.scale_about(&Pnt3d::new([0.5, 0.5, 0.5]), 3.0).translate(&Vec3d::new([0., 0., 1.]));
+        match mesh.bounding_box() {
+            Some((min, max)) => {
+                assert!(min.coords.equals(&Coord3d::new([-1., -1., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(max.coords.equals(&Coord3d::new([2., 2., 3.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn rotate() {
+
+        let mut mesh = make_cube_surface();
+        let area = mesh.get_total_surface_area();
+        let axis = Vec3d::new([1., 1., 0.]).normalize_out();
+        mesh.rotate(&axis, 0.3, &Pnt3d::new([0.5, 0., 2.])).rotate(&axis, -0.3, &Pnt3d::new([0.5, 0., 2.]));
+        assert!(mesh.vertices[7].coords.equals(&Coord3d::new([1., 1., 1.]), 1e-10));
+
+        mesh.rotate(&axis, 1.2, &Pnt3d::new([0.5, 0., 2.]));
+        assert!((mesh.get_total_surface_area() - area).abs() < 1e-10);
+        for t in &mesh.triangles { assert!((mesh.get_tri_view(t).get_area() - 0.5).abs() < 1e-10); }
+
+        let mut mesh = make_cube_surface();
+        mesh.rotate(&Vec3d::new([0., 0., 1.]).normalize_out(), 0.5 * std::f64::consts::PI, &Pnt3d::new([0., 0., 0.]));
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord3d::new([-1., 0., 0.]), 1e-10));
+                assert!(bbox.max.equals(&Coord3d::new([0., 1., 1.]), 1e-10));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn apply_transform() {
+
+        let mut mesh = make_cube_surface();
+        let t = Transform3d::new(Mat3::new([[2., 0., 0.], [0., 1., 0.], [0., 0., 1.]]), Vec3d::new([0., 0., -1.]));
+        mesh.apply_transform(&t);
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord3d::new([0., 0., -1.]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord3d::new([2., 1., 0.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+        match t.inverse() {
+            Some(inv) => { mesh.apply_transform(&inv); },
+            None => { panic!(); }
+        }
+        assert!(mesh.vertices[7].coords.equals(&Coord3d::new([1., 1., 1.]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn scale_about_area() {
+
+        let mut mesh = make_cube_surface();
+        let area = mesh.get_total_surface_area();
+        mesh.scale_about(&Pnt3d::new([0.2, -1., 3.]), 1.5);
+        assert!((mesh.get_total_surface_area() - 1.5 * 1.5 * area).abs() < 1e-10);
+    }
+
+    #[test]
+    fn to_json() {
+
+        let mut mesh = make_cube_surface();
+        mesh.push_tagged_edge(Edge::new([0, 1]), "feature");
+        mesh.quadrangles.push(Quad::new([0, 1, 3, 2]));
+
+        for &pretty in &[true, false] {
+            match mesh.to_json(pretty) {
+                Ok(json) => {
+                    assert_eq!(json.contains('\n'), pretty);
+                    match Mesh3d::from_json(&json) {
+                        Ok(copy) => {
+                            assert_eq!(copy.vertices.len(), 8);
+                            assert_eq!(copy.edges.len(), 1);
+                            assert_eq!(copy.triangles.len(), 12);
+                            assert_eq!(copy.quadrangles.len(), 1);
+                            assert_eq!(copy.edges_tags.get_registered_indexes("feature"), Some(&vec![0]));
+                        },
+                        Err(_) => { panic!(); }
+                    }
+                },
+                Err(_) => { panic!(); }
+            }
+        }
+        assert!(Mesh3d::from_json("{ \"vertices\": 3 }").is_err());
+    }
+
+    #[test]
+    fn remove_triangles_by_tag() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..6 { mesh.vertices.push(Pnt3d::new([k as f64, (k % 2) as f64, 0.])); }
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "left");
+        mesh.push_tagged_triangle(Tri::new([1, 2, 3]), "right");
+        mesh.push_tagged_triangle(Tri::new([2, 3, 4]), "left");
+        mesh.push_tagged_triangle(Tri::new([3, 4, 5]), "right");
+        mesh.triangles_tags.register("all", 1);
+        mesh.triangles_tags.register("all", 2);
+
+        assert_eq!(mesh.remove_triangles_by_tag("missing"), 0);
+        assert_eq!(mesh.remove_triangles_by_tag("left"), 2);
+        assert_eq!(mesh.triangles.len(), 2);
+        assert!(mesh.triangles_tags.get_registered_indexes("left").is_none());
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("all"), Some(&vec![0]));
+        match mesh.triangles_tags.get_registered_indexes("right") {
+            Some(indexes) => {
+                assert_eq!(indexes, &vec![0, 1]);
+                assert_eq!(mesh.triangles[indexes[0]].indexes, [1, 2, 3]);
+                assert_eq!(mesh.triangles[indexes[1]].indexes, [3, 4, 5]);
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn remove_triangles() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..6 { mesh.vertices.push(Pnt3d::new([k as f64, (k % 2) as f64, 0.])); }
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.push_tagged_triangle(Tri::new([1, 2, 3]), "middle");
+        mesh.triangles.push(Tri::new([2, 3, 4]));
+        mesh.push_tagged_triangle(Tri::new([3, 4, 5]), "last");
+
+        assert_eq!(mesh.remove_triangles(&[0, 2, 9]), vec![None, Some(0), None, Some(1)]);
+        assert_eq!(mesh.triangles.len(), 2);
+        match mesh.triangles_tags.get_registered_indexes("last") {
+            Some(indexes) => { assert_eq!(mesh.triangles[indexes[0]].indexes, [3, 4, 5]); },
+            None => { panic!(); }
+        }
+
+        assert_eq!(mesh.remove_triangles(&[0]), vec![None, Some(0)]);
+        assert!(mesh.triangles_tags.get_registered_indexes("middle").is_none());
+    }
+
+    #[test]
+    fn remove_vertices() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..5 { mesh.vertices.push(Pnt3d::new([k as f64, (k % 2) as f64, 0.])); }
+        mesh.push_tagged_vertex(Pnt3d::new([9., 9., 9.]), "isolated");
+        mesh.vertices_tags.register("corner", 4);
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "first");
+        mesh.push_tagged_triangle(Tri::new([2, 3, 4]), "second");
+        mesh.push_tagged_edge(Edge::new([0, 4]), "edge");
+
+        assert_eq!(mesh.remove_vertices(&[1], false), Err(MeshError::VertexInUse { index: 1 }));
+        assert_eq!(mesh.vertices.len(), 6);
+        assert_eq!(mesh.triangles.len(), 2);
+
+        assert_eq!(mesh.remove_vertices(&[5], false), Ok(vec![Some(0), Some(1), Some(2), Some(3), Some(4), None]));
+        assert!(mesh.vertices_tags.get_registered_indexes("isolated").is_none());
+
+        assert_eq!(mesh.remove_vertices(&[1], true), Ok(vec![Some(0), None, Some(1), Some(2), Some(3)]));
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.triangles.len(), 1);
+        assert_eq!(mesh.triangles[0].indexes, [1, 2, 3]);
+        assert_eq!(mesh.edges[0].indexes, [0, 3]);
+        assert!(mesh.triangles_tags.get_registered_indexes("first").is_none());
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("second"), Some(&vec![0]));
+        assert_eq!(mesh.vertices_tags.get_registered_indexes("corner"), Some(&vec![3]));
+    }
+
+    #[test]
+    fn project_points() {
+
+        // Flat square patch at z = 1.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+
+        let points = vec![Pnt3d::new([0.75, 0.25, 3.]), Pnt3d::new([0.25, 0.75, -1.]), Pnt3d::new([2., 0.5, 1.])];
+        let projections = mesh.project_points(&points);
+        assert_eq!(projections.len(), 3);
+        assert_eq!(projections[0].0, 0);
+        assert!(projections[0].1.coords.equals(&Coord3d::new([0.75, 0.25, 1.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(projections[1].0, 1);
+        assert!(projections[1].1.coords.equals(&Coord3d::new([0.25, 0.75, 1.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(projections[2].0, 0);
+        assert!(projections[2].1.coords.equals(&Coord3d::new([1., 0.5, 1.]), GEOMETRICAL_TOLERANCE));
+
+        assert!(Mesh3d::default().project_points(&points).is_empty());
+    }
+
+    #[test]
+    fn dual_graph_0() {
+
+        // Unit square split in two triangles.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+
+        let (xadj, adjncy) = mesh.dual_graph();
+        assert_eq!(xadj, vec![0, 1, 2]);
+        assert_eq!(adjncy, vec![1, 0]);
+    }
+
+    #[test]
+    fn triangle_components() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..7 { mesh.vertices.push(Pnt3d::new([k as f64, (k % 2) as f64, 0.])); }
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([3, 4, 5]));
+        mesh.triangles.push(Tri::new([1, 2, 3]));
+        mesh.triangles.push(Tri::new([5, 4, 6]));
+
+        // Triangles sharing a single vertex are not connected.
+        assert_eq!(mesh.triangle_components(), vec![0, 1, 0, 1]);
+
+        mesh.triangles.swap(1, 2);
+        assert_eq!(mesh.triangle_components(), vec![0, 0, 1, 1]);
+        assert!(Mesh3d::default().triangle_components().is_empty());
+    }
+
+    #[test]
+    fn tag_connected_components() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..6 { mesh.vertices.push(Pnt3d::new([k as f64, (k % 2) as f64, 0.])); }
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([3, 4, 5]));
+
+        assert_eq!(mesh.tag_connected_components("part"), 2);
+        assert_eq!(*mesh.triangles_tags.get_registered_indexes("part_0").unwrap(), vec![0]);
+        assert_eq!(*mesh.triangles_tags.get_registered_indexes("part_1").unwrap(), vec![1]);
+        assert!(mesh.triangles_tags.get_registered_indexes("part_2").is_none());
+        assert_eq!(Mesh3d::default().tag_connected_components("part"), 0);
+    }
+
+    #[test]
+    fn dual_graph_1() {
+
+        let (xadj, adjncy) = make_cube_surface().dual_graph();
+        assert_eq!(xadj.len(), 13);
+        assert_eq!(adjncy.len(), 36);
+        for i in 0..12 {
+            assert_eq!(xadj[i + 1] - xadj[i], 3);
+            for &j in &adjncy[xadj[i]..xadj[i + 1]] {
+                assert!(adjncy[xadj[j]..xadj[j + 1]].contains(&i));
+            }
+        }
+
+        let (xadj, adjncy) = Mesh3d::default().dual_graph();
+        assert_eq!(xadj, vec![0]);
+        assert!(adjncy.is_empty());
+    }
+
+    #[test]
+    fn reorder_vertices_morton() {
+
+        // Grid of 4 x 4 vertices pushed in a scrambled order.
+        let mut mesh = Mesh3d::default();
+        for k in 0..16 {
+            let v = (7 * k) % 16;
+            mesh.push_tagged_vertex(Pnt3d::new([(v % 4) as f64, (v / 4) as f64, 0.]), if v == 5 { "corner" } else { "grid" });
+        }
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.quadrangles.push(Quad::new([3, 4, 5, 6]));
+        let before: Vec<Coord3d> = mesh.triangles[0].indexes.iter().chain(&mesh.quadrangles[0].indexes)
+            .map(|&v| mesh.vertices[v].coords.clone()).collect();
+
+        let permutation = mesh.reorder_vertices_morton();
+        assert_eq!(permutation.len(), 16);
+
+        // Geometry is unchanged.
+        let after: Vec<Coord3d> = mesh.triangles[0].indexes.iter().chain(&mesh.quadrangles[0].indexes)
+            .map(|&v| mesh.vertices[v].coords.clone()).collect();
+        for (a, b) in before.iter().zip(&after) { assert!(a.equals(b, GEOMETRICAL_TOLERANCE)); }
+
+        // Each block of four consecutive vertices fills a quadrant of the grid.
+        for block in mesh.vertices.chunks(4) {
+            let (qx, qy) = ((block[0].coords.x / 2.0).floor(), (block[0].coords.y / 2.0).floor());
+            for p in block {
+                assert_eq!((p.coords.x / 2.0).floor(), qx);
+                assert_eq!((p.coords.y / 2.0).floor(), qy);
+            }
+        }
+        assert!(mesh.vertices[0].coords.equals(&Coord3d::new([0., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!(mesh.vertices[15].coords.equals(&Coord3d::new([3., 3., 0.]), GEOMETRICAL_TOLERANCE));
+
+        // Vertex tags follow the new numbering.
+        match mesh.vertices_tags.get_registered_indexes("corner") {
+            Some(indexes) => {
+                assert_eq!(indexes.len(), 1);
+                assert!(mesh.vertices[indexes[0]].coords.equals(&Coord3d::new([1., 1., 0.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        assert!(Mesh3d::default().reorder_vertices_morton().is_empty());
+    }
+
+    #[test]
+    fn cotangent_laplacian_triplets_0() {
+
+        // Right isosceles triangle, with its right angle at the first vertex.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let triplets = mesh.cotangent_laplacian_triplets();
+        let expected = [
+            (0, 0, -1.0), (0, 1, 0.5), (0, 2, 0.5),
+            (1, 0, 0.5), (1, 1, -0.5), (1, 2, 0.0),
+            (2, 0, 0.5), (2, 1, 0.0), (2, 2, -0.5)
+        ];
+        assert_eq!(triplets.len(), expected.len());
+        for (t, e) in triplets.iter().zip(&expected) {
+            assert_eq!((t.0, t.1), (e.0, e.1));
+            assert!((t.2 - e.2).abs() < GEOMETRICAL_TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn cotangent_laplacian_triplets_1() {
+
+        // Rows sum to zero and the matrix is symmetric.
+        let mesh = make_cube_surface();
+        let triplets = mesh.cotangent_laplacian_triplets();
+        let mut row_sums = vec![0.0; mesh.vertices.len()];
+        for &(i, j, w) in &triplets {
+            row_sums[i] += w;
+            let transposed = triplets.iter().find(|t| t.0 == j && t.1 == i);
+            match transposed {
+                Some(t) => assert!((t.2 - w).abs() < GEOMETRICAL_TOLERANCE),
+                None => { panic!(); }
+            }
+        }
+        for s in row_sums { assert!(s.abs() < GEOMETRICAL_TOLERANCE); }
+    }
+
+    #[test]
+    fn laplacian_0() {
+
+        let mesh = make_torus(8);
+        for l in mesh.laplacian(&vec![3.0; mesh.vertices.len()]) { assert!(l.abs() < GEOMETRICAL_TOLERANCE); }
+    }
+
+    #[test]
+    fn laplacian_1() {
+
+        // Matching the product of the assembled matrix with the field.
+        let mesh = make_cube_surface();
+        let field: Vec<f64> = mesh.vertices.iter().map(|p| p.coords.x * p.coords.x - 2.0 * p.coords.z).collect();
+        let mut expected = vec![0.0; field.len()];
+        for (i, j, w) in mesh.cotangent_laplacian_triplets() { expected[i] += w * field[j]; }
+        for (l, e) in mesh.laplacian(&field).iter().zip(&expected) { assert!((l - e).abs() < GEOMETRICAL_TOLERANCE); }
+    }
+
+    #[test]
+    #[should_panic]
+    fn laplacian_2() {
+
+        make_cube_surface().laplacian(&[1.0]);
+    }
+
+    #[test]
+    fn merge_duplicate_vertices_0() {
+
+        // Triangle soup of two triangles sharing an edge through duplicated vertices.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([1e-9, -1e-9, 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1. + 1e-9, 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([3, 4, 5]));
+        mesh.push_tagged_vertex(Pnt3d::new([2., 0., 0.]), "free");
+        mesh.vertices_tags.register("corner", 4);
+
+        let (_, adjncy) = mesh.dual_graph();
+        assert!(adjncy.is_empty());
+
+        assert_eq!(mesh.merge_duplicate_vertices(1e-6), 2);
+        assert_eq!(mesh.vertices.len(), 5);
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 3]);
+        let (xadj, adjncy) = mesh.dual_graph();
+        assert_eq!(xadj, vec![0, 1, 2]);
+        assert_eq!(adjncy, vec![1, 0]);
+
+        match mesh.vertices_tags.get_registered_indexes("corner") {
+            Some(indexes) => assert_eq!(*indexes, vec![2]),
+            None => { panic!(); }
+        }
+        match mesh.vertices_tags.get_registered_indexes("free") {
+            Some(indexes) => assert_eq!(*indexes, vec![4]),
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn merge_duplicate_vertices_1() {
+
+        // Close vertices across a cell boundary, and vertices just beyond the threshold.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0.99995, 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1.00005, 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0.0002, 0.]));
+        assert_eq!(mesh.merge_duplicate_vertices(1e-4), 0);
+        assert_eq!(mesh.merge_duplicate_vertices(0.0), 0);
+        assert_eq!(mesh.merge_duplicate_vertices(1.5e-4), 1);
+        assert_eq!(mesh.vertices.len(), 3);
+
+        // Cells saturating the integer range for large coordinates and a tiny threshold.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([1e8, -1e8, 0.]));
+        mesh.vertices.push(Pnt3d::new([1e8, -1e8, 0.]));
+        mesh.vertices.push(Pnt3d::new([1e8, -1e8 + 1., 1e8]));
+        assert_eq!(mesh.merge_duplicate_vertices(1e-12), 1);
+        assert_eq!(mesh.vertices.len(), 2);
+
+        // Merging is not transitive, vertices being merged onto the first vertex in range.
+        let mut cube = make_cube_surface();
+        assert_eq!(cube.merge_duplicate_vertices(0.5), 0);
+        assert_eq!(cube.merge_duplicate_vertices(1.1), 4);
+        assert_eq!(cube.vertices.len(), 4);
+    }
+
+    #[test]
+    fn boundary_length() {
+
+        // Open unit square patch.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+        assert!((mesh.boundary_length() - 4.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        // Closed surface of a tetrahedron.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.triangles.push(Tri::new([0, 2, 1]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        mesh.triangles.push(Tri::new([1, 2, 3]));
+        mesh.triangles.push(Tri::new([0, 3, 2]));
+        assert_eq!(mesh.boundary_length(), 0.0);
+
+        assert_eq!(Mesh3d::default().boundary_length(), 0.0);
+    }
+
+    #[test]
+    fn remove_unused_vertices_0() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.push_tagged_vertex(Pnt3d::new([5., 5., 5.]), "orphan");
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.push_tagged_vertex(Pnt3d::new([1., 0., 0.]), "corner");
+        mesh.vertices.push(Pnt3d::new([6., 6., 6.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices_tags.register("orphan", 3);
+        mesh.triangles.push(Tri::new([1, 2, 4]));
+        mesh.edges.push(Edge::new([4, 1]));
+        let before: Vec<Coord3d> = mesh.get_tri_view(&mesh.triangles[0]).points.iter().map(|p| p.coords.clone()).collect();
+
+        assert_eq!(mesh.remove_unused_vertices(), vec![0, 3]);
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.triangles[0].indexes, [0, 1, 2]);
+        assert_eq!(mesh.edges[0].indexes, [2, 0]);
+        let after = mesh.get_tri_view(&mesh.triangles[0]);
+        for (a, b) in after.points.iter().zip(&before) { assert!(a.coords.equals(b, GEOMETRICAL_TOLERANCE)); }
+
+        assert!(mesh.vertices_tags.get_registered_indexes("orphan").is_none());
+        match mesh.vertices_tags.get_registered_indexes("corner") {
+            Some(indexes) => assert_eq!(*indexes, vec![1]),
+            None => { panic!(); }
+        }
+        assert!(mesh.remove_unused_vertices().is_empty());
+    }
+
+    #[test]
+    fn remove_unused_vertices_1() {
+
+        // Mesh without elements.
+        let mut mesh = Mesh3d::default();
+        mesh.push_tagged_vertex(Pnt3d::new([0., 0., 0.]), "point");
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        assert_eq!(mesh.remove_unused_vertices(), vec![0, 1]);
+        assert!(mesh.vertices.is_empty());
+        assert!(mesh.vertices_tags.get_registered_indexes("point").is_none());
+    }
+
+    // Building a unit square made of two tagged triangles, with a lower left corner.
+    fn make_square(corner: [f64; 3]) -> Mesh3d {
+        let mut mesh = Mesh3d::default();
+        for &(x, y) in &[(0., 0.), (1., 0.), (1., 1.), (0., 1.)] {
+            mesh.vertices.push(Pnt3d::new([corner[0] + x, corner[1] + y, corner[2]]));
+        }
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "square");
+        mesh.push_tagged_triangle(Tri::new([0, 2, 3]), "square");
+        mesh.push_tagged_edge(Edge::new([0, 1]), "bottom");
+        mesh
+    }
+
+    #[test]
+    fn append() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.append(&make_square([2., 0., 0.]));
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 4);
+        assert_eq!(mesh.edges.len(), 2);
+        assert_eq!(mesh.triangles[3].indexes, [4, 6, 7]);
+        match mesh.triangles_tags.get_registered_indexes("square") {
+            Some(indexes) => assert_eq!(*indexes, vec![0, 1, 2, 3]),
+            None => { panic!(); }
+        }
+
+        let t = mesh.get_tri_view(&mesh.triangles[2]);
+        assert!(t.points[1].coords.equals(&Coord3d::new([3., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!((t.get_area() - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        let e = mesh.get_edge_view(&mesh.edges[1]);
+        assert!(e.points[0].coords.equals(&Coord3d::new([2., 0., 0.]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn append_with_prefix() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.append_with_prefix(&make_square([0., 0., 1.]), "top/");
+
+        assert_eq!(mesh.vertices.len(), 8);
+        match mesh.triangles_tags.get_registered_indexes("square") {
+            Some(indexes) => assert_eq!(*indexes, vec![0, 1]),
+            None => { panic!(); }
+        }
+        match mesh.triangles_tags.get_registered_indexes("top/square") {
+            Some(indexes) => assert_eq!(*indexes, vec![2, 3]),
+            None => { panic!(); }
+        }
+        match mesh.edges_tags.get_registered_indexes("top/bottom") {
+            Some(indexes) => assert_eq!(*indexes, vec![1]),
+            None => { panic!(); }
+        }
+        assert!((mesh.get_total_surface_area() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn extract_by_tag() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.push_tagged_triangle(Tri::new([1, 2, 3]), "single");
+        mesh.vertices_tags.register("corner", 3);
+        mesh.vertices_tags.register("origin", 0);
+
+        let sub = mesh.extract_by_tag(ElementKind::Tri, "single");
+        assert_eq!(sub.vertices.len(), 3);
+        assert_eq!(sub.triangles.len(), 1);
+        assert_eq!(sub.triangles[0].indexes, [0, 1, 2]);
+        assert!(sub.vertices[2].coords.equals(&Coord3d::new([0., 1., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!((sub.get_total_surface_area() - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        match sub.triangles_tags.get_registered_indexes("single") {
+            Some(indexes) => assert_eq!(*indexes, vec![0]),
+            None => { panic!(); }
+        }
+        match sub.vertices_tags.get_registered_indexes("corner") {
+            Some(indexes) => assert_eq!(*indexes, vec![2]),
+            None => { panic!(); }
+        }
+        assert!(sub.vertices_tags.get_registered_indexes("origin").is_none());
+
+        let sub = mesh.extract_by_tag(ElementKind::Edge, "bottom");
+        assert_eq!(sub.vertices.len(), 2);
+        assert_eq!(sub.edges.len(), 1);
+        assert!(sub.triangles.is_empty());
+
+        let sub = mesh.extract_by_tag(ElementKind::Quad, "square");
+        assert!(sub.vertices.is_empty());
+        assert!(sub.quadrangles.is_empty());
+    }
+
+    #[test]
+    fn weld_vertices() {
+
+        // Unit square whose second triangle uses a duplicate of the first corner.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.push_tagged_vertex(Pnt3d::new([0., 1e-8, 0.]), "duplicate");
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([4, 2, 3]));
+
+        assert_eq!(mesh.weld_vertices(1e-12), 1);
+        assert_eq!(mesh.vertices.len(), 4);
+        assert!(mesh.vertices[0].coords.equals(&Coord3d::new([0., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 3]);
+        match mesh.vertices_tags.get_registered_indexes("duplicate") {
+            Some(indexes) => assert_eq!(*indexes, vec![0]),
+            None => { panic!(); }
+        }
+        assert!(mesh.is_manifold());
+        assert_eq!(mesh.weld_vertices(1e-12), 0);
+
+        // The threshold bounds the square distance between welded vertices.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([1e8, 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1e8, 1e-3, 0.]));
+        assert_eq!(mesh.weld_vertices(1e-30), 0);
+        assert_eq!(mesh.weld_vertices(0.0), 0);
+        assert_eq!(mesh.weld_vertices(0.9e-6), 0);
+        assert_eq!(mesh.weld_vertices(1.1e-6), 1);
+        assert_eq!(mesh.vertices.len(), 1);
+    }
+
+    #[test]
+    fn num_boundary_loops() {
+
+        // Grid of 3 x 3 unit quadrangles, with or without its central quadrangle.
+        let make_grid = |with_hole: bool| {
+            let mut mesh = Mesh3d::default();
+            for j in 0..4 {
+                for i in 0..4 { mesh.vertices.push(Pnt3d::new([i as f64, j as f64, 0.])); }
+            }
+            for j in 0..3 {
+                for i in 0..3 {
+                    if with_hole && i == 1 && j == 1 { continue; }
+                    let v = 4 * j + i;
+                    mesh.quadrangles.push(Quad::new([v, v + 1, v + 5, v + 4]));
+                }
+            }
+            mesh
+        };
+
+        assert_eq!(make_grid(false).num_boundary_loops(), 1);
+        assert_eq!(make_grid(true).num_boundary_loops(), 2);
+        assert_eq!(make_cube_surface().num_boundary_loops(), 0);
+        assert_eq!(make_square([0., 0., 0.]).num_boundary_loops(), 1);
+    }
+
+    #[test]
+    fn extract_boundary_faces_0() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+
+        let (tris, quads) = mesh.extract_boundary_faces();
+        assert_eq!(tris.len(), 4);
+        assert!(quads.is_empty());
+
+        // Sharing face (1, 2, 3) with a second tetrahedron removes it from the boundary.
+        mesh.tetrahedra.push(Tet::new([1, 2, 3, 4]));
+
+        let (tris, _) = mesh.extract_boundary_faces();
+        assert_eq!(tris.len(), 6);
+        for tri in &tris {
+            let mut key = tri.indexes.to_vec();
+            key.sort();
+            assert_ne!(key, vec![1, 2, 3]);
+        }
+
+        // Checking that faces point outward of their tetrahedron.
+        for (k, tri) in tris.iter().enumerate() {
+            let tet = &mesh.tetrahedra[k / 3];
+            let view = mesh.get_tri_view(tri);
+            let outward = view.get_barycenter() - mesh.get_tet_view(tet).get_barycenter();
+            assert!(view.get_normal().coords.dot(&outward.coords) > 0.);
+        }
+    }
+
+    #[test]
+    fn extract_boundary_faces_1() {
+
+        let mut mesh = Mesh3d::default();
+
+        for k in 0..3 {
+            mesh.vertices.push(Pnt3d::new([0., 0., k as f64]));
+            mesh.vertices.push(Pnt3d::new([1., 0., k as f64]));
+            mesh.vertices.push(Pnt3d::new([1., 1., k as f64]));
+            mesh.vertices.push(Pnt3d::new([0., 1., k as f64]));
+        }
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+        mesh.hexahedra.push(Hexa::new([4, 5, 6, 7, 8, 9, 10, 11]));
+
+        let (tris, quads) = mesh.extract_boundary_faces();
+        assert!(tris.is_empty());
+        assert_eq!(quads.len(), 10);
+
+        let center = Pnt3d::new([0.5, 0.5, 1.]);
+        for quad in &quads {
+            let view = mesh.get_quad_view(quad);
+            let outward = view.get_barycenter() - center.clone();
+            assert!(view.get_tri_view(TriInQuad::Tri013).get_normal().coords.dot(&outward.coords) > 0.);
+        }
+
+        mesh.tag_boundary("boundary");
+        assert_eq!(mesh.quadrangles.len(), 10);
+        match mesh.quadrangles_tags.get_registered_indexes("boundary") {
+            Some(indexes) => { assert_eq!(indexes.len(), 10); },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn triangulate_quads() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 1., 0.]));
+        mesh.push_tagged_quadrangle(Quad::new([1, 4, 5, 2]), "quad");
+        let area = mesh.get_total_surface_area();
+
+        mesh.triangulate_quads();
+        assert!(mesh.quadrangles.is_empty());
+        assert!(mesh.quadrangles_tags.get_registered_indexes("quad").is_none());
+        assert_eq!(mesh.triangles.len(), 4);
+        assert_eq!(mesh.triangles[2].indexes, [1, 4, 2]);
+        assert_eq!(mesh.triangles[3].indexes, [4, 5, 2]);
+        assert!((mesh.get_total_surface_area() - area).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("square"), Some(&vec![0, 1]));
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("quad"), Some(&vec![2, 3]));
+    }
+
+    #[test]
+    fn triangulate_quads_best_diagonal() {
+
+        // Skewed quadrangle, whose diagonal (P0, P2) is much shorter than (P1, P3).
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([-1., 1., 0.]));
+        mesh.push_tagged_quadrangle(Quad::new([0, 1, 2, 3]), "skewed");
+        mesh.quadrangles.push(Quad::new([1, 2, 3, 0]));
+
+        mesh.triangulate_quads_best_diagonal();
+        assert!(mesh.quadrangles.is_empty());
+        assert_eq!(mesh.triangles.len(), 4);
+        assert_eq!(mesh.triangles[0].indexes, [0, 1, 2]);
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 3]);
+        assert_eq!(mesh.triangles[2].indexes, [1, 2, 0]);
+        assert_eq!(mesh.triangles[3].indexes, [2, 3, 0]);
+        assert!((mesh.get_total_surface_area() - 4.).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("skewed"), Some(&vec![0, 1]));
+    }
+
+    #[test]
+    fn tetrahedralize_hexas() {
+
+        let mut mesh = Mesh3d::default();
+        for k in 0..3 {
+            mesh.vertices.push(Pnt3d::new([0., 0., k as f64]));
+            mesh.vertices.push(Pnt3d::new([1., 0., k as f64]));
+            mesh.vertices.push(Pnt3d::new([1., 1., k as f64]));
+            mesh.vertices.push(Pnt3d::new([0., 1., k as f64]));
+        }
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+        mesh.push_tagged_hexahedron(Hexa::new([4, 5, 6, 7, 8, 9, 10, 11]), "top");
+
+        mesh.tetrahedralize_hexas();
+        assert!(mesh.hexahedra.is_empty());
+        assert_eq!(mesh.tetrahedra.len(), 12);
+        for tet in &mesh.tetrahedra { assert!(mesh.get_tet_view(tet).get_signed_volume() > 0.); }
+        assert!((mesh.get_total_volume(false) - 2.).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.tetrahedra_tags.get_registered_indexes("top").map(|i| i.len()), Some(6));
+
+        // Conforming split: the common face is interior, leaving two triangles per outer face.
+        let (tris, _) = mesh.extract_boundary_faces();
+        assert_eq!(tris.len(), 20);
+
+        // The volume of a hexahedron with non-planar faces is the one of its tetrahedra.
+        let mut mesh = Mesh3d::default();
+        for k in 0..8 { mesh.vertices.push(Pnt3d::new([(k & 1) as f64, ((k >> 1) & 1) as f64, ((k >> 2) & 1) as f64])); }
+        mesh.vertices[6] = Pnt3d::new([0.2, 1.3, 1.1]);
+        mesh.hexahedra.push(Hexa::new([0, 1, 3, 2, 4, 5, 7, 6]));
+        let volume = mesh.get_total_volume(false);
+        mesh.tetrahedralize_hexas();
+        assert!((mesh.get_total_volume(false) - volume).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn refine_triangles_uniform_0() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        assert_eq!(mesh.refine_triangles_uniform(), Ok(()));
+        assert_eq!(mesh.vertices.len(), 9);
+        assert_eq!(mesh.triangles.len(), 8);
+        assert_eq!(mesh.edges.len(), 2);
+        assert!(mesh.vertices[mesh.edges[0].indexes[1]].coords.equals(&Coord3d::new([0.5, 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(mesh.edges_tags.get_registered_indexes("bottom").map(|i| i.len()), Some(2));
+
+        assert_eq!(mesh.refine_triangles_uniform(), Ok(()));
+        assert_eq!(mesh.vertices.len(), 25);
+        assert_eq!(mesh.triangles.len(), 32);
+        assert!((mesh.get_total_surface_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(mesh.non_manifold_edges().is_empty());
+        assert!((mesh.boundary_length() - 4.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("square").map(|i| i.len()), Some(32));
+        assert_eq!(mesh.edges_tags.get_registered_indexes("bottom").map(|i| i.len()), Some(4));
+    }
+
+    #[test]
+    fn refine_triangles_uniform_1() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.triangles.push(Tri::new([0, 1, 4]));
+        assert_eq!(mesh.refine_triangles_uniform(), Ok(()));
+
+        mesh.triangles.clear();
+        mesh.triangles.push(Tri::new([1, 3, 0]));
+        assert_eq!(mesh.refine_triangles_uniform(), Err(MeshError::VolumeElementFace { index: 0 }));
+        assert_eq!(mesh.triangles.len(), 1);
+    }
+
+    #[test]
+    fn selection_from_tag() {
+
+        let mut mesh = make_square([0., 0., 0.]);
+        mesh.push_tagged_vertex(Pnt3d::new([2., 0., 0.]), "group");
+        mesh.push_tagged_triangle(Tri::new([1, 4, 2]), "group");
+
+        let selection = mesh.selection_from_tag("group");
+        assert_eq!(selection.vertices, vec![4]);
+        assert_eq!(selection.triangles, vec![2]);
+        assert!(selection.edges.is_empty());
+        assert!(selection.quadrangles.is_empty());
+        assert!(selection.tetrahedra.is_empty());
+        assert!(selection.hexahedra.is_empty());
+        assert_eq!(mesh.selection_from_tag("bottom").edges, vec![0]);
+        assert_eq!(mesh.selection_from_tag("missing"), Selection::default());
+    }
+
+    #[test]
+    fn degenerate_elements() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0.5, 0.8, 0.]));
+        mesh.vertices.push(Pnt3d::new([0.5, 1e-9, 0.]));
+        mesh.vertices.push(Pnt3d::new([10., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([10., 0.5, 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+        mesh.triangles.push(Tri::new([0, 4, 5]));
+
+        let report = mesh.degenerate_elements(1e-6, 5.);
+        assert_eq!(report.zero_area, vec![1]);
+        assert_eq!(report.high_aspect_ratio, vec![2]);
+        assert_eq!(make_cube_surface().degenerate_elements(1e-6, 5.), DegenerateReport::default());
+    }
+
+    #[test]
+    fn locate_point_in_tets() {
+
+        let mut mesh = Mesh3d::new_box(2, 2, 2, 1., 1., 1.);
+        mesh.tetrahedralize_hexas();
+        // Lowest index of the tetrahedra using all the given vertices.
+        let lowest = |vertices: &[usize]| mesh.tetrahedra.iter().position(|t| vertices.iter().all(|v| t.indexes.contains(v)));
+
+        for (i, tet) in mesh.tetrahedra.iter().enumerate() {
+            assert_eq!(mesh.locate_point_in_tets(&mesh.get_tet_view(tet).get_barycenter()), Some(i));
+            for e in &Tet::EDGES {
+                let (a, b) = (tet.indexes[e[0]], tet.indexes[e[1]]);
+                let midpoint = mesh.vertices[a].midpoint_with(&mesh.vertices[b]);
+                assert_eq!(mesh.locate_point_in_tets(&midpoint), lowest(&[a, b]));
+            }
+        }
+        for v in 0..mesh.vertices.len() { assert_eq!(mesh.locate_point_in_tets(&mesh.vertices[v]), lowest(&[v])); }
+
+        assert!(mesh.locate_point_in_tets(&Pnt3d::new([1.5, 0.5, 0.5])).is_none());
+        assert!(mesh.locate_point_in_tets(&Pnt3d::new([0.5, 0.5, -0.01])).is_none());
+        assert!(Mesh3d::default().locate_point_in_tets(&Pnt3d::new([0., 0., 0.])).is_none());
+    }
+}
+
+mod mesh2d {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+
+    #[test]
+    fn push_tagged_vertex() {
+
+        let mut mesh = Mesh2d::default();
+
+        mesh.vertices.push(Pnt2d::new([0.1, 2.6]));
+        mesh.push_tagged_vertex(Pnt2d::new([0.2, 1.6]), "tag");
+
+        assert!(mesh.vertices[1].coords.equals(&Coord2d::new([0.2, 1.6]), GEOMETRICAL_TOLERANCE));
+        match mesh.vertices_tags.get_registered_indexes("tag") {
+            Some(indexes) => { assert_eq!(indexes[0], 1); },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn push_tagged_elements() {
+
+        let mut mesh = Mesh2d::default();
+
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.push_tagged_edge(Edge::new([0, 1]), "bottom");
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.push_tagged_triangle(Tri::new([0, 2, 3]), "upper");
+        mesh.push_tagged_quadrangle(Quad::new([0, 1, 2, 3]), "square");
+
+        match mesh.edges_tags.get_registered_indexes("bottom") {
+            Some(indexes) => { assert_eq!(indexes[0], 0); },
+            None => { panic!(); }
+        }
+        match mesh.triangles_tags.get_registered_indexes("upper") {
+            Some(indexes) => { assert_eq!(indexes[0], 1); },
+            None => { panic!(); }
+        }
+        match mesh.quadrangles_tags.get_registered_indexes("square") {
+            Some(indexes) => { assert_eq!(indexes[0], 0); },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn get_views() {
+
+        let mut mesh = Mesh2d::default();
+
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([2., 0.]));
+        mesh.vertices.push(Pnt2d::new([2., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.edges.push(Edge::new([1, 2]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+
+        let e = mesh.get_edge_view(&mesh.edges[0]);
+        assert!((e.get_length() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(e.get_barycenter().coords.equals(&Coord2d::new([2., 0.5]), GEOMETRICAL_TOLERANCE));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        assert!(t.points[2].coords.equals(&Coord2d::new([2., 1.]), GEOMETRICAL_TOLERANCE));
+        assert!((t.get_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        let q = mesh.get_quad_view(&mesh.quadrangles[0]);
+        assert!((q.get_area() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(q.get_barycenter().coords.equals(&Coord2d::new([1., 0.5]), GEOMETRICAL_TOLERANCE));
+        assert!((mesh.get_total_area() - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn to_json() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "tri");
+
+        for &pretty in &[true, false] {
+            match mesh.to_json(pretty).map(|json| Mesh2d::from_json(&json)) {
+                Ok(Ok(read)) => {
+                    assert_eq!(read.vertices.len(), 3);
+                    assert_eq!(read.triangles[0].indexes, [0, 1, 2]);
+                    assert_eq!(read.triangles_tags.get_registered_indexes("tri"), Some(&vec![0]));
+                },
+                _ => { panic!(); }
+            }
+        }
+        assert!(Mesh2d::from_json("{ \"vertices\": 3 }").is_err());
+    }
+
+    #[test]
+    fn extract_boundary() {
+
+        let mut mesh = Mesh2d::default();
+
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+
+        let boundary = mesh.extract_boundary();
+        assert_eq!(boundary.len(), 4);
+        for edge in &boundary {
+            assert_ne!(edge.indexes, [0, 2]);
+            assert_ne!(edge.indexes, [2, 0]);
+            // Counterclockwise faces yield counterclockwise boundary edges.
+            assert_eq!(edge.indexes[1], (edge.indexes[0] + 1) % 4);
+        }
+
+        mesh.tag_boundary("boundary");
+        assert_eq!(mesh.edges.len(), 4);
+        match mesh.edges_tags.get_registered_indexes("boundary") {
+            Some(indexes) => { assert_eq!(indexes.len(), 4); },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn connected_components() {
+
+        let mut mesh = Mesh2d::default();
+        for &x in &[0., 2.] {
+            let n = mesh.vertices.len();
+            for &(dx, y) in &[(0., 0.), (1., 0.), (1., 1.), (0., 1.)] { mesh.vertices.push(Pnt2d::new([x + dx, y])); }
+            mesh.push_tagged_triangle(Tri::new([n, n + 1, n + 2]), "square");
+            mesh.push_tagged_triangle(Tri::new([n, n + 2, n + 3]), "square");
+        }
+        mesh.push_tagged_edge(Edge::new([4, 5]), "bottom");
+        mesh.vertices.push(Pnt2d::new([9., 9.]));
+
+        assert_eq!(mesh.connected_components(), vec![0, 0, 0, 0, 1, 1, 1, 1, 2]);
+
+        let components = mesh.split_connected_components();
+        assert_eq!(components.len(), 2);
+        for component in &components {
+            assert_eq!(component.vertices.len(), 4);
+            assert_eq!(component.triangles.len(), 2);
+            assert_eq!(component.triangles_tags.get_registered_indexes("square"), Some(&vec![0, 1]));
+        }
+        assert!(components[0].edges_tags.get_registered_indexes("bottom").is_none());
+        assert_eq!(components[1].edges[0].indexes, [0, 1]);
+        assert_eq!(components[1].edges_tags.get_registered_indexes("bottom"), Some(&vec![0]));
+    }
+
+    #[test]
+    fn triangulate_quads() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.push_tagged_quadrangle(Quad::new([0, 1, 2, 3]), "square");
+
+        mesh.triangulate_quads();
+        assert!(mesh.quadrangles.is_empty());
+        assert_eq!(mesh.triangles.len(), 2);
+        assert!((mesh.get_total_area() - 1.).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("square"), Some(&vec![0, 1]));
+    }
+
+    #[test]
+    fn refine_uniform() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.push_tagged_triangle(Tri::new([0, 2, 3]), "upper");
+        mesh.push_tagged_edge(Edge::new([3, 0]), "left");
+
+        mesh.refine_uniform();
+        mesh.refine_uniform();
+        assert_eq!(mesh.triangles.len(), 32);
+        assert_eq!(mesh.vertices.len(), 25);
+        assert!((mesh.get_total_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.extract_boundary().len(), 16);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("upper").map(|i| i.len()), Some(16));
+        match mesh.edges_tags.get_registered_indexes("left") {
+            Some(indexes) => {
+                assert_eq!(indexes.len(), 4);
+                for &i in indexes { assert!((mesh.get_edge_view(&mesh.edges[i]).get_length() - 0.25).abs() < GEOMETRICAL_TOLERANCE); }
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn split_edge() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "lower");
+        mesh.triangles.push(Tri::new([0, 2, 3]));
+        mesh.edges.push(Edge::new([2, 0]));
+        mesh.push_tagged_edge(Edge::new([0, 1]), "bottom");
+
+        // Interior edge.
+        assert_eq!(mesh.split_edge(0), 4);
+        assert!(mesh.vertices[4].coords.equals(&Coord2d::new([0.5, 0.5]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(mesh.edges[0].indexes, [2, 4]);
+        assert_eq!(mesh.edges[2].indexes, [4, 0]);
+        assert_eq!(mesh.triangles.len(), 4);
+        assert!((mesh.get_total_area() - 1.).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("lower"), Some(&vec![0, 2]));
+        for tri in &mesh.triangles {
+            let t = mesh.get_tri_view(tri);
+            assert!(t.points[0].to(t.points[1]).cross_out(&t.points[0].to(t.points[2])) > 0.);
+        }
+
+        // Boundary edge.
+        assert_eq!(mesh.split_edge(1), 5);
+        assert_eq!(mesh.triangles.len(), 5);
+        assert!((mesh.get_total_area() - 1.).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.edges_tags.get_registered_indexes("bottom"), Some(&vec![1, 3]));
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("lower"), Some(&vec![0, 2, 4]));
+        assert_eq!(mesh.extract_boundary().len(), 5);
+
+        // Edge shared by a quadrangle and a triangle.
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.vertices.push(Pnt2d::new([2., 0.5]));
+        mesh.push_tagged_quadrangle(Quad::new([0, 1, 2, 3]), "cell");
+        mesh.triangles.push(Tri::new([1, 4, 2]));
+        mesh.edges.push(Edge::new([1, 2]));
+
+        assert_eq!(mesh.split_edge(0), 5);
+        assert_eq!(mesh.quadrangles.len(), 1);
+        assert_eq!(mesh.quadrangles[0].indexes, [1, 5, 3, 0]);
+        assert_eq!(mesh.triangles.len(), 3);
+        assert_eq!(mesh.triangles[2].indexes, [5, 2, 3]);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("cell"), Some(&vec![2]));
+        assert_eq!(mesh.quadrangles_tags.get_registered_indexes("cell"), Some(&vec![0]));
+        assert!((mesh.get_total_area() - 1.5).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_quad_view(&mesh.quadrangles[0]).get_area() - 0.75).abs() < GEOMETRICAL_TOLERANCE);
+        // No hanging vertex is left on the boundary between the two elements.
+        assert_eq!(mesh.extract_boundary().len(), 5);
+    }
+
+    // Checking that the surface faces of a convex mesh face away from a point inside it.
+    fn assert_outward(mesh: &Mesh3d, inside: &Pnt3d) {
+
+        for tri in &mesh.triangles {
+            let t = mesh.get_tri_view(tri);
+            assert!(t.get_normal().coords.dot(&inside.to(&t.get_barycenter()).coords) > 0.);
+        }
+        for quad in &mesh.quadrangles {
+            let p: Vec<&Pnt3d> = quad.indexes.iter().map(|&v| &mesh.vertices[v]).collect();
+            let normal = p[0].to(p[2]).cross_out(&p[1].to(p[3]));
+            assert!(normal.dot(&inside.to(p[0])) > 0.);
+        }
+    }
+
+    #[test]
+    fn extrude_0() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.push_tagged_triangle(Tri::new([0, 2, 3]), "upper");
+        mesh.push_tagged_edge(Edge::new([1, 0]), "south");
+
+        let extruded = mesh.extrude(&Vec3d::new([0., 0., 1.]), 1);
+        assert_eq!(extruded.vertices.len(), 8);
+        assert_eq!(extruded.tetrahedra.len(), 6);
+        for tet in &extruded.tetrahedra { assert!(!extruded.get_tet_view(tet).is_inverted()); }
+        assert!((extruded.get_total_volume(false) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(*extruded.tetrahedra_tags.get_registered_indexes("upper").unwrap(), vec![3, 4, 5]);
+
+        // Prisms share the diagonals of their common side, leaving only the outer surface.
+        let (tris, quads) = extruded.extract_boundary_faces();
+        assert_eq!(tris.len(), 12);
+        assert!(quads.is_empty());
+
+        assert_eq!(extruded.triangles_tags.get_registered_indexes("bottom").unwrap().len(), 2);
+        assert_eq!(extruded.triangles_tags.get_registered_indexes("top").unwrap().len(), 2);
+        assert_eq!(extruded.quadrangles_tags.get_registered_indexes("lateral").unwrap().len(), 4);
+        match extruded.quadrangles_tags.get_registered_indexes("south") {
+            Some(indexes) => {
+                assert_eq!(indexes.len(), 1);
+                for &v in &extruded.quadrangles[indexes[0]].indexes { assert!(extruded.vertices[v].coords.y.abs() < GEOMETRICAL_TOLERANCE); }
+            },
+            None => { panic!(); }
+        }
+        assert_outward(&extruded, &Pnt3d::new([0.5, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn extrude_1() {
+
+        // Clockwise quadrangles extruded downward in two layers.
+        let mut mesh = Mesh2d::default();
+        for j in 0..2 {
+            for i in 0..3 { mesh.push_tagged_vertex(Pnt2d::new([i as f64, j as f64]), "grid"); }
+        }
+        mesh.push_tagged_quadrangle(Quad::new([0, 3, 4, 1]), "left");
+        mesh.quadrangles.push(Quad::new([1, 4, 5, 2]));
+
+        let extruded = mesh.extrude(&Vec3d::new([0., 0., -2.]), 2);
+        assert_eq!(extruded.vertices.len(), 18);
+        assert_eq!(extruded.vertices_tags.get_registered_indexes("grid").unwrap().len(), 18);
+        assert_eq!(extruded.hexahedra.len(), 4);
+        for hexa in &extruded.hexahedra { assert!(extruded.get_hexa_view(hexa).get_signed_volume() > 0.); }
+        assert!((extruded.get_total_volume(false) - 4.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(*extruded.hexahedra_tags.get_registered_indexes("left").unwrap(), vec![0, 2]);
+
+        assert_eq!(extruded.quadrangles_tags.get_registered_indexes("bottom").unwrap().len(), 2);
+        assert_eq!(extruded.quadrangles_tags.get_registered_indexes("top").unwrap().len(), 2);
+        assert_eq!(extruded.quadrangles_tags.get_registered_indexes("lateral").unwrap().len(), 12);
+        for &q in extruded.quadrangles_tags.get_registered_indexes("top").unwrap() {
+            for &v in &extruded.quadrangles[q].indexes { assert!((extruded.vertices[v].coords.z + 2.0).abs() < GEOMETRICAL_TOLERANCE); }
+        }
+        assert_outward(&extruded, &Pnt3d::new([1., 0.5, -1.]));
+    }
+
+    #[test]
+    fn locate_point() {
+
+        let mesh = Mesh2d::new_rectangle(3, 2, 1., 1.);
+        // Lowest index of the triangles using all the given vertices.
+        let lowest = |vertices: &[usize]| mesh.triangles.iter().position(|t| vertices.iter().all(|v| t.indexes.contains(v)));
+
+        for (i, tri) in mesh.triangles.iter().enumerate() {
+            assert_eq!(mesh.locate_point(&mesh.get_tri_view(tri).get_barycenter()), Some(i));
+            for e in &Tri::EDGES {
+                let (a, b) = (tri.indexes[e[0]], tri.indexes[e[1]]);
+                let midpoint = Pnt2d { coords: mesh.vertices[a].coords.mlt_add_out(0.5, 0.5, &mesh.vertices[b].coords) };
+                assert_eq!(mesh.locate_point(&midpoint), lowest(&[a, b]));
+            }
+        }
+        for v in 0..mesh.vertices.len() { assert_eq!(mesh.locate_point(&mesh.vertices[v]), lowest(&[v])); }
+
+        assert!(mesh.locate_point(&Pnt2d::new([1.5, 0.5])).is_none());
+        assert!(mesh.locate_point(&Pnt2d::new([0.5, -0.01])).is_none());
+        assert!(Mesh2d::default().locate_point(&Pnt2d::new([0., 0.])).is_none());
+    }
+
+    #[test]
+    fn get_bounding_box() {
+
+        assert!(Mesh2d::default().get_bounding_box().is_none());
+        let mesh = Mesh2d::new_rectangle(2, 3, 1.5, 2.);
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([0., 0.]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord2d::new([1.5, 2.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn translate_scale() {
+
+        let mut mesh = Mesh2d::new_rectangle(2, 2, 1., 1.);
+        mesh.translate(&Vec2d::new([1., -1.])).scale(2.0);
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([2., -2.]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord2d::new([4., 0.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+
+        let mut mesh = Mesh2d::new_rectangle(2, 2, 1., 1.);
+        mesh.scale_about(&Pnt2d::new([0.5, 0.5]), 3.0);
+        assert!((mesh.get_total_area() - 9.0).abs() < GEOMETRICAL_TOLERANCE);
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([-1., -1.]), GEOMETRICAL_TOLERANCE));
+                assert!(bbox.max.equals(&Coord2d::new([2., 2.]), GEOMETRICAL_TOLERANCE));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn rotate() {
+
+        let mut mesh = Mesh2d::new_rectangle(2, 2, 1., 1.);
+        mesh.rotate(0.3, &Pnt2d::new([0.5, 2.])).rotate(-0.3, &Pnt2d::new([0.5, 2.]));
+        assert!(mesh.vertices[8].coords.equals(&Coord2d::new([1., 1.]), 1e-10));
+
+        mesh.rotate(1.2, &Pnt2d::new([0.5, 2.]));
+        assert!((mesh.get_total_area() - 1.0).abs() < 1e-10);
+        for t in &mesh.triangles { assert!((mesh.get_tri_view(t).get_area() - 0.125).abs() < 1e-10); }
+
+        let mut mesh = Mesh2d::new_rectangle(2, 2, 1., 1.);
+        mesh.rotate(0.5 * std::f64::consts::PI, &Pnt2d::new([0., 0.]));
+        match mesh.get_bounding_box() {
+            Some(bbox) => {
+                assert!(bbox.min.equals(&Coord2d::new([-1., 0.]), 1e-10));
+                assert!(bbox.max.equals(&Coord2d::new([0., 1.]), 1e-10));
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn merge_duplicate_vertices() {
+
+        // Two triangles sharing an edge through duplicated vertices.
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 1.]));
+        mesh.vertices.push(Pnt2d::new([1e-9, -1e-9]));
+        mesh.vertices.push(Pnt2d::new([1., 1. + 1e-9]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([3, 4, 5]));
+        mesh.push_tagged_edge(Edge::new([4, 5]), "top");
+        mesh.push_tagged_vertex(Pnt2d::new([2., 0.]), "free");
+        mesh.vertices_tags.register("corner", 4);
+        assert_eq!(mesh.extract_boundary().len(), 6);
+
+        assert_eq!(mesh.merge_duplicate_vertices(0.0), 0);
+        assert_eq!(mesh.merge_duplicate_vertices(1e-6), 2);
+        assert_eq!(mesh.vertices.len(), 5);
+        assert_eq!(mesh.triangles[1].indexes, [0, 2, 3]);
+        assert_eq!(mesh.edges[0].indexes, [2, 3]);
+        assert_eq!(mesh.extract_boundary().len(), 4);
+        assert_eq!(mesh.vertices_tags.get_registered_indexes("corner"), Some(&vec![2]));
+        assert_eq!(mesh.vertices_tags.get_registered_indexes("free"), Some(&vec![4]));
+    }
+
+    #[test]
+    fn remove_unused_vertices() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.push_tagged_vertex(Pnt2d::new([5., 5.]), "orphan");
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.push_tagged_vertex(Pnt2d::new([1., 0.]), "corner");
+        mesh.vertices.push(Pnt2d::new([6., 6.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.vertices_tags.register("orphan", 3);
+        mesh.triangles.push(Tri::new([1, 2, 4]));
+        mesh.edges.push(Edge::new([4, 1]));
+        let area = mesh.get_tri_view(&mesh.triangles[0]).get_area();
+
+        assert_eq!(mesh.remove_unused_vertices(), vec![0, 3]);
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.triangles[0].indexes, [0, 1, 2]);
+        assert_eq!(mesh.edges[0].indexes, [2, 0]);
+        assert!((mesh.get_tri_view(&mesh.triangles[0]).get_area() - area).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(mesh.vertices_tags.get_registered_indexes("orphan").is_none());
+        assert_eq!(mesh.vertices_tags.get_registered_indexes("corner"), Some(&vec![1]));
+        assert!(mesh.remove_unused_vertices().is_empty());
+
+        // A mesh without elements ends up without vertices.
+        mesh.triangles.clear();
+        mesh.edges.clear();
+        assert_eq!(mesh.remove_unused_vertices(), vec![0, 1, 2]);
+        assert!(mesh.vertices.is_empty());
+        assert!(mesh.vertices_tags.get_registered_indexes("corner").is_none());
+    }
+
+    #[test]
+    fn append() {
+
+        let mut mesh = Mesh2d::new_rectangle(1, 1, 1., 1.);
+        let mut other = Mesh2d::new_rectangle(1, 1, 1., 1.);
+        other.translate(&Vec2d::new([2., 0.]));
+        other.push_tagged_quadrangle(Quad::new([0, 1, 3, 2]), "cell");
+        mesh.append(&other);
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 4);
+        assert_eq!(mesh.quadrangles[0].indexes, [4, 5, 7, 6]);
+        assert_eq!(mesh.edges.len(), 8);
+        assert_eq!(mesh.quadrangles_tags.get_registered_indexes("cell"), Some(&vec![0]));
+        assert!((mesh.get_total_area() - 3.).abs() < GEOMETRICAL_TOLERANCE);
+        match mesh.get_bounding_box() {
+            Some(bbox) => assert!(bbox.max.equals(&Coord2d::new([3., 1.]), GEOMETRICAL_TOLERANCE)),
+            None => { panic!(); }
+        }
+        let e = mesh.get_edge_view(&mesh.edges[4]);
+        assert!(e.points[0].coords.equals(&Coord2d::new([2., 0.]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn append_with_prefix() {
+
+        let mut mesh = Mesh2d::default();
+        mesh.vertices.push(Pnt2d::new([0., 0.]));
+        mesh.vertices.push(Pnt2d::new([1., 0.]));
+        mesh.vertices.push(Pnt2d::new([0., 1.]));
+        mesh.push_tagged_triangle(Tri::new([0, 1, 2]), "part");
+        mesh.push_tagged_vertex(Pnt2d::new([1., 1.]), "apex");
+
+        let mut other = Mesh2d { vertices: mesh.vertices.clone(), ..Mesh2d::default() };
+        other.push_tagged_triangle(Tri::new([1, 3, 2]), "part");
+        mesh.append(&other);
+        mesh.append_with_prefix(&other, "copy/");
+
+        assert_eq!(mesh.vertices.len(), 12);
+        assert_eq!(mesh.triangles[2].indexes, [9, 11, 10]);
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("part"), Some(&vec![0, 1]));
+        assert_eq!(mesh.triangles_tags.get_registered_indexes("copy/part"), Some(&vec![2]));
+        assert_eq!(mesh.vertices_tags.get_registered_indexes("apex"), Some(&vec![3]));
+        assert!(mesh.vertices_tags.get_registered_indexes("copy/apex").is_none());
+    }
+
+    #[test]
+    fn triangle_areas() {
+
+        let mut mesh = Mesh2d::new_rectangle(1, 1, 1., 1.);
+        assert!((mesh.total_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        let areas = mesh.triangle_areas();
+        assert_eq!(areas.len(), 2);
+        for a in &areas { assert!((a - 0.5).abs() < GEOMETRICAL_TOLERANCE); }
+
+        // Quadrangles count in the total area, but have no entry in the triangle areas.
+        mesh.quadrangles.push(Quad::new([0, 1, 3, 2]));
+        assert!((mesh.total_area() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.total_area() - mesh.get_total_area()).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(mesh.triangle_areas().len(), 2);
+        assert!(Mesh2d::default().triangle_areas().is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+extern crate mersh;
+
+mod histogram {
+
+    use mersh::quality::*;
+
+    #[test]
+    fn new() {
+        let histogram = Histogram::new(&[0.0, 0.5, 1.0, 1.5, 2.0, 3.0], 2, Some((0.0, 2.0)));
+        assert_eq!(histogram.counts, vec![2, 3]);
+        assert_eq!(histogram.total(), 5);
+        assert!((histogram.bin_width() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn non_finite() {
+        let values = [f64::NAN, 0.5, f64::INFINITY, 1.5, f64::NEG_INFINITY];
+        let histogram = Histogram::new(&values, 2, Some((0.0, 2.0)));
+        assert_eq!(histogram.counts, vec![1, 1]);
+
+        let histogram = Histogram::new(&values, 2, None);
+        assert_eq!((histogram.min, histogram.max), (0.5, 1.5));
+        assert_eq!(histogram.counts, vec![1, 1]);
+
+        let histogram = Histogram::new(&[f64::NAN], 2, None);
+        assert_eq!(histogram.total(), 0);
+    }
+
+    #[test]
+    fn fmt() {
+        let histogram = Histogram::new(&[0.0, 1.0, 2.0, 2.0], 2, None);
+        let formatted = format!("{}", histogram);
+        let lines: Vec<&str> = formatted.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("[0.000000, 1.000000]"));
+        assert!(lines[1].ends_with(&"#".repeat(40)));
+    }
+}
+
+mod mesh {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+
+    // Building a triangulated grid of n x n unit squares.
+    fn make_grid(n: usize) -> Mesh3d {
+        let mut mesh = Mesh3d::default();
+        for j in 0..n + 1 {
+            for i in 0..n + 1 { mesh.vertices.push(Pnt3d::new([i as f64, j as f64, 0.])); }
+        }
+        for j in 0..n {
+            for i in 0..n {
+                let p = j * (n + 1) + i;
+                mesh.triangles.push(Tri::new([p, p + 1, p + n + 2]));
+                mesh.triangles.push(Tri::new([p, p + n + 2, p + n + 1]));
+            }
+        }
+        mesh
+    }
+
+    #[test]
+    fn edge_length_histogram() {
+
+        let mesh = make_grid(3);
+        let histogram = mesh.edge_length_histogram(2, None);
+
+        assert!((histogram.min - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((histogram.max - 2f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+        assert_eq!(histogram.counts, vec![24, 9]);
+    }
+
+    #[test]
+    fn check_size_field() {
+
+        let mesh = make_grid(3);
+        assert!(mesh.check_size_field(1.2, 1.25).is_empty());
+
+        let report = mesh.check_size_field(1.0, 1.25);
+        assert!(!report.is_empty());
+        assert_eq!(report.too_coarse.triangles.len(), 18);
+        assert!(report.too_fine.triangles.is_empty());
+    }
+
+    #[test]
+    fn quality_report() {
+
+        assert!(Mesh3d::default().quality_report().is_none());
+
+        let mesh = make_grid(2);
+        match mesh.quality_report() {
+            Some(report) => {
+                // Right isosceles triangles only.
+                let expected_aspect_ratio = 1.0 / (2.0 * (2f64.sqrt() - 1.0));
+                assert!((report.aspect_ratio.min - expected_aspect_ratio).abs() < 1e-10);
+                assert!((report.aspect_ratio.max - expected_aspect_ratio).abs() < 1e-10);
+                assert!((report.min_angle.mean - std::f64::consts::FRAC_PI_4).abs() < 1e-10);
+                assert!((report.edge_ratio.max - 2f64.sqrt()).abs() < 1e-10);
+            },
+            None => { panic!(); }
+        }
+    }
+
+    #[test]
+    fn find_inverted_tets() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., -1.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 4]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 4]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 3]));
+
+        assert_eq!(mesh.find_inverted_tets(), vec![1, 3]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,183 @@
+extern crate mersh;
+
+mod search {
+
+    use mersh::base::*;
+    use mersh::mesh::*;
+    use mersh::search::*;
+
+    // Generating pseudo-random coordinates in [0, 1) from a linear congruential generator.
+    fn make_random(seed: &mut u64) -> f64 {
+
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (*seed >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Building a mesh of random vertices in a flattened box, with a few duplicated vertices.
+    fn make_cloud(n: usize, seed: &mut u64) -> Mesh3d {
+
+        let mut mesh = Mesh3d::default();
+        for _ in 0..n {
+            let xyz = [4.0 * make_random(seed), 2.0 * make_random(seed), 0.1 * make_random(seed)];
+            mesh.vertices.push(Pnt3d::new(xyz));
+        }
+        for i in 0..n / 100 {
+            let p = mesh.vertices[37 * i % n].clone();
+            mesh.vertices.push(p);
+        }
+        mesh
+    }
+
+    // Sorting all the vertices by distance to a probe point, ties being sorted by index.
+    fn brute_force(vertices: &[Pnt3d], p: &Pnt3d) -> Vec<(usize, f64)> {
+
+        let mut sorted: Vec<(usize, f64)> = vertices.iter().enumerate().map(|(i, q)| (i, q.distance_to(p))).collect();
+        sorted.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+        sorted
+    }
+
+    #[test]
+    fn against_brute_force() {
+
+        let mut seed = 7;
+        let mesh = make_cloud(3000, &mut seed);
+        let index = mesh.build_vertex_index();
+        assert_eq!(index.len(), mesh.vertices.len());
+
+        for q in 0..200 {
+            // Probes partly lie outside the bounding box of the vertices, and partly on vertices.
+            let p = if q % 10 == 0 {
+                mesh.vertices[q * 13].clone()
+            } else {
+                Pnt3d::new([6.0 * make_random(&mut seed) - 1.0, 4.0 * make_random(&mut seed) - 1.0, 2.0 * make_random(&mut seed) - 1.0])
+            };
+            let expected = brute_force(&mesh.vertices, &p);
+
+            match index.nearest(&p) {
+                Some((i, d)) => { assert_eq!(i, expected[0].0); assert_eq!(d, expected[0].1); },
+                None => { panic!(); }
+            }
+            assert_eq!(index.k_nearest(&p, 10), expected[..10].to_vec());
+
+            let r = 0.15;
+            let mut within: Vec<usize> = expected.iter().filter(|&&(_, d)| d <= r).map(|&(i, _)| i).collect();
+            within.sort();
+            assert_eq!(index.within_radius(&p, r), within);
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+
+        let mut mesh = Mesh3d::default();
+        for _ in 0..3 { mesh.vertices.push(Pnt3d::new([1., 1., 1.])); }
+        mesh.vertices.push(Pnt3d::new([2., 1., 1.]));
+        let index = mesh.build_vertex_index();
+
+        let p = Pnt3d::new([1., 1., 1.]);
+        assert_eq!(index.nearest(&p), Some((0, 0.)));
+        assert_eq!(index.k_nearest(&p, 3), vec![(0, 0.), (1, 0.), (2, 0.)]);
+        assert_eq!(index.k_nearest(&p, 10).len(), 4);
+        assert_eq!(index.within_radius(&p, 0.), vec![0, 1, 2]);
+
+        // All points coincide.
+        let index = PointIndex3d::new(&mesh.vertices[..3]);
+        assert_eq!(index.nearest(&Pnt3d::new([0., 0., 0.])).unwrap().0, 0);
+        assert_eq!(index.within_radius(&Pnt3d::new([0., 0., 0.]), 2.0), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn empty() {
+
+        let index = Mesh3d::default().build_vertex_index();
+        let p = Pnt3d::new([0., 0., 0.]);
+        assert!(index.is_empty());
+        assert!(index.nearest(&p).is_none());
+        assert!(index.k_nearest(&p, 3).is_empty());
+        assert!(index.within_radius(&p, 1.0).is_empty());
+    }
+
+    #[test]
+    fn rebuild() {
+
+        let mut seed = 11;
+        let mut mesh = make_cloud(500, &mut seed);
+        let mut index = mesh.build_vertex_index();
+        let p = Pnt3d::new([10., 10., 10.]);
+        let far = index.nearest(&p).unwrap();
+
+        mesh.translate(&Vec3d::new([8., 8., 8.]));
+        index.rebuild(&mesh.vertices);
+        let expected = brute_force(&mesh.vertices, &p);
+        assert_eq!(index.nearest(&p), Some(expected[0]));
+        assert!(expected[0].1 < far.1);
+    }
+
+    #[test]
+    fn box_index() {
+
+        let mut seed = 3;
+        let boxes: Vec<BoundingBox3d> = (0..500).map(|_| {
+            let (x, y, z) = (4.0 * make_random(&mut seed), 2.0 * make_random(&mut seed), make_random(&mut seed));
+            let (dx, dy, dz) = (0.3 * make_random(&mut seed), 0.3 * make_random(&mut seed), 0.3 * make_random(&mut seed));
+            BoundingBox3d::new([x, y, z], [x + dx, y + dy, z + dz])
+        }).collect();
+        let index = BoxIndex3d::new(&boxes);
+
+        for _ in 0..200 {
+            let p = Pnt3d::new([5.0 * make_random(&mut seed) - 0.5, 3.0 * make_random(&mut seed) - 0.5, 2.0 * make_random(&mut seed) - 0.5]);
+            let candidates = index.candidates(&p);
+            assert!(candidates.windows(2).all(|w| w[0] < w[1]));
+            for (i, b) in boxes.iter().enumerate() {
+                if b.contains(&p, 0.) { assert!(candidates.contains(&i)); }
+            }
+        }
+        assert!(BoxIndex3d::new(&[]).candidates(&Pnt3d::new([0., 0., 0.])).is_empty());
+    }
+
+    #[test]
+    fn box_index_2d() {
+
+        let mut seed = 13;
+        let boxes: Vec<BoundingBox2d> = (0..500).map(|_| {
+            let (x, y) = (4.0 * make_random(&mut seed), 2.0 * make_random(&mut seed));
+            let (dx, dy) = (0.3 * make_random(&mut seed), 0.3 * make_random(&mut seed));
+            BoundingBox2d::new([x, y], [x + dx, y + dy])
+        }).collect();
+        let index = BoxIndex2d::new(&boxes);
+
+        for _ in 0..200 {
+            let p = Pnt2d::new([5.0 * make_random(&mut seed) - 0.5, 3.0 * make_random(&mut seed) - 0.5]);
+            let candidates = index.candidates(&p);
+            assert!(candidates.windows(2).all(|w| w[0] < w[1]));
+            for (i, b) in boxes.iter().enumerate() {
+                if b.contains(&p, 0.) { assert!(candidates.contains(&i)); }
+            }
+        }
+        assert!(BoxIndex2d::new(&[]).candidates(&Pnt2d::new([0., 0.])).is_empty());
+    }
+
+    #[test]
+    fn locate_point() {
+
+        let mut seed = 5;
+        let mesh2d = Mesh2d::new_rectangle(20, 10, 2., 1.);
+        let mut mesh3d = Mesh3d::new_box(6, 5, 4, 1., 1., 1.);
+        mesh3d.tetrahedralize_hexas();
+        let (locator2d, locator3d) = (mesh2d.build_tri_locator(), mesh3d.build_tet_locator());
+        for _ in 0..200 {
+            let (x, y, z) = (2.4 * make_random(&mut seed) - 0.2, 1.2 * make_random(&mut seed) - 0.1, 1.2 * make_random(&mut seed) - 0.1);
+
+            // Lowest index of the elements containing the point, found by scanning all of them.
+            let p = Pnt2d::new([x, y]);
+            let expected = mesh2d.triangles.iter().position(|t| mesh2d.get_tri_view(t).contains_point(&p, GEOMETRICAL_TOLERANCE));
+            assert_eq!(locator2d.locate(&p), expected);
+            assert_eq!(mesh2d.locate_point(&p), expected);
+
+            let p = Pnt3d::new([x / 2., y, z]);
+            let expected = mesh3d.tetrahedra.iter().position(|t| mesh3d.get_tet_view(t).contains_point(&p, GEOMETRICAL_TOLERANCE));
+            assert_eq!(locator3d.locate(&p), expected);
+            assert_eq!(mesh3d.locate_point_in_tets(&p), expected);
+        }
+    }
+}
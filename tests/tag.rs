@@ -1,4 +1,5 @@
 extern crate mersh;
+extern crate serde_json;
 
 mod tag {
 
@@ -8,7 +9,7 @@ mod tag {
     fn get_registered_indexes() {
 
         let tag_set = TagSet::default();
-        let indexes = tag_set.get_registered_indexes(&"tag_name_0".to_string());
+        let indexes = tag_set.get_registered_indexes("tag_name_0");
         assert_eq!(indexes, None);
     }
 
@@ -26,16 +27,134 @@ mod tag {
 
         match tag_set.get_registered_indexes(&name0) {
             Some(indexes) => { assert_eq!(indexes[0], 0); assert_eq!(indexes[1], 85);},
-            None => { assert!(false); }
+            None => { panic!(); }
         }
 
         match tag_set.get_registered_indexes(&name1) {
             Some(indexes) => { assert_eq!(indexes[0], 2);},
-            None => { assert!(false); }
+            None => { panic!(); }
         }
 
-        if let Some(_) = tag_set.get_registered_indexes(&name2) {
-            assert!(false);
+        assert!(tag_set.get_registered_indexes(&name2).is_none());
+    }
+
+    #[test]
+    fn remap() {
+
+        let mut tag_set = TagSet::default();
+        tag_set.register("tag_name_0", 0);
+        tag_set.register("tag_name_0", 2);
+        tag_set.register("tag_name_0", 3);
+        tag_set.register("tag_name_1", 1);
+
+        let remapped = tag_set.remap(&[Some(0), None, Some(1), Some(1)]);
+
+        match remapped.get_registered_indexes("tag_name_0") {
+            Some(indexes) => { assert_eq!(*indexes, vec![0, 1]); },
+            None => { panic!(); }
+        }
+        assert!(remapped.get_registered_indexes("tag_name_1").is_none());
+    }
+
+    #[test]
+    fn serialize() {
+
+        let mut tag_set0 = TagSet::default();
+        let mut tag_set1 = TagSet::default();
+        let names = ["tag_name_0", "tag_name_1", "tag_name_2", "tag_name_3"];
+
+        for (idx, name) in names.iter().enumerate() { tag_set0.register(name, idx); }
+        for (idx, name) in names.iter().enumerate().rev() { tag_set1.register(name, idx); }
+
+        let json0 = serde_json::to_string(&tag_set0).unwrap();
+        let json1 = serde_json::to_string(&tag_set1).unwrap();
+        assert_eq!(json0, json1);
+        assert_eq!(json0, r#"{"tag_map":{"tag_name_0":[0],"tag_name_1":[1],"tag_name_2":[2],"tag_name_3":[3]}}"#);
+    }
+
+    #[test]
+    fn deserialize() {
+
+        let json = r#"{"tag_map":{"tag_name_1":[4,2],"tag_name_0":[1]}}"#;
+        let tag_set: TagSet = serde_json::from_str(json).unwrap();
+
+        match tag_set.get_registered_indexes("tag_name_1") {
+            Some(indexes) => { assert_eq!(*indexes, vec![4, 2]); },
+            None => { panic!(); }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn merge() {
+
+        let mut tag_set = TagSet::default();
+        tag_set.register("a", 0);
+        let mut other = TagSet::default();
+        other.register("a", 1);
+        other.register("b", 0);
+
+        tag_set.merge(&other, 10, "");
+        assert_eq!(*tag_set.get_registered_indexes("a").unwrap(), vec![0, 11]);
+        assert_eq!(*tag_set.get_registered_indexes("b").unwrap(), vec![10]);
+
+        tag_set.merge(&other, 20, "other/");
+        assert_eq!(*tag_set.get_registered_indexes("other/a").unwrap(), vec![21]);
+        assert_eq!(*tag_set.get_registered_indexes("other/b").unwrap(), vec![20]);
+        assert_eq!(*tag_set.get_registered_indexes("a").unwrap(), vec![0, 11]);
+    }
+
+    #[test]
+    fn remove() {
+
+        let mut tag_set = TagSet::default();
+        tag_set.register("a", 3);
+        tag_set.register("a", 1);
+
+        assert_eq!(tag_set.remove("missing"), None);
+        assert_eq!(tag_set.remove("a"), Some(vec![3, 1]));
+        assert!(tag_set.get_registered_indexes("a").is_none());
+        assert_eq!(tag_set.remove("a"), None);
+    }
+
+    #[test]
+    fn rename() {
+
+        let mut tag_set = TagSet::default();
+        tag_set.register("a", 0);
+        tag_set.register("a", 2);
+        tag_set.register("b", 2);
+        tag_set.register("b", 5);
+
+        assert!(!tag_set.rename("missing", "c"));
+        assert!(tag_set.get_registered_indexes("c").is_none());
+
+        assert!(tag_set.rename("a", "c"));
+        assert!(tag_set.get_registered_indexes("a").is_none());
+        assert_eq!(*tag_set.get_registered_indexes("c").unwrap(), vec![0, 2]);
+
+        // Renaming into an existing tag merges the indexes.
+        assert!(tag_set.rename("c", "b"));
+        assert!(tag_set.get_registered_indexes("c").is_none());
+        assert_eq!(*tag_set.get_registered_indexes("b").unwrap(), vec![2, 5, 0]);
+        assert!(tag_set.rename("b", "b"));
+    }
+
+    #[test]
+    fn tags_of() {
+
+        let mut tag_set = TagSet::default();
+        tag_set.register("b", 5);
+        tag_set.register("a", 1);
+        tag_set.register("a", 5);
+        tag_set.register("c", 2);
+
+        assert_eq!(tag_set.tags_of(5), vec!["a", "b"]);
+        assert_eq!(tag_set.tags_of(2), vec!["c"]);
+        assert!(tag_set.tags_of(3).is_empty());
+
+        assert!(tag_set.contains("a", 5));
+        assert!(tag_set.contains("b", 5));
+        assert!(!tag_set.contains("c", 5));
+        assert!(!tag_set.contains("missing", 5));
+    }
+}
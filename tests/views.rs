@@ -18,6 +18,120 @@ mod edge_view3d {
         let e = mesh.get_edge_view(&mesh.edges[0]);
         assert!((e.get_length() - 1.0) < 1e-10);
     }
+
+    #[test]
+    fn distance_to_edge_0() {
+
+        // Skew edges.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([-1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0.5, -1., 2.]));
+        mesh.vertices.push(Pnt3d::new([0.5, 1., 2.]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.edges.push(Edge::new([2, 3]));
+
+        let e = mesh.get_edge_view(&mesh.edges[0]);
+        let f = mesh.get_edge_view(&mesh.edges[1]);
+        assert!((e.distance_to_edge(&f) - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        let (p, q) = e.closest_points_between(&f);
+        assert!(p.coords.equals(&Coord3d::new([0.5, 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!(q.coords.equals(&Coord3d::new([0.5, 0., 2.]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn distance_to_edge_1() {
+
+        // Parallel edges, overlapping or not.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([3., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([5., 1., 0.]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.edges.push(Edge::new([2, 3]));
+        mesh.edges.push(Edge::new([4, 3]));
+
+        let e = mesh.get_edge_view(&mesh.edges[0]);
+        let f = mesh.get_edge_view(&mesh.edges[1]);
+        let g = mesh.get_edge_view(&mesh.edges[2]);
+        assert!((e.distance_to_edge(&f) - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((e.distance_to_edge(&g) - 2f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((g.distance_to_edge(&e) - 2f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn distance_to_edge_2() {
+
+        // Intersecting edges.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 2., 2.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 2., 2.]));
+        mesh.edges.push(Edge::new([0, 1]));
+        mesh.edges.push(Edge::new([2, 3]));
+
+        let e = mesh.get_edge_view(&mesh.edges[0]);
+        let f = mesh.get_edge_view(&mesh.edges[1]);
+        assert!(e.distance_to_edge(&f) < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn project_point_0() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.edges.push(Edge::new([0, 1]));
+
+        let e = mesh.get_edge_view(&mesh.edges[0]);
+        let (p, t) = e.project_point(&Pnt3d::new([0.5, 1., -1.]));
+        assert!(p.coords.equals(&Coord3d::new([0.5, 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!((t - 0.25).abs() < GEOMETRICAL_TOLERANCE);
+
+        // Orthogonal projection beyond the second vertex is clamped.
+        let (p, t) = e.project_point(&Pnt3d::new([3., 1., 0.]));
+        assert!(p.coords.equals(&Coord3d::new([2., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!((t - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        let (p, t) = e.project_point(&Pnt3d::new([-1., 0., 0.]));
+        assert!(p.coords.equals(&Coord3d::new([0., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!(t.abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn project_point_1() {
+
+        // Degenerate edge.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.edges.push(Edge::new([0, 0]));
+
+        let (p, t) = mesh.get_edge_view(&mesh.edges[0]).project_point(&Pnt3d::new([3., 1., 0.]));
+        assert!(p.coords.equals(&Coord3d::new([1., 1., 1.]), GEOMETRICAL_TOLERANCE));
+        assert_eq!(t, 0.0);
+    }
+
+    #[test]
+    fn get_barycenter() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 3., -4.]));
+        mesh.edges.push(Edge::new([0, 1]));
+
+        let b = mesh.get_edge_view(&mesh.edges[0]).get_barycenter();
+        assert!(b.coords.equals(&Coord3d::new([1., 2., -2.]), GEOMETRICAL_TOLERANCE));
+    }
 }
 
 mod tri_view3d {
@@ -59,7 +173,601 @@ mod tri_view3d {
         assert!((e.get_length() - 2.0).abs() < 1e-10);
 
     }
+
+    #[test]
+    fn get_barycenter() {
+
+        // Degenerate triangle collapsed to a single point.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([1.0, 2.0, 3.0]));
+        mesh.triangles.push(Tri::new([0, 0, 0]));
+
+        let b = mesh.get_tri_view(&mesh.triangles[0]).get_barycenter();
+        assert!(b.coords.equals(&Coord3d::new([1.0, 2.0, 3.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn project_point_to_plane() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 1.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        let p = t.project_point_to_plane(&Pnt3d::new([5.0, -2.0, 4.0]));
+        assert!(p.coords.equals(&Coord3d::new([5.0, -2.0, 1.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn get_barycentric_coords() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([2.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 2.0, 1.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        let expected = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        for (p, e) in t.points.iter().zip(&expected) {
+            let l = t.get_barycentric_coords(p);
+            for (li, ei) in l.iter().zip(e) { assert!((li - ei).abs() < GEOMETRICAL_TOLERANCE); }
+        }
+        let l = t.get_barycentric_coords(&t.get_barycenter());
+        for li in &l { assert!((li - 1.0 / 3.0).abs() < GEOMETRICAL_TOLERANCE); }
+
+        // Point off the plane, projected on the middle of the first edge.
+        let l = t.get_barycentric_coords(&Pnt3d::new([1.0, 0.0, -3.0]));
+        assert!((l[0] - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((l[1] - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(l[2].abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn contains_point() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([2.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 2.0, 1.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        for k in 0..3 { assert!(t.contains_point(t.points[k], GEOMETRICAL_TOLERANCE)); }
+        assert!(t.contains_point(&t.get_barycenter(), 0.0));
+        assert!(t.contains_point(&Pnt3d::new([0.5, 0.5, 4.0]), 0.0));
+
+        // Point just outside the hypotenuse.
+        let p = Pnt3d::new([1.001, 1.001, 1.0]);
+        assert!(!t.contains_point(&p, 1e-6));
+        assert!(t.contains_point(&p, 1e-2));
+    }
+
+    #[test]
+    fn get_area() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 2.0]));
+        mesh.vertices.push(Pnt3d::new([3.0, 0.0, 2.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 2.0, 2.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        assert!((mesh.get_tri_view(&mesh.triangles[0]).get_area() - 3.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn quality_0() {
+
+        // Equilateral triangle.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([2.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 3f64.sqrt(), 1.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        assert!((t.get_aspect_ratio() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_min_angle() - std::f64::consts::PI / 3.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_max_min_edge_ratio() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn quality_1() {
+
+        // Needle triangle, and triangle of zero area.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([10.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([10.0, 0.1, 0.0]));
+        mesh.vertices.push(Pnt3d::new([5.0, 0.0, 0.0]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+        mesh.triangles.push(Tri::new([0, 1, 3]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        assert!(t.get_aspect_ratio() > 10.0);
+        assert!((t.get_min_angle() - 0.01f64.atan()).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_max_min_edge_ratio() - 100.01f64.sqrt() / 0.1).abs() < 1e-10);
+
+        let t = mesh.get_tri_view(&mesh.triangles[1]);
+        assert_eq!(t.get_aspect_ratio(), f64::INFINITY);
+        assert_eq!(t.get_min_angle(), 0.0);
+        assert!((t.get_max_min_edge_ratio() - 2.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+    #[test]
+    fn circumcenter_and_incenter_0() {
+
+        // Right triangle with legs 3 and 4, circumcenter being the middle of the hypotenuse.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([3., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([0., 4., 1.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        match t.get_circumcenter() {
+            Some(c) => assert!(c.coords.equals(&Coord3d::new([1.5, 2., 1.]), GEOMETRICAL_TOLERANCE)),
+            None => { panic!(); }
+        }
+        match t.get_circumradius() {
+            Some(r) => assert!((r - 2.5).abs() < GEOMETRICAL_TOLERANCE),
+            None => { panic!(); }
+        }
+        assert!(t.get_incenter().coords.equals(&Coord3d::new([1., 1., 1.]), GEOMETRICAL_TOLERANCE));
+        assert!((t.get_inradius() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn circumcenter_and_incenter_1() {
+
+        // Collinear vertices.
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 1.]));
+        mesh.vertices.push(Pnt3d::new([3., 3., 3.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        assert!(t.get_circumcenter().is_none());
+        assert!(t.get_circumradius().is_none());
+        assert!(t.get_inradius().abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn closest_point() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.triangles.push(Tri::new([0, 1, 2]));
+
+        let t = mesh.get_tri_view(&mesh.triangles[0]);
+        let q = t.closest_point(&Pnt3d::new([0.25, 0.25, 2.]));
+        assert!(q.coords.equals(&Coord3d::new([0.25, 0.25, 0.]), GEOMETRICAL_TOLERANCE));
+        let q = t.closest_point(&Pnt3d::new([1., 1., -1.]));
+        assert!(q.coords.equals(&Coord3d::new([0.5, 0.5, 0.]), GEOMETRICAL_TOLERANCE));
+        let q = t.closest_point(&Pnt3d::new([-1., -2., 1.]));
+        assert!(q.coords.equals(&Coord3d::new([0., 0., 0.]), GEOMETRICAL_TOLERANCE));
+    }
+
+}
+
+mod quad_view3d {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+
+    #[test]
+    fn get_barycenter() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 2.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 2.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 2.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 2.0]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+
+        let b = mesh.get_quad_view(&mesh.quadrangles[0]).get_barycenter();
+        assert!(b.coords.equals(&Coord3d::new([0.5, 0.5, 2.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn get_area_0() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+
+        assert!((mesh.get_quad_view(&mesh.quadrangles[0]).get_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_area_1() {
+
+        // Quadrangle degenerated into a right triangle, its last vertex lying on its first.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([2.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 0]));
+
+        assert!((mesh.get_quad_view(&mesh.quadrangles[0]).get_area() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_area_2() {
+
+        // Non-planar quadrangle, split along the diagonal joining P1 and P3.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+
+        let expected = 0.5 + 0.5 * 3f64.sqrt();
+        assert!((mesh.get_quad_view(&mesh.quadrangles[0]).get_area() - expected).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_tri_view() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.quadrangles.push(Quad::new([0, 1, 2, 3]));
+
+        let q = mesh.get_quad_view(&mesh.quadrangles[0]);
+        let t = q.get_tri_view(TriInQuad::Tri123);
+        assert!(t.points[0].coords.equals(&q.points[1].coords, GEOMETRICAL_TOLERANCE));
+        assert!(t.points[2].coords.equals(&q.points[3].coords, GEOMETRICAL_TOLERANCE));
+        assert!((t.get_area() - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+    }
 }
 
+mod tet_view3d {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+
+    #[test]
+    fn get_volume() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 3]));
+
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[0]).get_volume() - 1.0 / 6.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[1]).get_volume() - 1.0 / 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_signed_volume() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 3]));
+
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[0]).get_signed_volume() - 1.0 / 6.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[1]).get_signed_volume() + 1.0 / 6.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_barycenter() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+
+        let b = mesh.get_tet_view(&mesh.tetrahedra[0]).get_barycenter();
+        assert!(b.coords.equals(&Coord3d::new([0.25, 0.25, 0.25]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn get_quality() {
 
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, -1.0, -1.0]));
+        mesh.vertices.push(Pnt3d::new([-1.0, 1.0, -1.0]));
+        mesh.vertices.push(Pnt3d::new([-1.0, -1.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([-1.0 / 3.0, -1.0 / 3.0, -1.0 / 3.0 + 0.01]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 3]));
+        mesh.tetrahedra.push(Tet::new([1, 2, 3, 4]));
+
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[0]).get_quality() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_tet_view(&mesh.tetrahedra[1]).get_quality() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(mesh.get_tet_view(&mesh.tetrahedra[2]).get_quality() < 0.1);
+    }
+
+    #[test]
+    fn is_inverted() {
 
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([1, 0, 2, 3]));
+
+        assert!(!mesh.get_tet_view(&mesh.tetrahedra[0]).is_inverted());
+        assert!(mesh.get_tet_view(&mesh.tetrahedra[1]).is_inverted());
+    }
+    #[test]
+    fn get_bounding_box() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 3., 0.]));
+        mesh.vertices.push(Pnt3d::new([-1., 1., 4.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+
+        let bbox = mesh.get_tet_view(&mesh.tetrahedra[0]).get_bounding_box();
+        assert!(bbox.min.equals(&Coord3d::new([-1., 0., 0.]), GEOMETRICAL_TOLERANCE));
+        assert!(bbox.max.equals(&Coord3d::new([2., 3., 4.]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn get_barycentric_coords() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([2., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 2., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 2.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 2, 1, 3]));
+
+        for tet in &mesh.tetrahedra {
+            let t = mesh.get_tet_view(tet);
+            for k in 0..4 {
+                let l = t.get_barycentric_coords(t.points[k]);
+                for (m, lm) in l.iter().enumerate() { assert!((lm - if m == k { 1. } else { 0. }).abs() < GEOMETRICAL_TOLERANCE); }
+            }
+            let l = t.get_barycentric_coords(&t.get_barycenter());
+            for lm in &l { assert!((lm - 0.25).abs() < GEOMETRICAL_TOLERANCE); }
+        }
+
+        let l = mesh.get_tet_view(&mesh.tetrahedra[0]).get_barycentric_coords(&Pnt3d::new([2., 1., 0.]));
+        assert!((l.iter().sum::<f64>() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((l[0] + 0.5).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn contains_point() {
+
+        let mut mesh = Mesh3d::default();
+        mesh.vertices.push(Pnt3d::new([0., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([1., 0., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 1., 0.]));
+        mesh.vertices.push(Pnt3d::new([0., 0., 1.]));
+        mesh.vertices.push(Pnt3d::new([1., 1., 0.]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 2, 3]));
+        mesh.tetrahedra.push(Tet::new([0, 1, 4, 2]));
+
+        let t = mesh.get_tet_view(&mesh.tetrahedra[0]);
+        for k in 0..4 { assert!(t.contains_point(t.points[k], GEOMETRICAL_TOLERANCE)); }
+        assert!(t.contains_point(&t.get_barycenter(), 0.0));
+        assert!(t.contains_point(&Pnt3d::new([0.5, 0.5, 0.]), 0.0));
+        assert!(!t.contains_point(&Pnt3d::new([0.5, 0.5, 0.01]), 1e-3));
+        assert!(!t.contains_point(&Pnt3d::new([0.2, 0.2, -0.01]), 1e-3));
+        assert!(t.contains_point(&Pnt3d::new([0.2, 0.2, -0.01]), 2e-2));
+
+        // Flat tetrahedra contain no point.
+        assert!(!mesh.get_tet_view(&mesh.tetrahedra[1]).contains_point(&Pnt3d::new([0.2, 0.2, 0.]), 1.0));
+    }
+}
+
+mod hexa_view3d {
+
+    use mersh::base::*;
+    use mersh::elements::*;
+    use mersh::mesh::*;
+
+    #[test]
+    fn get_volume_0() {
+
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 1.0]));
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+
+        assert!((mesh.get_hexa_view(&mesh.hexahedra[0]).get_volume() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_volume_1() {
+
+        // Unit cube sheared along x as z increases.
+        let mut mesh = Mesh3d::default();
+
+        mesh.vertices.push(Pnt3d::new([0.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 0.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([1.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.0, 1.0, 0.0]));
+        mesh.vertices.push(Pnt3d::new([0.7, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.7, 0.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([1.7, 1.0, 1.0]));
+        mesh.vertices.push(Pnt3d::new([0.7, 1.0, 1.0]));
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+
+        assert!((mesh.get_hexa_view(&mesh.hexahedra[0]).get_volume() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn get_barycenter() {
+
+        let mut mesh = Mesh3d::default();
+
+        for k in 0..8 {
+            let (x, y) = ([0.0, 1.0, 1.0, 0.0][k % 4], [0.0, 0.0, 1.0, 1.0][k % 4]);
+            mesh.vertices.push(Pnt3d::new([x, y, (k / 4) as f64]));
+        }
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+
+        let b = mesh.get_hexa_view(&mesh.hexahedra[0]).get_barycenter();
+        assert!(b.coords.equals(&Coord3d::new([0.5, 0.5, 0.5]), GEOMETRICAL_TOLERANCE));
+    }
+
+    #[test]
+    fn get_signed_volume() {
+
+        let mut mesh = Mesh3d::default();
+
+        for k in 0..8 {
+            let (x, y) = ([0.0, 1.0, 1.0, 0.0][k % 4], [0.0, 0.0, 1.0, 1.0][k % 4]);
+            mesh.vertices.push(Pnt3d::new([x, y, (k / 4) as f64]));
+        }
+        mesh.hexahedra.push(Hexa::new([0, 1, 2, 3, 4, 5, 6, 7]));
+        mesh.hexahedra.push(Hexa::new([4, 5, 6, 7, 0, 1, 2, 3]));
+
+        assert!((mesh.get_hexa_view(&mesh.hexahedra[0]).get_signed_volume() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_hexa_view(&mesh.hexahedra[1]).get_signed_volume() + 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((mesh.get_hexa_view(&mesh.hexahedra[1]).get_volume() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+    }
+}
+
+mod tri_view2d {
+
+    use mersh::base::*;
+    use mersh::views::*;
+
+    #[test]
+    fn get_barycentric_coords() {
+
+        let (p0, p1, p2) = (Pnt2d::new([1.0, 1.0]), Pnt2d::new([3.0, 1.0]), Pnt2d::new([1.0, 3.0]));
+        let t = TriView2d { points: [&p0, &p1, &p2] };
+
+        let l = t.get_barycentric_coords(&p1);
+        assert!(l[0].abs() < GEOMETRICAL_TOLERANCE);
+        assert!((l[1] - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!(l[2].abs() < GEOMETRICAL_TOLERANCE);
+
+        let l = t.get_barycentric_coords(&Pnt2d::new([2.0, 1.5]));
+        assert!((l[0] - 0.25).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((l[1] - 0.5).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((l[2] - 0.25).abs() < GEOMETRICAL_TOLERANCE);
+    }
+
+    #[test]
+    fn contains_point() {
+
+        let (p0, p1, p2) = (Pnt2d::new([1.0, 1.0]), Pnt2d::new([3.0, 1.0]), Pnt2d::new([1.0, 3.0]));
+        let t = TriView2d { points: [&p0, &p1, &p2] };
+
+        assert!(t.contains_point(&p0, GEOMETRICAL_TOLERANCE));
+        assert!(t.contains_point(&p2, GEOMETRICAL_TOLERANCE));
+        assert!(t.contains_point(&Pnt2d::new([5.0 / 3.0, 5.0 / 3.0]), 0.0));
+        assert!(!t.contains_point(&Pnt2d::new([2.0, 0.999]), 1e-6));
+    }
+
+    #[test]
+    fn contains() {
+
+        let (p0, p1, p2) = (Pnt2d::new([1.0, 1.0]), Pnt2d::new([3.0, 1.0]), Pnt2d::new([1.0, 3.0]));
+        let t = TriView2d { points: [&p0, &p1, &p2] };
+
+        assert!(t.contains(&Pnt2d::new([1.5, 1.5])));
+        assert!(!t.contains(&Pnt2d::new([2.5, 2.5])));
+        assert!(t.contains(&p1));
+        assert!(t.contains(&Pnt2d::new([2.0, 2.0])));
+
+        // Clockwise triangles give the same answers.
+        let t = TriView2d { points: [&p0, &p2, &p1] };
+        assert!(t.contains(&Pnt2d::new([1.5, 1.5])));
+        assert!(!t.contains(&Pnt2d::new([0.5, 1.5])));
+    }
+
+    #[test]
+    fn quality() {
+
+        let (p0, p1, p2) = (Pnt2d::new([0.0, 0.0]), Pnt2d::new([2.0, 0.0]), Pnt2d::new([1.0, 3f64.sqrt()]));
+        let t = TriView2d { points: [&p0, &p1, &p2] };
+        assert!((t.get_area() - 3f64.sqrt()).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_aspect_ratio() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_min_angle() - std::f64::consts::PI / 3.0).abs() < GEOMETRICAL_TOLERANCE);
+        assert!((t.get_max_min_edge_ratio() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        let t = TriView2d { points: [&p0, &p0, &p2] };
+        assert_eq!(t.get_aspect_ratio(), f64::INFINITY);
+        assert_eq!(t.get_max_min_edge_ratio(), f64::INFINITY);
+        assert_eq!(t.get_min_angle(), 0.0);
+    }
+    #[test]
+    fn circumcenter_and_incenter() {
+
+        let (p0, p1, p2) = (Pnt2d::new([1.0, 1.0]), Pnt2d::new([4.0, 1.0]), Pnt2d::new([1.0, 5.0]));
+        let t = TriView2d { points: [&p0, &p1, &p2] };
+        match t.get_circumcenter() {
+            Some(c) => assert!(c.coords.equals(&Coord2d::new([2.5, 3.0]), GEOMETRICAL_TOLERANCE)),
+            None => { panic!(); }
+        }
+        match t.get_circumradius() {
+            Some(r) => assert!((r - 2.5).abs() < GEOMETRICAL_TOLERANCE),
+            None => { panic!(); }
+        }
+        assert!(t.get_incenter().coords.equals(&Coord2d::new([2.0, 2.0]), GEOMETRICAL_TOLERANCE));
+        assert!((t.get_inradius() - 1.0).abs() < GEOMETRICAL_TOLERANCE);
+
+        let p3 = Pnt2d::new([7.0, 1.0 + 1e-14]);
+        let t = TriView2d { points: [&p0, &p1, &p3] };
+        assert!(t.get_circumcenter().is_none());
+    }
+
+    #[test]
+    fn get_bounding_box() {
+
+        let (p0, p1, p2) = (Pnt2d::new([1.0, 1.0]), Pnt2d::new([4.0, -1.0]), Pnt2d::new([2.0, 5.0]));
+        let bbox = TriView2d { points: [&p0, &p1, &p2] }.get_bounding_box();
+        assert!(bbox.min.equals(&Coord2d::new([1.0, -1.0]), GEOMETRICAL_TOLERANCE));
+        assert!(bbox.max.equals(&Coord2d::new([4.0, 5.0]), GEOMETRICAL_TOLERANCE));
+    }
+
+}